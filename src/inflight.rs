@@ -0,0 +1,52 @@
+//! Registry of keyed requests in flight, shared by the fetch hooks so that
+//! components requesting the same key at the same time share one network call.
+
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    future::Future,
+    rc::Rc,
+};
+
+type SharedRequest<T> = Shared<LocalBoxFuture<'static, Rc<T>>>;
+
+thread_local! {
+    /// Requests in flight, by output type and key.
+    static IN_FLIGHT: RefCell<HashMap<(TypeId, String), Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Await the request for `key` already in flight, or start one with `request`.
+/// Every caller receives the same result. The key is released once the request
+/// completes, so a later call fetches again.
+pub(crate) async fn dedupe<T, Fut>(key: &str, request: impl FnOnce() -> Fut) -> Rc<T>
+where
+    T: 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    let id = (TypeId::of::<T>(), key.to_string());
+    let shared = IN_FLIGHT.with(|in_flight| {
+        let mut in_flight = in_flight.borrow_mut();
+        if let Some(shared) = in_flight
+            .get(&id)
+            .and_then(|shared| shared.downcast_ref::<SharedRequest<T>>())
+        {
+            return shared.clone();
+        }
+        let future = request();
+        let shared: SharedRequest<T> = {
+            let id = id.clone();
+            async move {
+                let result = Rc::new(future.await);
+                IN_FLIGHT.with(|in_flight| in_flight.borrow_mut().remove(&id));
+                result
+            }
+            .boxed_local()
+            .shared()
+        };
+        in_flight.insert(id, Box::new(shared.clone()));
+        shared
+    });
+    shared.await
+}
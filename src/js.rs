@@ -0,0 +1,65 @@
+//! Small helpers for reaching browser APIs that `web-sys` only exposes behind
+//! `web_sys_unstable_apis` (or not at all).
+
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Whether `key` exists on `target` (the JS `in` operator).
+pub(crate) fn has(target: &JsValue, key: &str) -> bool {
+    target.is_object() && Reflect::has(target, &JsValue::from_str(key)).unwrap_or(false)
+}
+
+/// Read `target[key]`, treating `undefined` and `null` as absent.
+pub(crate) fn get(target: &JsValue, key: &str) -> Option<JsValue> {
+    Reflect::get(target, &JsValue::from_str(key))
+        .ok()
+        .filter(|v| !v.is_undefined() && !v.is_null())
+}
+
+/// Call `target[method](...args)`.
+pub(crate) fn call(target: &JsValue, method: &str, args: &[JsValue]) -> Result<JsValue, JsValue> {
+    let function: Function = get(target, method)
+        .ok_or_else(|| JsValue::from_str(&format!("{method} is not supported")))?
+        .dyn_into()?;
+    let args: js_sys::Array = args.iter().collect();
+    Reflect::apply(&function, target, &args)
+}
+
+/// Call `target[method](...args)` and await the result if it is a promise.
+pub(crate) async fn call_async(
+    target: &JsValue,
+    method: &str,
+    args: &[JsValue],
+) -> Result<JsValue, JsValue> {
+    let value = call(target, method, args)?;
+    match value.dyn_into::<Promise>() {
+        Ok(promise) => JsFuture::from(promise).await,
+        Err(value) => Ok(value),
+    }
+}
+
+/// Build a plain JS object from key/value pairs.
+pub(crate) fn object(entries: &[(&str, JsValue)]) -> js_sys::Object {
+    let object = js_sys::Object::new();
+    for (key, value) in entries {
+        let _ = Reflect::set(&object, &JsValue::from_str(key), value);
+    }
+    object
+}
+
+/// Convert a serializable value into a plain JS value (via JSON), e.g. for `postMessage`.
+#[cfg(feature = "serde")]
+pub(crate) fn to_js<T: serde::Serialize>(value: &T) -> Result<JsValue, String> {
+    let json = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    js_sys::JSON::parse(&json).map_err(|e| format!("{e:?}"))
+}
+
+/// Convert a plain JS value (e.g. `MessageEvent.data`) into a deserializable value (via JSON).
+#[cfg(feature = "serde")]
+pub(crate) fn from_js<T: serde::de::DeserializeOwned>(value: &JsValue) -> Result<T, String> {
+    let json: String = js_sys::JSON::stringify(value)
+        .map_err(|e| format!("{e:?}"))?
+        .into();
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
@@ -1,12 +1,340 @@
 #![deny(unused)]
 
+mod inflight;
+mod js;
+mod network_mock;
+mod storage;
+mod upload;
+pub use network_mock::{NetworkMock, NetworkMockProvider, NetworkMockProviderProps};
+#[cfg(feature = "storage")]
+pub use storage::{STORAGE_NAMESPACE, StorageOptions};
+pub use upload::{UploadOptions, UploadResponse, UploadStatus};
+
+mod use_abortable_async;
+pub use use_abortable_async::{UseAbortableAsyncHandle, use_abortable_async};
+mod use_app_badge;
+pub use use_app_badge::{UseAppBadgeHandle, use_app_badge};
 mod use_btree_set;
 pub use use_btree_set::{UseBTreeSetHandle, use_btree_set};
+mod use_battery;
+pub use use_battery::{BatteryState, use_battery};
+mod use_beacon;
+#[cfg(feature = "serde")]
+pub use use_beacon::{BeaconOptions, UseBeaconHandle, use_beacon};
+mod use_breakpoint;
+pub use use_breakpoint::{Breakpoint, Breakpoints, use_breakpoint};
+mod use_broadcast_state;
+#[cfg(feature = "serde")]
+pub use use_broadcast_state::{UseBroadcastStateHandle, use_broadcast_state};
+mod use_cached_fetch;
+#[cfg(feature = "storage")]
+pub use use_cached_fetch::{UseCachedFetchHandle, use_cached_fetch};
+mod use_clipboard;
+pub use use_clipboard::{ClipboardOptions, UseClipboardHandle, use_clipboard};
+mod use_clipboard_files_upload;
+pub use use_clipboard_files_upload::{
+    QueuedUpload, UseClipboardFilesUploadHandle, use_clipboard_files_upload,
+};
+mod use_conditional_fetch;
+pub use use_conditional_fetch::{
+    ConditionalFetchResult, UseConditionalFetchHandle, use_conditional_fetch,
+};
+mod use_connection_quality;
+pub use use_connection_quality::{
+    ConnectionQuality, ConnectionQualityOptions, ConnectionQualityReport, use_connection_quality,
+};
+mod use_dark_mode;
+#[cfg(feature = "storage")]
+pub use use_dark_mode::{
+    ColorScheme, ColorSchemePreference, DarkModeOptions, UseDarkModeHandle, use_dark_mode,
+};
+mod use_device_orientation;
+pub use use_device_orientation::{
+    Acceleration, DeviceMotion, DeviceOrientation, RotationRate, UseDeviceSensorHandle,
+    use_device_motion, use_device_orientation,
+};
+mod use_device_pixel_ratio;
+pub use use_device_pixel_ratio::use_device_pixel_ratio;
+mod use_document_language_observer;
+pub use use_document_language_observer::{
+    UseDocumentLanguageHandle, use_document_language_observer,
+};
+mod use_document_pip_widget;
+pub use use_document_pip_widget::{
+    DocumentPipOptions, UseDocumentPipWidgetHandle, use_document_pip_widget,
+};
+mod use_document_title;
+pub use use_document_title::use_document_title;
+mod use_download;
+pub use use_download::{DownloadStatus, UseDownloadHandle, use_download};
+mod use_element_size;
+pub use use_element_size::{BoxSize, ElementSize, use_element_size, use_element_size_with_rate};
+mod use_element_visibility;
+pub use use_element_visibility::{
+    ElementVisibility, ElementVisibilityOptions, use_element_visibility,
+};
+mod use_environment_capabilities;
+pub use use_environment_capabilities::{EnvironmentCapabilities, use_environment_capabilities};
+mod use_event_source;
+#[cfg(feature = "serde")]
+pub use use_event_source::{
+    EventSourceMessage, EventSourceOptions, EventSourceState, UseEventSourceHandle,
+    use_event_source,
+};
+mod use_favicon;
+pub use use_favicon::use_favicon;
+mod use_forced_colors;
+pub use use_forced_colors::use_forced_colors;
+mod use_fullscreen;
+pub use use_fullscreen::{UseFullscreenHandle, use_fullscreen};
+mod use_gamepad;
+pub use use_gamepad::{
+    ButtonState, GamepadCallbacks, GamepadState, use_gamepad, use_gamepad_with_callbacks,
+};
+mod use_geolocation;
+pub use use_geolocation::{
+    GeolocationError, GeolocationOptions, GeolocationPosition, UseGeolocationHandle,
+    use_geolocation,
+};
+mod use_graphql;
+#[cfg(feature = "serde")]
+pub use use_graphql::{GraphQLError, GraphQLRequestError, UseGraphQLHandle, use_graphql};
+mod use_idle;
+pub use use_idle::{UseIdleHandle, use_idle};
+mod use_idle_detection;
+pub use use_idle_detection::{
+    IdleDetectionOptions, IdleDetectionState, ScreenIdleState, UseIdleDetectionHandle,
+    UserIdleState, use_idle_detection,
+};
+mod use_iframe_resize;
+#[cfg(feature = "serde")]
+pub use use_iframe_resize::{IframeSize, use_iframe_resize, use_iframe_resize_host};
+mod use_infinite_scroll;
+pub use use_infinite_scroll::{InfiniteScrollOptions, use_infinite_scroll};
+mod use_install_prompt;
+pub use use_install_prompt::{InstallChoice, UseInstallPromptHandle, use_install_prompt};
+mod use_keyboard_focus_order_debugger;
+pub use use_keyboard_focus_order_debugger::{
+    FocusOrderDebuggerOptions, KeyboardShortcut, UseFocusOrderDebuggerHandle,
+    use_keyboard_focus_order_debugger,
+};
 mod use_local_storage_default;
-pub use use_local_storage_default::{UseLocalStorageDefaultHandle, use_local_storage_default};
+#[cfg(feature = "storage")]
+pub use use_local_storage_default::{
+    UseLocalStorageDefaultHandle, use_local_storage_default,
+    use_local_storage_default_with_consent, use_local_storage_default_with_options,
+};
+mod use_long_poll;
+#[cfg(feature = "serde")]
+pub use use_long_poll::{UseLongPollHandle, use_long_poll};
+mod use_low_power_video;
+pub use use_low_power_video::{LOW_BATTERY_LEVEL, LowPowerVideoState, use_low_power_video};
+mod use_media_devices;
+pub use use_media_devices::{MediaDevice, UseMediaDevicesHandle, use_media_devices};
+mod use_media_query;
+pub use use_media_query::use_media_query;
+mod use_meta_theme_color;
+pub use use_meta_theme_color::use_meta_theme_color;
+mod use_multi_tab_form_lock;
+#[cfg(feature = "storage")]
+pub use use_multi_tab_form_lock::{
+    FormLockHolder, FormLockOptions, FormLockState, UseFormLockHandle, use_multi_tab_form_lock,
+};
+mod use_mutation_observer;
+pub use use_mutation_observer::{MutationObserverOptions, use_mutation_observer};
+mod use_network_information;
+pub use use_network_information::{
+    EffectiveConnectionType, NetworkInformation, use_network_information,
+};
+mod use_notification;
+pub use use_notification::{
+    NotificationOptions, NotifyOptions, UseNotificationHandle, use_notification,
+};
+mod use_notification_permission_funnel;
+#[cfg(feature = "storage")]
+pub use use_notification_permission_funnel::{
+    NotificationFunnel, NotificationFunnelOptions, SoftPromptResponse,
+    UseNotificationPermissionFunnelHandle, use_notification_permission_funnel,
+};
+mod use_offline_queue;
+#[cfg(feature = "storage")]
+pub use use_offline_queue::{
+    MutationStatus, OfflineQueueOptions, QueuedMutation, UseOfflineQueueHandle, use_offline_queue,
+};
 mod use_online;
-pub use use_online::use_online;
+pub use use_online::{
+    OnlineCallbacks, OnlineState, OnlineStatus, use_online, use_online_with_callbacks,
+    use_online_with_probe,
+};
+mod use_page_visibility;
+pub use use_page_visibility::{PageVisibility, use_page_visibility};
+mod use_paste;
+pub use use_paste::{PasteData, use_paste, use_window_paste};
+mod use_periodic_background_fetch;
+#[cfg(feature = "storage")]
+pub use use_periodic_background_fetch::{
+    PERIODIC_SYNC_MESSAGE_TYPE, PeriodicBackgroundFetchState, UsePeriodicBackgroundFetchHandle,
+    use_periodic_background_fetch,
+};
+mod use_permission;
+pub use use_permission::use_permission;
+mod use_persisted_zoom_and_font_scale;
+#[cfg(feature = "storage")]
+pub use use_persisted_zoom_and_font_scale::{
+    FontScaleOptions, UseFontScaleHandle, use_persisted_zoom_and_font_scale,
+};
+mod use_pointer_lock;
+pub use use_pointer_lock::{PointerDelta, UsePointerLockHandle, use_pointer_lock};
+mod use_polling;
+pub use use_polling::{UsePollingHandle, use_polling};
+mod use_preferred_languages;
+pub use use_preferred_languages::use_preferred_languages;
+mod use_prefers_contrast;
+pub use use_prefers_contrast::{ContrastPreference, use_prefers_contrast};
+mod use_prefers_reduced_motion;
+pub use use_prefers_reduced_motion::use_prefers_reduced_motion;
+mod use_prefetch;
+pub use use_prefetch::{PrefetchOptions, UsePrefetchHandle, use_prefetch};
+mod use_push_subscription;
+pub use use_push_subscription::{
+    PushSubscriptionInfo, UsePushSubscriptionHandle, use_push_subscription,
+};
+mod use_query;
+pub use use_query::{
+    QueryOptions, UseQueryHandle, invalidate_query, use_query, use_query_with_options,
+};
+mod use_rate_limited_callback;
+pub use use_rate_limited_callback::{
+    RateLimitOverflow, use_rate_limited_callback, use_rate_limited_callback_with_options,
+};
+mod use_rate_of_change;
+pub use use_rate_of_change::{RateOfChange, RateOfChangeOptions, use_rate_of_change};
+mod use_reduced_motion_scroll;
+pub use use_reduced_motion_scroll::{UseReducedMotionScrollHandle, use_reduced_motion_scroll};
+mod use_retryable_async;
+pub use use_retryable_async::{RetryPolicy, UseRetryableAsyncHandle, use_retryable_async};
+mod use_rtc_data_channel;
+#[cfg(feature = "serde")]
+pub use use_rtc_data_channel::{
+    IceCandidate, RtcConnectionState, RtcDataChannelError, RtcDataChannelOptions,
+    UseRtcDataChannelHandle, use_rtc_data_channel,
+};
+mod use_screen_orientation;
+pub use use_screen_orientation::{Orientation, UseScreenOrientationHandle, use_screen_orientation};
+mod use_scroll_lock;
+pub use use_scroll_lock::use_scroll_lock;
+mod use_scroll_position;
+pub use use_scroll_position::{
+    ScrollDirection, ScrollOptions, ScrollPosition, use_element_scroll,
+    use_element_scroll_with_options, use_window_scroll, use_window_scroll_with_options,
+};
+mod use_scroll_progress_reading_time;
+pub use use_scroll_progress_reading_time::{
+    ReadingProgress, ReadingTimeOptions, use_scroll_progress_reading_time,
+};
+mod use_server_time;
+pub use use_server_time::{
+    ServerTimeOptions, UseServerTimeHandle, use_server_time, use_server_time_with_options,
+};
+mod use_service_worker;
+pub use use_service_worker::{
+    SKIP_WAITING_MESSAGE_TYPE, ServiceWorkerState, UseServiceWorkerHandle, use_service_worker,
+};
+mod use_session_replay_breadcrumbs;
+#[cfg(feature = "storage")]
+pub use use_session_replay_breadcrumbs::{
+    Breadcrumb, BreadcrumbKind, BreadcrumbOptions, UseSessionReplayBreadcrumbsHandle,
+    use_session_replay_breadcrumbs,
+};
 mod use_session_storage_with_listen;
+#[cfg(feature = "storage")]
 pub use use_session_storage_with_listen::{
     UseSessionStorageWithListenHandle, use_session_storage_with_listen,
+    use_session_storage_with_listen_with_options,
+};
+mod use_speculation_rules;
+pub use use_speculation_rules::{
+    SpeculationAction, SpeculationEagerness, SpeculationRulesOptions, use_speculation_rules,
+};
+mod use_speech_synthesis;
+pub use use_speech_synthesis::{
+    SpeakOptions, UseSpeechSynthesisHandle, Voice, use_speech_synthesis,
+};
+mod use_speech_recognition;
+pub use use_speech_recognition::{
+    SpeechRecognitionOptions, UseSpeechRecognitionHandle, use_speech_recognition,
+};
+mod use_storage_backup;
+#[cfg(feature = "storage")]
+pub use use_storage_backup::{STORAGE_BACKUP_VERSION, UseStorageBackupHandle, use_storage_backup};
+mod use_storage_keyspace_watchdog;
+#[cfg(feature = "storage")]
+pub use use_storage_keyspace_watchdog::{
+    KeyUsage, KeyspaceReport, KeyspaceWatchdogOptions, NamespaceUsage,
+    use_storage_keyspace_watchdog,
+};
+mod use_storage_listen_many;
+#[cfg(feature = "storage")]
+pub use use_storage_listen_many::{
+    UseStorageListenManyHandle, use_storage_listen_many, use_storage_listen_many_with_options,
+};
+mod use_storage_snapshot_export;
+#[cfg(feature = "storage")]
+pub use use_storage_snapshot_export::{
+    StorageSnapshotError, UseStorageSnapshotExportHandle, use_storage_snapshot_export,
+};
+mod use_suspense_resource;
+pub use use_suspense_resource::use_suspense_resource;
+mod use_text_fragment_highlight;
+pub use use_text_fragment_highlight::{
+    TextFragment, TextFragmentHighlightOptions, UseTextFragmentHighlightHandle,
+    use_text_fragment_highlight,
+};
+mod use_time_sync;
+pub use use_time_sync::{TimeSample, TimeSyncOptions, UseTimeSyncHandle, use_time_sync};
+mod use_timezone;
+pub use use_timezone::use_timezone;
+mod use_upload;
+pub use use_upload::{UseUploadHandle, use_upload};
+mod use_user_media;
+pub use use_user_media::{UseUserMediaHandle, UserMediaOptions, use_user_media};
+mod use_vibrate;
+pub use use_vibrate::use_vibrate;
+mod use_virtual_list;
+pub use use_virtual_list::{
+    ItemSize, UseVirtualListHandle, VirtualItem, VirtualListOptions, use_virtual_list,
+};
+mod use_wake_lock;
+pub use use_wake_lock::{UseWakeLockHandle, use_wake_lock};
+mod use_web_share;
+pub use use_web_share::{ShareContent, ShareOutcome, UseWebShareHandle, use_web_share};
+mod use_web_transport;
+#[cfg(feature = "web-transport")]
+pub use use_web_transport::{
+    UseWebTransportHandle, WebTransportState, WebTransportStream, use_web_transport,
+};
+mod use_websocket_json;
+#[cfg(feature = "serde")]
+pub use use_websocket_json::{
+    JsonFrameError, UseWebSocketJsonHandle, use_websocket_json, use_websocket_json_with_options,
+};
+mod use_websocket_reconnecting;
+pub use use_websocket_reconnecting::{
+    Heartbeat, ReconnectingWebSocketOptions, ReconnectingWebSocketState,
+    UseWebSocketReconnectingHandle, use_websocket_reconnecting,
+};
+mod use_worker_pool_map;
+#[cfg(feature = "serde")]
+pub use use_worker_pool_map::{
+    UseWorkerPoolMapHandle, WorkerItemStatus, WorkerPoolOptions, use_worker_pool_map,
+};
+mod use_window_focus;
+pub use use_window_focus::use_window_focus;
+mod use_window_messaging;
+#[cfg(feature = "serde")]
+pub use use_window_messaging::{
+    MessageTarget, ReceivedMessage, UseWindowMessagingHandle, WindowMessagingError,
+    WindowMessagingOptions, use_window_messaging,
 };
+mod use_window_size;
+pub use use_window_size::{ResizeRate, WindowSize, use_window_size, use_window_size_with_rate};
@@ -0,0 +1,91 @@
+//! Context overriding what the network hooks report, to develop and test
+//! offline and slow-network UI without touching the OS network settings.
+
+use gloo::utils::window;
+use yew::prelude::*;
+
+use crate::NetworkInformation;
+
+/// Simulated network state provided by [`NetworkMockProvider`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkMock {
+    /// Forced `navigator.onLine`; `None` reports the real status.
+    pub online: Option<bool>,
+    /// Forced Network Information API values; `None` reports the real ones.
+    pub network: Option<NetworkInformation>,
+}
+
+/// Props of [`NetworkMockProvider`].
+#[derive(Properties, PartialEq)]
+pub struct NetworkMockProviderProps {
+    /// Force [`use_online`](crate::use_online) and the hooks built on it
+    /// (polling, probes, queries, offline queue) into this status.
+    #[prop_or_default]
+    pub online: Option<bool>,
+    /// Force [`use_network_information`](crate::use_network_information) to
+    /// report these values, e.g. a 2G connection with `save_data`.
+    #[prop_or_default]
+    pub network: Option<NetworkInformation>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Overrides what the network hooks report for every component below it.
+/// Props left unset report the real values, and the override follows prop
+/// changes, so a dev toolbar can toggle it live.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(OfflinePreview)]
+/// fn offline_preview() -> Html {
+///     let offline = use_state(|| false);
+///     let ontoggle = {
+///         let offline = offline.clone();
+///         Callback::from(move |_| offline.set(!*offline))
+///     };
+///
+///     html! {
+///         <>
+///             <button onclick={ontoggle}>{ "Toggle simulated offline" }</button>
+///             <NetworkMockProvider online={(*offline).then_some(false)}>
+///                 <Status />
+///             </NetworkMockProvider>
+///         </>
+///     }
+/// }
+///
+/// #[function_component(Status)]
+/// fn status() -> Html {
+///     let online = use_online();
+///     html! { <p>{ if *online { "Online" } else { "Offline" } }</p> }
+/// }
+/// ```
+#[function_component(NetworkMockProvider)]
+pub fn network_mock_provider(props: &NetworkMockProviderProps) -> Html {
+    let mock = NetworkMock {
+        online: props.online,
+        network: props.network.clone(),
+    };
+
+    html! {
+        <ContextProvider<NetworkMock> context={mock}>
+            { props.children.clone() }
+        </ContextProvider<NetworkMock>>
+    }
+}
+
+/// The innermost [`NetworkMock`], or no override outside a provider.
+#[hook]
+pub(crate) fn use_network_mock() -> NetworkMock {
+    use_context::<NetworkMock>().unwrap_or_default()
+}
+
+/// `navigator.onLine`, unless `forced`.
+pub(crate) fn navigator_online(forced: Option<bool>) -> bool {
+    forced.unwrap_or_else(|| window().navigator().on_line())
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "storage")]
+
+use gloo::timers::callback::Timeout;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+/// Key prefix of this crate's storage namespace. The storage hooks' default
+/// keys live under it, and [`use_storage_backup`](crate::use_storage_backup)
+/// backs up every key under it; prefix your own keys with it to include them.
+pub const STORAGE_NAMESPACE: &str = "more-yew-hooks:";
+
+/// Options shared by the Web Storage hooks.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageOptions {
+    /// Coalesce bursts of `storage` events for the hook's key (e.g. another tab
+    /// autosaving) into a single state update, applied once no further event
+    /// has arrived for this long. [`Duration::ZERO`] updates on every event.
+    pub listen_debounce: Duration,
+}
+
+/// Slot holding the pending debounced update of a listening storage hook.
+pub(crate) type PendingUpdate = Rc<RefCell<Option<Timeout>>>;
+
+/// Run `update` after `delay`, cancelling any update already pending in `pending`.
+/// Runs immediately when `delay` is zero.
+pub(crate) fn debounce(pending: &PendingUpdate, delay: Duration, update: impl FnOnce() + 'static) {
+    if delay.is_zero() {
+        pending.borrow_mut().take();
+        update();
+        return;
+    }
+    let millis = u32::try_from(delay.as_millis()).unwrap_or(u32::MAX);
+    *pending.borrow_mut() = Some(Timeout::new(millis, update));
+}
+
+/// Every key in `storage` starting with `prefix`, mapped to its JSON value.
+/// Values that are not valid JSON are exported as strings.
+pub(crate) fn snapshot(
+    storage: &web_sys::Storage,
+    prefix: &str,
+) -> serde_json::Map<String, serde_json::Value> {
+    let length = storage.length().unwrap_or(0);
+    (0..length)
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter(|key| key.starts_with(prefix))
+        .filter_map(|key| {
+            let raw = storage.get_item(&key).ok().flatten()?;
+            let value = serde_json::from_str(&raw).unwrap_or(serde_json::Value::String(raw));
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Replace every key in `storage` starting with `prefix` with the entries of
+/// `snapshot`; keys outside `prefix` are skipped. Returns the keys written or removed.
+///
+/// All entries are written before stale keys are removed; if a write fails
+/// (e.g. quota exceeded), the keys under `prefix` are rolled back to what they
+/// were and the error is returned.
+pub(crate) fn restore(
+    storage: &web_sys::Storage,
+    prefix: &str,
+    snapshot: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Vec<String>, String> {
+    // Raw values, so the rollback writes back exactly what was there.
+    let previous: Vec<(String, String)> = (0..storage.length().unwrap_or(0))
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter(|key| key.starts_with(prefix))
+        .filter_map(|key| {
+            let raw = storage.get_item(&key).ok().flatten()?;
+            Some((key, raw))
+        })
+        .collect();
+
+    let mut written = Vec::new();
+    let write = |written: &mut Vec<String>| -> Result<(), String> {
+        for (key, value) in snapshot.iter().filter(|(key, _)| key.starts_with(prefix)) {
+            let json = serde_json::to_string(value).map_err(|e| e.to_string())?;
+            storage.set_item(key, &json).map_err(|e| format!("{e:?}"))?;
+            written.push(key.clone());
+        }
+        Ok(())
+    };
+    if let Err(e) = write(&mut written) {
+        for key in &written {
+            let _ = storage.remove_item(key);
+        }
+        for (key, raw) in &previous {
+            let _ = storage.set_item(key, raw);
+        }
+        return Err(e);
+    }
+
+    for (key, _) in previous {
+        if !snapshot.contains_key(&key) {
+            let _ = storage.remove_item(&key);
+            written.push(key);
+        }
+    }
+    Ok(written)
+}
+
+/// Dispatch a same-tab `storage` event for each of `keys`, so live storage hooks
+/// pick up writes made by this tab (browsers only notify *other* tabs).
+pub(crate) fn notify(storage: &web_sys::Storage, keys: &[String]) {
+    for key in keys {
+        let init = web_sys::StorageEventInit::new();
+        init.set_key(Some(key));
+        init.set_new_value(storage.get_item(key).ok().flatten().as_deref());
+        init.set_storage_area(Some(storage));
+        if let Ok(event) = web_sys::StorageEvent::new_with_event_init_dict("storage", &init) {
+            let _ = gloo::utils::window().dispatch_event(&event);
+        }
+    }
+}
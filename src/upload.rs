@@ -0,0 +1,160 @@
+use gloo::events::EventListener;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsCast;
+use web_sys::{Blob, FormData, ProgressEvent, XmlHttpRequest};
+
+/// Where and how files are uploaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadOptions {
+    /// Endpoint receiving the upload.
+    pub url: String,
+    /// HTTP method, `POST` by default.
+    pub method: String,
+    /// Name of the `multipart/form-data` field holding the file.
+    pub field_name: String,
+    /// Extra request headers, e.g. an `Authorization` token.
+    pub headers: Vec<(String, String)>,
+    /// Send cookies with cross-origin uploads.
+    pub with_credentials: bool,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            method: "POST".to_string(),
+            field_name: "file".to_string(),
+            headers: Vec::new(),
+            with_credentials: false,
+        }
+    }
+}
+
+/// Response of a finished upload.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadResponse {
+    /// HTTP status code (always 2xx).
+    pub status: u16,
+    /// Response body as text.
+    pub body: String,
+}
+
+/// Lifecycle of a single upload.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum UploadStatus {
+    /// Waiting for its turn.
+    #[default]
+    Queued,
+    /// Bytes sent so far out of `total` (`total` is 0 when unknown).
+    Uploading { loaded: f64, total: f64 },
+    /// The server accepted the upload.
+    Done(UploadResponse),
+    /// The request failed or the server responded with a non-2xx status.
+    Failed(String),
+    /// The upload was cancelled.
+    Cancelled,
+}
+
+impl UploadStatus {
+    /// Progress between 0 and 1, when known.
+    pub fn progress(&self) -> Option<f64> {
+        match self {
+            Self::Uploading { loaded, total } if *total > 0.0 => Some(loaded / total),
+            Self::Done(_) => Some(1.0),
+            _ => None,
+        }
+    }
+
+    /// Whether the upload has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Done(_) | Self::Failed(_) | Self::Cancelled)
+    }
+}
+
+/// An in-flight upload; dropping it detaches the listeners without aborting.
+pub(crate) struct Upload {
+    xhr: XmlHttpRequest,
+    _listeners: Vec<EventListener>,
+}
+
+impl Upload {
+    pub(crate) fn abort(&self) {
+        let _ = self.xhr.abort();
+    }
+}
+
+/// Upload `file` as `multipart/form-data`, reporting progress as `(loaded, total)`
+/// and calling `on_done` exactly once with the outcome.
+pub(crate) fn start(
+    options: &UploadOptions,
+    file: &Blob,
+    filename: &str,
+    on_progress: impl Fn(f64, f64) + 'static,
+    on_done: impl FnOnce(UploadStatus) + 'static,
+) -> Result<Upload, String> {
+    let err = |e: wasm_bindgen::JsValue| format!("{e:?}");
+    let xhr = XmlHttpRequest::new().map_err(err)?;
+    xhr.open_with_async(&options.method, &options.url, true)
+        .map_err(err)?;
+    xhr.set_with_credentials(options.with_credentials);
+    for (name, value) in &options.headers {
+        xhr.set_request_header(name, value).map_err(err)?;
+    }
+    let form = FormData::new().map_err(err)?;
+    form.append_with_blob_and_filename(&options.field_name, file, filename)
+        .map_err(err)?;
+
+    let on_done = Rc::new(RefCell::new(Some(on_done)));
+    let finish = move |status: UploadStatus| {
+        if let Some(on_done) = on_done.borrow_mut().take() {
+            on_done(status);
+        }
+    };
+
+    let upload = xhr.upload().map_err(err)?;
+    let mut listeners = vec![EventListener::new(&upload, "progress", move |e| {
+        if let Some(e) = e.dyn_ref::<ProgressEvent>() {
+            let total = if e.length_computable() {
+                e.total()
+            } else {
+                0.0
+            };
+            on_progress(e.loaded(), total);
+        }
+    })];
+    {
+        let finish = finish.clone();
+        let target = xhr.clone();
+        listeners.push(EventListener::new(&xhr, "load", move |_| {
+            let status = target.status().unwrap_or(0);
+            let body = target.response_text().ok().flatten().unwrap_or_default();
+            finish(if (200..300).contains(&status) {
+                UploadStatus::Done(UploadResponse { status, body })
+            } else {
+                UploadStatus::Failed(format!("HTTP {status}: {body}"))
+            });
+        }));
+    }
+    {
+        let finish = finish.clone();
+        listeners.push(EventListener::new(&xhr, "error", move |_| {
+            finish(UploadStatus::Failed("network error".to_string()));
+        }));
+    }
+    {
+        let finish = finish.clone();
+        listeners.push(EventListener::new(&xhr, "timeout", move |_| {
+            finish(UploadStatus::Failed("timed out".to_string()));
+        }));
+    }
+    listeners.push(EventListener::new(&xhr, "abort", move |_| {
+        finish(UploadStatus::Cancelled);
+    }));
+
+    xhr.send_with_opt_form_data(Some(&form)).map_err(err)?;
+
+    Ok(Upload {
+        xhr,
+        _listeners: listeners,
+    })
+}
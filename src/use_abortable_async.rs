@@ -0,0 +1,225 @@
+use std::{cell::RefCell, future::Future, rc::Rc};
+use web_sys::{AbortController, AbortSignal};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::{use_latest, use_unmount};
+
+/// The run in flight: its controller, and an id to recognize it when it settles.
+struct Run {
+    id: u64,
+    controller: AbortController,
+}
+
+#[derive(Default)]
+struct Runs {
+    current: Option<Run>,
+    next_id: u64,
+}
+
+impl Runs {
+    /// Abort the run in flight, if any.
+    fn abort(&mut self) -> bool {
+        match self.current.take() {
+            Some(run) => {
+                run.controller.abort();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// State handle for the [`use_abortable_async`] hook.
+pub struct UseAbortableAsyncHandle<T, E> {
+    data: UseStateHandle<Option<T>>,
+    error: UseStateHandle<Option<E>>,
+    loading: UseStateHandle<bool>,
+    run: Rc<dyn Fn()>,
+    cancel: Rc<dyn Fn()>,
+}
+
+impl<T, E> UseAbortableAsyncHandle<T, E> {
+    /// The result of the last completed run, if it succeeded.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// The error of the last completed run, if it failed.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Whether a run is in flight.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// Start a new run, aborting the one in flight.
+    pub fn run(&self) {
+        (self.run)();
+    }
+
+    /// Abort the run in flight. Its result, if it still settles, is discarded.
+    pub fn cancel(&self) {
+        (self.cancel)();
+    }
+}
+
+impl<T, E> Clone for UseAbortableAsyncHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            error: self.error.clone(),
+            loading: self.loading.clone(),
+            run: self.run.clone(),
+            cancel: self.cancel.clone(),
+        }
+    }
+}
+
+impl<T, E> PartialEq for UseAbortableAsyncHandle<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.error == other.error && self.loading == other.loading
+    }
+}
+
+/// A hook that runs an async function with an [`AbortSignal`] and aborts it
+/// when the component unmounts or a new run starts.
+///
+/// Pass the signal on to `fetch` (e.g. gloo's `RequestBuilder::abort_signal`)
+/// so aborted requests are actually cancelled. Results of aborted runs are
+/// discarded, so state is never set after unmount or by a stale run. Nothing
+/// runs until [`run`](UseAbortableAsyncHandle::run) is called.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use gloo::net::http::Request;
+/// use more_yew_hooks::*;
+/// use web_sys::AbortSignal;
+///
+/// async fn search(query: String, signal: AbortSignal) -> Result<String, String> {
+///     let url = format!("/api/search?q={query}");
+///     let response = Request::get(&url)
+///         .abort_signal(Some(&signal))
+///         .send()
+///         .await
+///         .map_err(|e| e.to_string())?;
+///     response.text().await.map_err(|e| e.to_string())
+/// }
+///
+/// #[function_component(Search)]
+/// fn search_results(props: &SearchProps) -> Html {
+///     let results = {
+///         let query = props.query.clone();
+///         use_abortable_async(move |signal| search(query.clone(), signal))
+///     };
+///
+///     {
+///         let results = results.clone();
+///         use_effect_with(props.query.clone(), move |_| results.run());
+///     }
+///
+///     html! {
+///         <div>
+///             if results.loading() {
+///                 <p>{ "Searching..." }</p>
+///             }
+///             <p>{ results.data().cloned().unwrap_or_default() }</p>
+///         </div>
+///     }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct SearchProps {
+///     query: String,
+/// }
+/// ```
+#[hook]
+pub fn use_abortable_async<T, E, F, Fut>(f: F) -> UseAbortableAsyncHandle<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn(AbortSignal) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let data = use_state(|| None);
+    let error = use_state(|| None);
+    let loading = use_state(|| false);
+    let runs = use_mut_ref(Runs::default);
+    let f = use_latest(f);
+
+    let run: Rc<dyn Fn()> = {
+        let data = data.clone();
+        let error = error.clone();
+        let loading = loading.clone();
+        let runs = runs.clone();
+        Rc::new(move || {
+            let Ok(controller) = AbortController::new() else {
+                log::error!("AbortController is not supported");
+                return;
+            };
+            let signal = controller.signal();
+            let id = {
+                let mut runs = runs.borrow_mut();
+                runs.abort();
+                let id = runs.next_id;
+                runs.next_id += 1;
+                runs.current = Some(Run { id, controller });
+                id
+            };
+            let future = (*f.current())(signal);
+            loading.set(true);
+
+            let data = data.clone();
+            let error = error.clone();
+            let loading = loading.clone();
+            let runs: Rc<RefCell<Runs>> = runs.clone();
+            spawn_local(async move {
+                let result = future.await;
+                {
+                    let mut runs = runs.borrow_mut();
+                    if runs.current.as_ref().is_none_or(|run| run.id != id) {
+                        return;
+                    }
+                    runs.current = None;
+                }
+                match result {
+                    Ok(value) => {
+                        data.set(Some(value));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let cancel: Rc<dyn Fn()> = {
+        let loading = loading.clone();
+        let runs = runs.clone();
+        Rc::new(move || {
+            if runs.borrow_mut().abort() {
+                loading.set(false);
+            }
+        })
+    };
+
+    use_unmount(move || {
+        runs.borrow_mut().abort();
+    });
+
+    UseAbortableAsyncHandle {
+        data,
+        error,
+        loading,
+        run,
+        cancel,
+    }
+}
@@ -0,0 +1,103 @@
+use gloo::utils::window;
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::JsValue;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+fn is_supported() -> bool {
+    js::has(&window().navigator(), "setAppBadge")
+}
+
+/// Call a badging method, ignoring the promise's outcome: a badge is a hint,
+/// and browsers reject it when the app isn't installed.
+fn badge(method: &'static str, args: Vec<JsValue>) {
+    if !is_supported() {
+        return;
+    }
+    spawn_local(async move {
+        let _ = js::call_async(&window().navigator(), method, &args).await;
+    });
+}
+
+/// Handle for the [`use_app_badge`] hook.
+#[derive(Clone)]
+pub struct UseAppBadgeHandle {
+    set: Rc<Cell<bool>>,
+}
+
+impl UseAppBadgeHandle {
+    /// Whether the Badging API is available.
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// Show `count` on the app icon; 0 clears it.
+    pub fn set(&self, count: u32) {
+        self.set.set(count > 0);
+        badge("setAppBadge", vec![count.into()]);
+    }
+
+    /// Show a plain dot on the app icon, without a number.
+    pub fn set_flag(&self) {
+        self.set.set(true);
+        badge("setAppBadge", vec![]);
+    }
+
+    /// Remove the badge.
+    pub fn clear(&self) {
+        self.set.set(false);
+        badge("clearAppBadge", vec![]);
+    }
+}
+
+impl PartialEq for UseAppBadgeHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.set, &other.set)
+    }
+}
+
+/// A hook for the Badging API, so an installed PWA can show an unread count
+/// on its icon in the dock, taskbar or home screen.
+///
+/// Calls do nothing where the API is unsupported or the app isn't installed.
+/// A badge set through the handle is cleared on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     unread: u32,
+/// }
+///
+/// #[function_component(Inbox)]
+/// fn inbox(props: &Props) -> Html {
+///     let badge = use_app_badge();
+///
+///     use_effect_with(props.unread, move |unread| badge.set(*unread));
+///
+///     html! { <h1>{ format!("Inbox ({})", props.unread) }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_app_badge() -> UseAppBadgeHandle {
+    let set = use_memo((), |_| Cell::new(false));
+
+    {
+        let set = set.clone();
+        use_effect_with((), move |_| {
+            move || {
+                if set.get() {
+                    badge("clearAppBadge", vec![]);
+                }
+            }
+        });
+    }
+
+    UseAppBadgeHandle { set }
+}
@@ -0,0 +1,119 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::JsCast;
+use web_sys::BatteryManager;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+const EVENTS: [&str; 4] = [
+    "chargingchange",
+    "levelchange",
+    "chargingtimechange",
+    "dischargingtimechange",
+];
+
+/// The battery status reported by [`use_battery`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatteryState {
+    /// Charge level from 0.0 to 1.0.
+    pub level: f64,
+    /// Whether the battery is charging.
+    pub charging: bool,
+    /// Time until fully charged; `None` when discharging or unknown.
+    pub charging_time: Option<Duration>,
+    /// Time until empty; `None` when charging or unknown.
+    pub discharging_time: Option<Duration>,
+}
+
+impl BatteryState {
+    fn read(battery: &BatteryManager) -> Self {
+        // Unknown or not applicable times are reported as Infinity.
+        let seconds = |s: f64| (s.is_finite() && s >= 0.0).then(|| Duration::from_secs_f64(s));
+        Self {
+            level: battery.level(),
+            charging: battery.charging(),
+            charging_time: seconds(battery.charging_time()),
+            discharging_time: seconds(battery.discharging_time()),
+        }
+    }
+
+    /// Whether the battery is discharging and at or below `level`.
+    pub fn is_low(&self, level: f64) -> bool {
+        !self.charging && self.level <= level
+    }
+}
+
+/// A sensor hook for the Battery Status API: charge level, charging state and
+/// charging/discharging time, updated as they change, so apps can reduce
+/// background work on low battery.
+///
+/// Returns `None` until the battery is read, and in browsers without the API
+/// (Firefox and Safari).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(SyncSettings)]
+/// fn sync_settings() -> Html {
+///     let battery = use_battery();
+///     let saving = battery.is_some_and(|b| b.is_low(0.2));
+///
+///     html! {
+///         if saving {
+///             <p>{ "Battery low: background sync is paused." }</p>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_battery() -> Option<BatteryState> {
+    let state = use_state_eq(|| None);
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            let alive = Rc::new(Cell::new(true));
+            let listeners = Rc::new(RefCell::new(Vec::new()));
+            {
+                let alive = alive.clone();
+                let listeners = listeners.clone();
+                spawn_local(async move {
+                    let navigator = window().navigator();
+                    let Ok(battery) = js::call_async(&navigator, "getBattery", &[]).await else {
+                        return;
+                    };
+                    if !alive.get() {
+                        return;
+                    }
+                    let battery: BatteryManager = battery.unchecked_into();
+                    state.set(Some(BatteryState::read(&battery)));
+                    *listeners.borrow_mut() = EVENTS
+                        .iter()
+                        .map(|event| {
+                            let state = state.clone();
+                            let target = battery.clone();
+                            EventListener::new(&battery, *event, move |_| {
+                                state.set(Some(BatteryState::read(&target)));
+                            })
+                        })
+                        .collect();
+                });
+            }
+            move || {
+                alive.set(false);
+                listeners.borrow_mut().clear();
+            }
+        });
+    }
+
+    *state
+}
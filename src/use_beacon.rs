@@ -0,0 +1,225 @@
+#![cfg(feature = "serde")]
+
+use gloo::{
+    events::EventListener,
+    utils::{document, window},
+};
+use log::warn;
+use serde::Serialize;
+use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use yew::prelude::*;
+
+/// Browsers reject beacons over 64 KiB (shared by all beacons in flight).
+const MAX_BEACON_BYTES: usize = 64 * 1024;
+
+/// Options for the [`use_beacon`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeaconOptions {
+    /// Endpoint receiving the payloads as a JSON array.
+    pub url: String,
+    /// Flush as soon as this many payloads are queued; `0` only flushes when
+    /// the page is hidden or unloaded.
+    pub max_queue: usize,
+}
+
+impl Default for BeaconOptions {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            max_queue: 20,
+        }
+    }
+}
+
+struct Queue {
+    url: String,
+    /// Serialized payloads.
+    items: Vec<String>,
+}
+
+impl Queue {
+    /// Send every queued payload, in batches that fit a beacon. Payloads that
+    /// could not be sent stay queued for the next flush.
+    fn flush(&mut self) {
+        let mut unsent = Vec::new();
+        let mut batch: Vec<String> = Vec::new();
+        let mut size = 2;
+        for item in std::mem::take(&mut self.items) {
+            if !batch.is_empty() && size + item.len() + 1 > MAX_BEACON_BYTES {
+                let full = std::mem::take(&mut batch);
+                if !send(&self.url, &full) {
+                    unsent.extend(full);
+                }
+                size = 2;
+            }
+            size += item.len() + 1;
+            batch.push(item);
+        }
+        if !batch.is_empty() && !send(&self.url, &batch) {
+            unsent.extend(batch);
+        }
+        self.items = unsent;
+    }
+}
+
+fn send(url: &str, batch: &[String]) -> bool {
+    let body = format!("[{}]", batch.join(","));
+    match window()
+        .navigator()
+        .send_beacon_with_opt_str(url, Some(&body))
+    {
+        Ok(true) => true,
+        Ok(false) => {
+            warn!("Beacon to {url} was not queued by the browser");
+            false
+        }
+        Err(e) => {
+            warn!("sendBeacon failed: {e:?}");
+            false
+        }
+    }
+}
+
+/// State handle for the [`use_beacon`] hook.
+pub struct UseBeaconHandle<T> {
+    queue: Rc<RefCell<Queue>>,
+    max_queue: usize,
+    _payload: PhantomData<T>,
+}
+
+impl<T> UseBeaconHandle<T>
+where
+    T: Serialize,
+{
+    /// Queue `payload`, flushing if the queue is full. Payloads too large
+    /// for a beacon on their own are dropped.
+    pub fn push(&self, payload: &T) {
+        let json = match serde_json::to_string(payload) {
+            Ok(json) => json,
+            Err(e) => {
+                warn!("Failed to serialize beacon payload: {e}");
+                return;
+            }
+        };
+        // Sent alone, the payload is wrapped in `[` and `]`.
+        if json.len() + 2 > MAX_BEACON_BYTES {
+            warn!(
+                "Dropping beacon payload of {} bytes, over the {MAX_BEACON_BYTES} byte limit",
+                json.len()
+            );
+            return;
+        }
+        let mut queue = self.queue.borrow_mut();
+        queue.items.push(json);
+        if self.max_queue > 0 && queue.items.len() >= self.max_queue {
+            queue.flush();
+        }
+    }
+}
+
+impl<T> UseBeaconHandle<T> {
+    /// Send every queued payload now.
+    pub fn flush(&self) {
+        self.queue.borrow_mut().flush();
+    }
+
+    /// Number of payloads waiting to be sent.
+    pub fn queued(&self) -> usize {
+        self.queue.borrow().items.len()
+    }
+}
+
+impl<T> Clone for UseBeaconHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            max_queue: self.max_queue,
+            _payload: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for UseBeaconHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.queue, &other.queue) && self.max_queue == other.max_queue
+    }
+}
+
+/// A hook that queues small analytics/telemetry payloads and delivers them
+/// with `navigator.sendBeacon`, which survives the page being closed.
+///
+/// The queue is flushed when the page is hidden (`visibilitychange`), on
+/// `pagehide`, when it reaches [`BeaconOptions::max_queue`] and on unmount.
+/// Payloads are sent as a JSON array, split into batches that fit the
+/// browser's beacon size limit. Queueing does not re-render the component.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::Serialize;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Serialize)]
+/// struct Event {
+///     name: &'static str,
+/// }
+///
+/// #[function_component(SignupButton)]
+/// fn signup_button() -> Html {
+///     let analytics = use_beacon::<Event>(BeaconOptions {
+///         url: "/analytics".to_string(),
+///         ..Default::default()
+///     });
+///
+///     let onclick = {
+///         let analytics = analytics.clone();
+///         Callback::from(move |_| analytics.push(&Event { name: "signup_click" }))
+///     };
+///
+///     html! { <button {onclick}>{ "Sign up" }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_beacon<T>(options: BeaconOptions) -> UseBeaconHandle<T>
+where
+    T: Serialize + 'static,
+{
+    let queue = use_mut_ref(|| Queue {
+        url: options.url.clone(),
+        items: Vec::new(),
+    });
+    queue.borrow_mut().url = options.url;
+
+    {
+        let queue = queue.clone();
+        use_effect_with((), move |_| {
+            let visibility = {
+                let queue = queue.clone();
+                EventListener::new(&document(), "visibilitychange", move |_| {
+                    if document().hidden() {
+                        queue.borrow_mut().flush();
+                    }
+                })
+            };
+            let pagehide = {
+                let queue = queue.clone();
+                EventListener::new(&window(), "pagehide", move |_| {
+                    queue.borrow_mut().flush();
+                })
+            };
+            let listeners = [visibility, pagehide];
+            move || {
+                drop(listeners);
+                queue.borrow_mut().flush();
+            }
+        });
+    }
+
+    UseBeaconHandle {
+        queue,
+        max_queue: options.max_queue,
+        _payload: PhantomData,
+    }
+}
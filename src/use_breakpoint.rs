@@ -0,0 +1,139 @@
+use gloo::{events::EventListener, utils::window};
+use yew::prelude::*;
+
+use crate::use_media_query::matches;
+
+/// Default named breakpoints, ordered from narrowest to widest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Below `576px`.
+    #[default]
+    Xs,
+    /// From `576px`.
+    Sm,
+    /// From `768px`.
+    Md,
+    /// From `992px`.
+    Lg,
+    /// From `1200px`.
+    Xl,
+}
+
+/// Breakpoints for the [`use_breakpoint`] hook: the value for the narrowest
+/// viewports and the minimum widths at which each wider one starts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakpoints<B> {
+    /// The breakpoint below the smallest `min_widths` entry.
+    pub base: B,
+    /// Minimum viewport widths in CSS pixels and their breakpoints; the
+    /// widest matching entry wins, whatever the order.
+    pub min_widths: Vec<(u32, B)>,
+}
+
+impl Default for Breakpoints<Breakpoint> {
+    fn default() -> Self {
+        Self {
+            base: Breakpoint::Xs,
+            min_widths: vec![
+                (576, Breakpoint::Sm),
+                (768, Breakpoint::Md),
+                (992, Breakpoint::Lg),
+                (1200, Breakpoint::Xl),
+            ],
+        }
+    }
+}
+
+impl<B: Clone> Breakpoints<B> {
+    fn query(width: u32) -> String {
+        format!("(min-width: {width}px)")
+    }
+
+    fn current(&self) -> B {
+        self.min_widths
+            .iter()
+            .filter(|(width, _)| matches(&Self::query(*width)))
+            .max_by_key(|(width, _)| *width)
+            .map_or_else(|| self.base.clone(), |(_, breakpoint)| breakpoint.clone())
+    }
+}
+
+/// A sensor hook that returns the named breakpoint the viewport width falls
+/// in, and re-renders when it changes, so layouts can switch component
+/// trees rather than only CSS.
+///
+/// Pass [`Breakpoints::default()`] for the [`Breakpoint`] scale (`Xs` to
+/// `Xl`, matching Bootstrap's widths), or your own widths and type. Widths
+/// are evaluated as `(min-width: Npx)` media queries, so they agree with the
+/// stylesheet, and only crossing one re-renders.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Layout)]
+/// fn layout() -> Html {
+///     let breakpoint = use_breakpoint(Breakpoints::default());
+///
+///     html! {
+///         if breakpoint >= Breakpoint::Md {
+///             <aside>{ "Sidebar" }</aside>
+///         } else {
+///             <details><summary>{ "Menu" }</summary></details>
+///         }
+///     }
+/// }
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Density {
+///     Compact,
+///     Comfortable,
+/// }
+///
+/// #[function_component(Table)]
+/// fn table() -> Html {
+///     let density = use_breakpoint(Breakpoints {
+///         base: Density::Compact,
+///         min_widths: vec![(1024, Density::Comfortable)],
+///     });
+///
+///     html! {
+///         <table class={if density == Density::Compact { "compact" } else { "" }} />
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_breakpoint<B>(breakpoints: Breakpoints<B>) -> B
+where
+    B: Clone + PartialEq + 'static,
+{
+    let current = use_state_eq(|| breakpoints.current());
+
+    {
+        let current = current.clone();
+        use_effect_with(breakpoints, move |breakpoints| {
+            current.set(breakpoints.current());
+            let listeners: Vec<EventListener> = breakpoints
+                .min_widths
+                .iter()
+                .filter_map(|(width, _)| {
+                    let list = window()
+                        .match_media(&Breakpoints::<B>::query(*width))
+                        .ok()
+                        .flatten()?;
+                    let current = current.clone();
+                    let breakpoints = breakpoints.clone();
+                    Some(EventListener::new(&list, "change", move |_| {
+                        current.set(breakpoints.current())
+                    }))
+                })
+                .collect();
+            move || drop(listeners)
+        });
+    }
+
+    (*current).clone()
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "serde")]
+
+use gloo::events::EventListener;
+use log::warn;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{BroadcastChannel, MessageEvent};
+use yew::prelude::*;
+
+use crate::js;
+
+/// When and by which tab a value was written; the later write wins, ties are
+/// broken by tab id.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+struct Stamp {
+    time: f64,
+    tab: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum Message<T> {
+    /// A tab joined and asks the others for the current value.
+    Hello,
+    /// The current value, sent on every write and in reply to `Hello`.
+    State { value: T, stamp: Stamp },
+}
+
+struct Shared {
+    channel: Option<BroadcastChannel>,
+    stamp: Option<Stamp>,
+    /// The last `State` message sent or adopted, replayed to joining tabs.
+    state: Option<JsValue>,
+}
+
+impl Shared {
+    fn post(&self, message: &JsValue) {
+        if let Some(channel) = &self.channel
+            && let Err(e) = channel.post_message(message)
+        {
+            warn!("Failed to broadcast state: {e:?}");
+        }
+    }
+}
+
+/// State handle for the [`use_broadcast_state`] hook.
+pub struct UseBroadcastStateHandle<T> {
+    inner: UseStateHandle<T>,
+    shared: Rc<RefCell<Shared>>,
+    tab_id: Rc<String>,
+}
+
+impl<T> UseBroadcastStateHandle<T>
+where
+    T: Serialize,
+{
+    /// Set the value in this tab and every other tab on the channel.
+    pub fn set(&self, value: T) {
+        let stamp = Stamp {
+            time: js_sys::Date::now(),
+            tab: (*self.tab_id).clone(),
+        };
+        let message = Message::State {
+            value: &value,
+            stamp: stamp.clone(),
+        };
+        let mut shared = self.shared.borrow_mut();
+        match js::to_js(&message) {
+            Ok(message) => {
+                shared.post(&message);
+                shared.state = Some(message);
+            }
+            Err(e) => warn!("Failed to serialize broadcast state: {e}"),
+        }
+        shared.stamp = Some(stamp);
+        self.inner.set(value);
+    }
+
+    /// A random id identifying this tab (and hook instance) on the channel,
+    /// e.g. to store "which tab owns the audio".
+    pub fn tab_id(&self) -> &str {
+        &self.tab_id
+    }
+}
+
+impl<T> Deref for UseBroadcastStateHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseBroadcastStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            shared: self.shared.clone(),
+            tab_id: self.tab_id.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseBroadcastStateHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner && self.tab_id == other.tab_id
+    }
+}
+
+/// A hook that keeps a value in sync across every tab of the same origin via
+/// a `BroadcastChannel`, without persisting it.
+///
+/// The last write wins. A newly opened tab asks the others for the current
+/// value and starts from `initial` until one replies. Useful for ephemeral
+/// coordination, e.g. which tab is playing audio. Where `BroadcastChannel` is
+/// unsupported the value is local to the tab.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Player)]
+/// fn player() -> Html {
+///     let playing = use_broadcast_state::<Option<String>>("audio".to_string(), || None);
+///     let is_playing_here = playing.as_deref() == Some(playing.tab_id());
+///
+///     let onplay = {
+///         let playing = playing.clone();
+///         Callback::from(move |_| playing.set(Some(playing.tab_id().to_string())))
+///     };
+///
+///     html! {
+///         <button onclick={onplay} disabled={is_playing_here}>
+///             { if is_playing_here { "Playing" } else { "Play here" } }
+///         </button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_broadcast_state<T>(
+    channel: String,
+    initial: impl FnOnce() -> T,
+) -> UseBroadcastStateHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let inner = use_state(initial);
+    let shared = use_mut_ref(|| Shared {
+        channel: None,
+        stamp: None,
+        state: None,
+    });
+    let tab_id = use_memo((), |_| {
+        format!("{:x}", (js_sys::Math::random() * u64::MAX as f64) as u64)
+    });
+
+    {
+        let inner = inner.clone();
+        let shared = shared.clone();
+        use_effect_with(channel, move |name| {
+            let listener = BroadcastChannel::new(name).ok().map(|channel| {
+                let listener = {
+                    let shared = shared.clone();
+                    EventListener::new(&channel, "message", move |e| {
+                        let e: &MessageEvent = e.unchecked_ref();
+                        let message = match js::from_js::<Message<T>>(&e.data()) {
+                            Ok(message) => message,
+                            Err(e) => {
+                                warn!("Ignoring broadcast state message: {e}");
+                                return;
+                            }
+                        };
+                        let mut shared = shared.borrow_mut();
+                        match message {
+                            Message::Hello => {
+                                if let Some(state) = &shared.state {
+                                    shared.post(state);
+                                }
+                            }
+                            Message::State { value, stamp } => {
+                                if shared.stamp.as_ref().is_none_or(|s| stamp > *s) {
+                                    shared.stamp = Some(stamp);
+                                    shared.state = Some(e.data());
+                                    inner.set(value);
+                                }
+                            }
+                        }
+                    })
+                };
+                let mut shared = shared.borrow_mut();
+                shared.channel = Some(channel);
+                if let Ok(hello) = js::to_js(&Message::<()>::Hello) {
+                    shared.post(&hello);
+                }
+                listener
+            });
+            move || {
+                drop(listener);
+                if let Some(channel) = shared.borrow_mut().channel.take() {
+                    channel.close();
+                }
+            }
+        });
+    }
+
+    UseBroadcastStateHandle {
+        inner,
+        shared,
+        tab_id,
+    }
+}
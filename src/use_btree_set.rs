@@ -104,7 +104,7 @@ where
 /// # use std::collections::BTreeSet;
 /// # use yew::prelude::*;
 /// #
-/// use yew_hooks::prelude::*;
+/// use more_yew_hooks::*;
 ///
 /// #[function_component(UseSet)]
 /// fn set() -> Html {
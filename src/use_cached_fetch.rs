@@ -0,0 +1,198 @@
+#![cfg(feature = "storage")]
+
+use serde::{Serialize, de::DeserializeOwned};
+use std::{cell::Cell, future::Future, ops::Deref, rc::Rc};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_latest;
+
+use crate::{UseLocalStorageDefaultHandle, inflight::dedupe, use_local_storage_default};
+
+/// State handle for the [`use_cached_fetch`] hook.
+pub struct UseCachedFetchHandle<T, E> {
+    data: UseLocalStorageDefaultHandle<Option<T>>,
+    loading: UseStateHandle<bool>,
+    /// The failed result of the last revalidation, shared with every hook
+    /// that joined the same request.
+    error: UseStateHandle<Option<Rc<Result<T, E>>>>,
+    revalidate: Rc<dyn Fn()>,
+}
+
+impl<T, E> UseCachedFetchHandle<T, E> {
+    /// Whether a revalidation request is in flight.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// The error from the last revalidation, if it failed.
+    /// The cached value is kept when revalidation fails.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_deref()?.as_ref().err()
+    }
+
+    /// Fetch a fresh response and write it back to the cache.
+    pub fn revalidate(&self) {
+        (self.revalidate)();
+    }
+
+    /// Replace the cached value without fetching, e.g. for optimistic updates.
+    pub fn set(&self, value: T)
+    where
+        T: Serialize + Clone,
+    {
+        self.data.set(Some(value));
+    }
+
+    /// Drop the cached response.
+    pub fn delete(&self) {
+        self.data.delete();
+    }
+}
+
+impl<T, E> Deref for UseCachedFetchHandle<T, E> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T, E> Clone for UseCachedFetchHandle<T, E>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            loading: self.loading.clone(),
+            error: self.error.clone(),
+            revalidate: self.revalidate.clone(),
+        }
+    }
+}
+
+impl<T, E> PartialEq for UseCachedFetchHandle<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && *self.loading == *other.loading && self.error() == other.error()
+    }
+}
+
+/// A stale-while-revalidate fetch hook backed by `localStorage`.
+///
+/// Renders the persisted response for `key` immediately (stale, or `None` on
+/// first visit) and calls `fetcher` on mount, and whenever `key` changes, to
+/// revalidate; `fetcher` should fetch the resource for the current `key`. A successful
+/// response is written back to `localStorage`, and other tabs pick it up via
+/// `storage` events. Components revalidating the same `key` at the same time
+/// share a single `fetcher` call.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// struct Profile {
+///     name: String,
+/// }
+///
+/// async fn fetch_profile() -> Result<Profile, String> {
+///     Ok(Profile { name: "Ada".to_string() })
+/// }
+///
+/// #[function_component(CachedProfile)]
+/// fn cached_profile() -> Html {
+///     let profile = use_cached_fetch("profile".to_string(), fetch_profile);
+///
+///     let onrefresh = {
+///         let profile = profile.clone();
+///         Callback::from(move |_| profile.revalidate())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onrefresh} disabled={profile.loading()}>{ "Refresh" }</button>
+///             {
+///                 if let Some(profile) = &*profile {
+///                     html! { <p>{ &profile.name }</p> }
+///                 } else {
+///                     html! { <p>{ "Loading..." }</p> }
+///                 }
+///             }
+///             {
+///                 if let Some(error) = profile.error() {
+///                     html! { <p>{ error }</p> }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_cached_fetch<T, E, F, Fut>(key: String, fetcher: F) -> UseCachedFetchHandle<T, E>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let data = use_local_storage_default::<Option<T>>(key.clone());
+    let loading = use_state(|| false);
+    let error = use_state(|| None);
+    let fetcher = use_latest(fetcher);
+    // Bumped by every revalidation, so a response for a previous key (or an
+    // overtaken request) doesn't overwrite the current one.
+    let generation = use_memo((), |_| Cell::new(0u64));
+
+    let revalidate: Rc<dyn Fn()> = {
+        let key = key.clone();
+        let data = data.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        Rc::new(move || {
+            let fetcher = fetcher.clone();
+            let key = key.clone();
+            let data = data.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let generation = generation.clone();
+            let current = generation.get() + 1;
+            generation.set(current);
+            loading.set(true);
+            spawn_local(async move {
+                let result = dedupe(&key, || (*fetcher.current())()).await;
+                if generation.get() != current {
+                    return;
+                }
+                match &*result {
+                    Ok(value) => {
+                        data.set(Some(value.clone()));
+                        error.set(None);
+                    }
+                    Err(_) => error.set(Some(result)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let revalidate = revalidate.clone();
+        use_effect_with(key, move |_| revalidate());
+    }
+
+    UseCachedFetchHandle {
+        data,
+        loading,
+        error,
+        revalidate,
+    }
+}
@@ -0,0 +1,223 @@
+use gloo::{timers::callback::Timeout, utils::window};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, ClipboardItem, PermissionState};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{js, use_permission};
+
+/// Options for the [`use_clipboard`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipboardOptions {
+    /// How long [`copied`](UseClipboardHandle::copied) stays `true` after a
+    /// successful write.
+    pub copied_reset: Duration,
+}
+
+impl Default for ClipboardOptions {
+    fn default() -> Self {
+        Self {
+            copied_reset: Duration::from_secs(2),
+        }
+    }
+}
+
+fn is_supported() -> bool {
+    js::has(&window().navigator(), "clipboard")
+}
+
+fn error_message(e: &wasm_bindgen::JsValue) -> String {
+    js::get(e, "message")
+        .and_then(|message| message.as_string())
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+/// State handle for the [`use_clipboard`] hook.
+#[derive(Clone)]
+pub struct UseClipboardHandle {
+    text: UseStateHandle<Option<String>>,
+    copied: UseStateHandle<bool>,
+    error: UseStateHandle<Option<String>>,
+    read_permission: Option<PermissionState>,
+    reset: Rc<RefCell<Option<Timeout>>>,
+    copied_reset: Duration,
+}
+
+impl UseClipboardHandle {
+    /// Whether the async Clipboard API is available; it requires a secure
+    /// context.
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// The text of the last successful [`read_text`](Self::read_text).
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Whether a write succeeded within the last
+    /// [`copied_reset`](ClipboardOptions::copied_reset), for "Copied!" feedback.
+    pub fn copied(&self) -> bool {
+        *self.copied
+    }
+
+    /// The error of the last read or write, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The `clipboard-read` permission; `None` where it cannot be queried
+    /// (e.g. Firefox and Safari, which ask on every read instead).
+    pub fn read_permission(&self) -> Option<PermissionState> {
+        self.read_permission
+    }
+
+    /// Copy `text` to the clipboard. Call from a user gesture.
+    pub fn write_text(&self, text: &str) {
+        if !is_supported() {
+            self.error
+                .set(Some("Clipboard API is not supported".to_string()));
+            return;
+        }
+        let promise = window().navigator().clipboard().write_text(text);
+        self.written(promise);
+    }
+
+    /// Copy `blob` (e.g. a PNG image) to the clipboard under its MIME type.
+    /// Browsers only accept a few types, `image/png` being the portable one.
+    /// Call from a user gesture.
+    pub fn write_blob(&self, blob: Blob) {
+        if !is_supported() {
+            self.error
+                .set(Some("Clipboard API is not supported".to_string()));
+            return;
+        }
+        let record = js::object(&[(&blob.type_(), blob.into())]);
+        let item = match ClipboardItem::new_with_record_from_str_to_blob_promise(&record) {
+            Ok(item) => item,
+            Err(e) => {
+                self.error.set(Some(error_message(&e)));
+                return;
+            }
+        };
+        let items = js_sys::Array::of1(&item);
+        let promise = window().navigator().clipboard().write(&items);
+        self.written(promise);
+    }
+
+    /// Read the clipboard's text into [`text`](Self::text). May prompt for
+    /// permission.
+    pub fn read_text(&self) {
+        if !is_supported() {
+            self.error
+                .set(Some("Clipboard API is not supported".to_string()));
+            return;
+        }
+        let promise = window().navigator().clipboard().read_text();
+        let text = self.text.clone();
+        let error = self.error.clone();
+        spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(value) => {
+                    text.set(value.as_string());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(error_message(&e))),
+            }
+        });
+    }
+
+    /// Track the outcome of a write.
+    fn written(&self, promise: js_sys::Promise) {
+        let copied = self.copied.clone();
+        let error = self.error.clone();
+        let reset = self.reset.clone();
+        let millis = u32::try_from(self.copied_reset.as_millis()).unwrap_or(u32::MAX);
+        spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(_) => {
+                    copied.set(true);
+                    error.set(None);
+                    let copied = copied.clone();
+                    *reset.borrow_mut() = Some(Timeout::new(millis, move || copied.set(false)));
+                }
+                Err(e) => {
+                    copied.set(false);
+                    error.set(Some(error_message(&e)));
+                }
+            }
+        });
+    }
+}
+
+impl PartialEq for UseClipboardHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.copied == other.copied
+            && self.error == other.error
+            && self.read_permission == other.read_permission
+            && self.copied_reset == other.copied_reset
+    }
+}
+
+/// A hook wrapping the async Clipboard API: copy text or images, read text,
+/// and show "Copied!" feedback through a [`copied`](UseClipboardHandle::copied)
+/// flag that resets itself after [`ClipboardOptions::copied_reset`].
+///
+/// Reads and writes run in the background and report through the handle's
+/// state. The `clipboard-read` permission is followed where browsers expose
+/// it, so a "Paste" button can be hidden once it is denied.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     snippet: AttrValue,
+/// }
+///
+/// #[function_component(CopyButton)]
+/// fn copy_button(props: &Props) -> Html {
+///     let clipboard = use_clipboard(ClipboardOptions::default());
+///
+///     let onclick = {
+///         let clipboard = clipboard.clone();
+///         let snippet = props.snippet.clone();
+///         Callback::from(move |_| clipboard.write_text(&snippet))
+///     };
+///
+///     html! {
+///         if clipboard.is_supported() {
+///             <button {onclick}>
+///                 { if clipboard.copied() { "Copied!" } else { "Copy" } }
+///             </button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_clipboard(options: ClipboardOptions) -> UseClipboardHandle {
+    let text = use_state(|| None);
+    let copied = use_state_eq(|| false);
+    let error = use_state(|| None);
+    let read_permission = use_permission("clipboard-read".to_string());
+    let reset = use_mut_ref(|| None);
+
+    {
+        let reset = reset.clone();
+        use_effect_with((), move |_| move || drop(reset.borrow_mut().take()));
+    }
+
+    UseClipboardHandle {
+        text,
+        copied,
+        error,
+        read_permission,
+        reset,
+        copied_reset: options.copied_reset,
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    cell::{Cell, Ref, RefCell},
+    rc::Rc,
+};
+use web_sys::{ClipboardEvent, DataTransfer, DragEvent, File};
+use yew::prelude::*;
+use yew_hooks::{use_event, use_update};
+
+use crate::upload::{self, Upload, UploadOptions, UploadStatus};
+
+/// A file captured by [`use_clipboard_files_upload`] and its upload status.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedUpload {
+    /// Identifier to [`cancel`](UseClipboardFilesUploadHandle::cancel) the upload with.
+    pub id: u64,
+    /// The pasted or dropped file, e.g. for rendering a thumbnail.
+    pub file: File,
+    /// Current upload status.
+    pub status: UploadStatus,
+}
+
+struct Pipeline {
+    options: RefCell<UploadOptions>,
+    queue: RefCell<Vec<QueuedUpload>>,
+    active: RefCell<Option<(u64, Upload)>>,
+    next_id: Cell<u64>,
+    dragging: Cell<bool>,
+    /// Set on unmount, so no further upload is started.
+    closed: Cell<bool>,
+    /// Bumped on every change, so handles from different renders compare unequal.
+    version: Cell<u64>,
+    update: Rc<dyn Fn()>,
+}
+
+impl Pipeline {
+    fn changed(&self) {
+        self.version.set(self.version.get() + 1);
+        (self.update)();
+    }
+
+    fn set_status(&self, id: u64, status: UploadStatus) {
+        if let Some(item) = self.queue.borrow_mut().iter_mut().find(|i| i.id == id) {
+            item.status = status;
+        }
+        self.changed();
+    }
+
+    fn enqueue(self: &Rc<Self>, files: Vec<File>) {
+        if files.is_empty() {
+            return;
+        }
+        for file in files {
+            let id = self.next_id.get();
+            self.next_id.set(id + 1);
+            self.queue.borrow_mut().push(QueuedUpload {
+                id,
+                file,
+                status: UploadStatus::Queued,
+            });
+        }
+        self.changed();
+        self.pump();
+    }
+
+    /// Start the next queued upload unless one is already running.
+    fn pump(self: &Rc<Self>) {
+        if self.closed.get() || self.active.borrow().is_some() {
+            return;
+        }
+        let next = self
+            .queue
+            .borrow()
+            .iter()
+            .find(|i| i.status == UploadStatus::Queued)
+            .map(|i| (i.id, i.file.clone()));
+        let Some((id, file)) = next else {
+            return;
+        };
+        self.set_status(
+            id,
+            UploadStatus::Uploading {
+                loaded: 0.0,
+                total: file.size(),
+            },
+        );
+
+        let on_progress = {
+            let pipeline = self.clone();
+            move |loaded, total| pipeline.set_status(id, UploadStatus::Uploading { loaded, total })
+        };
+        let on_done = {
+            let pipeline = self.clone();
+            move |status| {
+                pipeline.active.take();
+                pipeline.set_status(id, status);
+                pipeline.pump();
+            }
+        };
+        let options = self.options.borrow().clone();
+        match upload::start(&options, &file, &file.name(), on_progress, on_done) {
+            Ok(upload) => *self.active.borrow_mut() = Some((id, upload)),
+            Err(e) => {
+                self.set_status(id, UploadStatus::Failed(e));
+                self.pump();
+            }
+        }
+    }
+}
+
+/// The files carried by a paste or drop.
+pub(crate) fn files(data: Option<DataTransfer>) -> Vec<File> {
+    let Some(list) = data.and_then(|data| data.files()) else {
+        return Vec::new();
+    };
+    (0..list.length()).filter_map(|i| list.get(i)).collect()
+}
+
+/// State handle for the [`use_clipboard_files_upload`] hook.
+#[derive(Clone)]
+pub struct UseClipboardFilesUploadHandle {
+    pipeline: Rc<Pipeline>,
+    version: u64,
+}
+
+impl UseClipboardFilesUploadHandle {
+    /// Every captured file, in the order it was queued.
+    pub fn items(&'_ self) -> Ref<'_, Vec<QueuedUpload>> {
+        self.pipeline.queue.borrow()
+    }
+
+    /// Whether files are currently being dragged over the drop zone.
+    pub fn is_dragging(&self) -> bool {
+        self.pipeline.dragging.get()
+    }
+
+    /// Queue files from another source, e.g. an `<input type="file">`.
+    pub fn add_files(&self, files: Vec<File>) {
+        self.pipeline.enqueue(files);
+    }
+
+    /// Cancel a queued or running upload.
+    pub fn cancel(&self, id: u64) {
+        let is_active =
+            matches!(&*self.pipeline.active.borrow(), Some((active_id, _)) if *active_id == id);
+        if is_active {
+            // Taken out first: the `abort` event fires synchronously and
+            // finishes the upload through the pipeline.
+            if let Some((_, upload)) = self.pipeline.active.take() {
+                upload.abort();
+            }
+        } else {
+            self.pipeline.set_status(id, UploadStatus::Cancelled);
+        }
+    }
+
+    /// Remove finished (done, failed or cancelled) uploads from [`items`](Self::items).
+    pub fn clear_finished(&self) {
+        self.pipeline
+            .queue
+            .borrow_mut()
+            .retain(|item| !item.status.is_finished());
+        self.pipeline.changed();
+    }
+}
+
+impl PartialEq for UseClipboardFilesUploadHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.pipeline, &other.pipeline) && self.version == other.version
+    }
+}
+
+/// A hook that turns `node` into a paste target and drop zone: every file or
+/// image pasted or dropped onto it is queued and uploaded one at a time, with
+/// progress reported per file.
+///
+/// Pasting plain text is left alone; only pastes that carry files are captured.
+/// The running upload is aborted when the component unmounts.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Attachments)]
+/// fn attachments() -> Html {
+///     let zone = use_node_ref();
+///     let uploads = use_clipboard_files_upload(
+///         zone.clone(),
+///         UploadOptions {
+///             url: "/api/attachments".to_string(),
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <div ref={zone} tabindex="0" class={classes!(uploads.is_dragging().then_some("dragging"))}>
+///             { "Paste or drop files here" }
+///             <ul>
+///                 {
+///                     for uploads.items().iter().map(|item| html! {
+///                         <li>
+///                             { item.file.name() }
+///                             { format!(" {:.0}%", item.status.progress().unwrap_or(0.0) * 100.0) }
+///                         </li>
+///                     })
+///                 }
+///             </ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_clipboard_files_upload(
+    node: NodeRef,
+    options: UploadOptions,
+) -> UseClipboardFilesUploadHandle {
+    let update = use_update();
+    let pipeline = use_memo((), |_| Pipeline {
+        options: RefCell::new(options.clone()),
+        queue: RefCell::new(Vec::new()),
+        active: RefCell::new(None),
+        next_id: Cell::new(0),
+        dragging: Cell::new(false),
+        closed: Cell::new(false),
+        version: Cell::new(0),
+        update,
+    });
+    *pipeline.options.borrow_mut() = options;
+
+    {
+        let pipeline = pipeline.clone();
+        use_event(node.clone(), "paste", move |e: ClipboardEvent| {
+            let files = files(e.clipboard_data());
+            if !files.is_empty() {
+                e.prevent_default();
+                pipeline.enqueue(files);
+            }
+        });
+    }
+    {
+        let pipeline = pipeline.clone();
+        use_event(node.clone(), "dragover", move |e: DragEvent| {
+            // Required for the element to accept drops.
+            e.prevent_default();
+            if !pipeline.dragging.replace(true) {
+                pipeline.changed();
+            }
+        });
+    }
+    {
+        let pipeline = pipeline.clone();
+        use_event(node.clone(), "dragleave", move |_: DragEvent| {
+            if pipeline.dragging.replace(false) {
+                pipeline.changed();
+            }
+        });
+    }
+    {
+        let pipeline = pipeline.clone();
+        use_event(node, "drop", move |e: DragEvent| {
+            e.prevent_default();
+            pipeline.dragging.set(false);
+            pipeline.enqueue(files(e.data_transfer()));
+            pipeline.changed();
+        });
+    }
+    {
+        let pipeline = pipeline.clone();
+        use_effect_with((), move |_| {
+            move || {
+                pipeline.closed.set(true);
+                if let Some((_, upload)) = pipeline.active.take() {
+                    upload.abort();
+                }
+            }
+        });
+    }
+
+    let version = pipeline.version.get();
+    UseClipboardFilesUploadHandle { pipeline, version }
+}
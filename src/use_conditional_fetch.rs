@@ -0,0 +1,231 @@
+use gloo::net::http::Request;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use web_sys::RequestCache;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::inflight::dedupe;
+
+/// The last full response for a URL, with its validators.
+#[derive(Clone)]
+struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: Rc<String>,
+}
+
+thread_local! {
+    /// Validated responses by URL, shared by every component.
+    static CACHE: RefCell<HashMap<String, CachedResponse>> = RefCell::new(HashMap::new());
+}
+
+/// Outcome of one conditional request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionalFetchResult {
+    /// `200`: a new body was received and cached.
+    Modified(Rc<String>),
+    /// `304`: the cached body is still current.
+    NotModified(Rc<String>),
+}
+
+impl ConditionalFetchResult {
+    /// The body, new or cached.
+    pub fn body(&self) -> &Rc<String> {
+        match self {
+            Self::Modified(body) | Self::NotModified(body) => body,
+        }
+    }
+}
+
+/// The cached body of `url`, as a not-modified result.
+fn cached(url: &str) -> Option<ConditionalFetchResult> {
+    CACHE.with(|cache| {
+        let cache = cache.borrow();
+        let cached = cache.get(url)?;
+        Some(ConditionalFetchResult::NotModified(cached.body.clone()))
+    })
+}
+
+/// Request `url` with the validators of its cached response, if any.
+async fn fetch(url: &str) -> Result<ConditionalFetchResult, String> {
+    let cached = CACHE.with(|cache| cache.borrow().get(url).cloned());
+    // The browser's HTTP cache would answer 304s itself and hide them.
+    let mut request = Request::get(url).cache(RequestCache::NoStore);
+    if let Some(cached) = &cached {
+        if let Some(etag) = &cached.etag {
+            request = request.header("If-None-Match", etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.header("If-Modified-Since", last_modified);
+        }
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if response.status() == 304
+        && let Some(cached) = cached
+    {
+        return Ok(ConditionalFetchResult::NotModified(cached.body));
+    }
+    if !response.ok() {
+        return Err(format!("{} {}", response.status(), response.status_text()));
+    }
+    let headers = response.headers();
+    let body = Rc::new(response.text().await.map_err(|e| e.to_string())?);
+    CACHE.with(|cache| {
+        cache.borrow_mut().insert(
+            url.to_string(),
+            CachedResponse {
+                etag: headers.get("etag"),
+                last_modified: headers.get("last-modified"),
+                body: body.clone(),
+            },
+        )
+    });
+    Ok(ConditionalFetchResult::Modified(body))
+}
+
+/// State handle for the [`use_conditional_fetch`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseConditionalFetchHandle {
+    result: UseStateHandle<Option<ConditionalFetchResult>>,
+    error: UseStateHandle<Option<String>>,
+    loading: UseStateHandle<bool>,
+    refetch: Callback<()>,
+}
+
+impl UseConditionalFetchHandle {
+    /// The latest body; kept when a later request fails.
+    pub fn body(&self) -> Option<&str> {
+        self.result.as_ref().map(|result| result.body().as_str())
+    }
+
+    /// The outcome of the last successful request.
+    pub fn result(&self) -> Option<&ConditionalFetchResult> {
+        self.result.as_ref()
+    }
+
+    /// Whether the last successful request was answered with `304 Not Modified`.
+    pub fn not_modified(&self) -> bool {
+        matches!(*self.result, Some(ConditionalFetchResult::NotModified(_)))
+    }
+
+    /// The error of the last request, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Whether a request is in flight.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// Request the URL again, conditionally.
+    pub fn refetch(&self) {
+        self.refetch.emit(());
+    }
+}
+
+/// A fetch hook that remembers the `ETag` and `Last-Modified` validators of
+/// each URL and sends `If-None-Match` / `If-Modified-Since` on the next
+/// request, so an unchanged resource costs a bodiless `304` and the cached
+/// body keeps being served. Made for frequently polled endpoints.
+///
+/// Fetches on mount and when `url` changes, showing the cached body of the
+/// new URL, if any, meanwhile; responses to superseded requests are ignored.
+/// Call [`refetch`](UseConditionalFetchHandle::refetch) (e.g. from
+/// [`yew_hooks::use_interval`]) to poll. The validator cache lives in memory
+/// and is shared by all components, which also share requests in flight for
+/// the same URL. Cross-origin servers must expose the `ETag` header (CORS
+/// `Access-Control-Expose-Headers`).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use yew_hooks::use_interval;
+///
+/// #[function_component(Announcements)]
+/// fn announcements() -> Html {
+///     let feed = use_conditional_fetch("/api/announcements".to_string());
+///     {
+///         let feed = feed.clone();
+///         use_interval(move || feed.refetch(), 15_000);
+///     }
+///
+///     html! {
+///         <div>
+///             <pre>{ feed.body().unwrap_or("Loading...") }</pre>
+///             if let Some(error) = feed.error() {
+///                 <p class="error">{ error }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_conditional_fetch(url: String) -> UseConditionalFetchHandle {
+    let result = use_state(|| cached(&url));
+    let error = use_state(|| None);
+    let loading = use_state(|| false);
+    let generation = use_mut_ref(|| 0u64);
+    // The URL `result` belongs to.
+    let loaded = use_mut_ref(|| url.clone());
+
+    let refetch = {
+        let result = result.clone();
+        let error = error.clone();
+        let loading = loading.clone();
+        use_callback(url.clone(), move |_: (), url| {
+            let current = {
+                let mut generation = generation.borrow_mut();
+                *generation += 1;
+                *generation
+            };
+            let generation = generation.clone();
+            let url = url.clone();
+            let result = result.clone();
+            let error = error.clone();
+            let loading = loading.clone();
+            loading.set(true);
+            spawn_local(async move {
+                let response = dedupe(&url, || {
+                    let url = url.clone();
+                    async move { fetch(&url).await }
+                })
+                .await;
+                if *generation.borrow() != current {
+                    return;
+                }
+                match &*response {
+                    Ok(response) => {
+                        result.set(Some(response.clone()));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e.clone())),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let refetch = refetch.clone();
+        let result = result.clone();
+        let error = error.clone();
+        use_effect_with(url, move |url| {
+            if *loaded.borrow() != *url {
+                *loaded.borrow_mut() = url.clone();
+                result.set(cached(url));
+                error.set(None);
+            }
+            refetch.emit(());
+        });
+    }
+
+    UseConditionalFetchHandle {
+        result,
+        error,
+        loading,
+        refetch,
+    }
+}
@@ -0,0 +1,213 @@
+use futures::future::{Either, select};
+use gloo::{net::http::Request, timers::future::TimeoutFuture};
+use std::{collections::VecDeque, time::Duration};
+use web_sys::RequestCache;
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_interval;
+
+use crate::{
+    network_mock::{navigator_online, use_network_mock},
+    use_online,
+};
+
+/// Connection quality classified by [`use_connection_quality`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConnectionQuality {
+    /// No sample has completed yet.
+    #[default]
+    Unknown,
+    Good,
+    /// Usable, but slow, jittery or dropping some requests.
+    Degraded,
+    /// Latency or request loss high enough to hurt interactivity.
+    Poor,
+    /// `navigator.onLine` is `false`.
+    Offline,
+}
+
+/// Options for the [`use_connection_quality`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConnectionQualityOptions {
+    /// Endpoint to time; ideally same-origin, tiny and uncached (an empty
+    /// `204`). Any response counts, whatever its status.
+    pub url: String,
+    /// Sample this often.
+    pub interval: Duration,
+    /// Number of recent samples the classification is based on.
+    pub window: usize,
+    /// A sample slower than this counts as lost.
+    pub timeout: Duration,
+    /// Median latency (or jitter) above which the connection is degraded.
+    pub degraded_above: Duration,
+    /// Median latency above which the connection is poor.
+    pub poor_above: Duration,
+}
+
+impl Default for ConnectionQualityOptions {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            interval: Duration::from_secs(10),
+            window: 5,
+            timeout: Duration::from_secs(5),
+            degraded_above: Duration::from_millis(300),
+            poor_above: Duration::from_millis(1000),
+        }
+    }
+}
+
+/// Measurements returned by [`use_connection_quality`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConnectionQualityReport {
+    pub quality: ConnectionQuality,
+    /// Median round-trip time of the successful samples in the window.
+    pub latency: Option<Duration>,
+    /// Mean difference between consecutive successful round trips.
+    pub jitter: Option<Duration>,
+    /// Fraction of samples in the window that failed or timed out.
+    pub loss: f64,
+    /// Number of samples in the window.
+    pub samples: usize,
+}
+
+impl ConnectionQualityReport {
+    /// Summarize round-trip times in milliseconds, `None` for lost samples.
+    fn from_samples(samples: &VecDeque<Option<f64>>, options: &ConnectionQualityOptions) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let rtts: Vec<f64> = samples.iter().flatten().copied().collect();
+        let loss = 1.0 - rtts.len() as f64 / samples.len() as f64;
+        let latency = {
+            let mut sorted = rtts.clone();
+            sorted.sort_by(f64::total_cmp);
+            sorted.get(sorted.len() / 2).copied()
+        };
+        let jitter = (rtts.len() > 1).then(|| {
+            rtts.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f64>() / (rtts.len() - 1) as f64
+        });
+        let millis = |d: Duration| d.as_secs_f64() * 1000.0;
+        let quality = match latency {
+            _ if loss >= 0.5 => ConnectionQuality::Poor,
+            None => ConnectionQuality::Poor,
+            Some(latency) if latency > millis(options.poor_above) => ConnectionQuality::Poor,
+            Some(latency)
+                if loss > 0.0
+                    || latency > millis(options.degraded_above)
+                    || jitter.is_some_and(|j| j > millis(options.degraded_above)) =>
+            {
+                ConnectionQuality::Degraded
+            }
+            Some(_) => ConnectionQuality::Good,
+        };
+        let duration = |ms: f64| Duration::from_secs_f64(ms / 1000.0);
+        Self {
+            quality,
+            latency: latency.map(duration),
+            jitter: jitter.map(duration),
+            loss,
+            samples: samples.len(),
+        }
+    }
+}
+
+/// Time one request to `url`, in milliseconds; `None` if it failed or timed out.
+async fn sample(url: &str, timeout: Duration) -> Option<f64> {
+    let sent = js_sys::Date::now();
+    let request = Request::get(url).cache(RequestCache::NoStore).send();
+    let millis = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+    match select(Box::pin(request), TimeoutFuture::new(millis)).await {
+        Either::Left((Ok(_), _)) => Some(js_sys::Date::now() - sent),
+        Either::Left((Err(_), _)) | Either::Right(_) => None,
+    }
+}
+
+/// A hook that measures the connection by timing small periodic requests to
+/// an endpoint and classifies it as good, degraded or poor, so the UI can
+/// adapt (lower polling rates, warn before uploads) based on what the user
+/// actually experiences rather than the coarse, static estimates of the
+/// Network Information API.
+///
+/// The classification uses the median latency, jitter and request loss over
+/// the last [`ConnectionQualityOptions::window`] samples. Sampling pauses
+/// while the browser is offline.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ConnectionIndicator)]
+/// fn connection_indicator() -> Html {
+///     let report = use_connection_quality(ConnectionQualityOptions {
+///         url: "/ping".to_string(),
+///         ..Default::default()
+///     });
+///
+///     let label = match report.quality {
+///         ConnectionQuality::Unknown => "Measuring...",
+///         ConnectionQuality::Good => "Good connection",
+///         ConnectionQuality::Degraded => "Slow connection",
+///         ConnectionQuality::Poor => "Poor connection",
+///         ConnectionQuality::Offline => "Offline",
+///     };
+///
+///     html! {
+///         <p title={report.latency.map(|l| format!("{} ms", l.as_millis()))}>{ label }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_connection_quality(options: ConnectionQualityOptions) -> ConnectionQualityReport {
+    let forced = use_network_mock().online;
+    let online = use_online();
+    let report = use_state_eq(ConnectionQualityReport::default);
+    let samples = use_mut_ref(VecDeque::new);
+
+    let run = {
+        let report = report.clone();
+        let options = options.clone();
+        move || {
+            if !navigator_online(forced) {
+                return;
+            }
+            let report = report.clone();
+            let samples = samples.clone();
+            let options = options.clone();
+            spawn_local(async move {
+                let rtt = sample(&options.url, options.timeout).await;
+                // Requests failing because the browser went offline say
+                // nothing about the connection's quality.
+                if !navigator_online(forced) {
+                    return;
+                }
+                let mut samples = samples.borrow_mut();
+                samples.push_back(rtt);
+                while samples.len() > options.window.max(1) {
+                    samples.pop_front();
+                }
+                report.set(ConnectionQualityReport::from_samples(&samples, &options));
+            });
+        }
+    };
+
+    {
+        let run = run.clone();
+        use_effect_with((*online, options.url.clone()), move |_| run());
+    }
+    use_interval(
+        run,
+        u32::try_from(options.interval.as_millis()).unwrap_or(u32::MAX),
+    );
+
+    if *online {
+        *report
+    } else {
+        ConnectionQualityReport {
+            quality: ConnectionQuality::Offline,
+            ..*report
+        }
+    }
+}
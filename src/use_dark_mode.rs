@@ -0,0 +1,175 @@
+#![cfg(feature = "storage")]
+
+use gloo::utils::document;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::{
+    STORAGE_NAMESPACE, UseLocalStorageDefaultHandle, use_local_storage_default, use_media_query,
+};
+
+const PREFERS_DARK: &str = "(prefers-color-scheme: dark)";
+
+/// The user's choice in a [`use_dark_mode`] theme switcher.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorSchemePreference {
+    /// Follow the system's `prefers-color-scheme`.
+    #[default]
+    Auto,
+    Light,
+    Dark,
+}
+
+/// The color scheme in effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    /// `"light"` or `"dark"`, as used by CSS `color-scheme`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+/// Options for the [`use_dark_mode`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DarkModeOptions {
+    /// `localStorage` key holding the preference.
+    pub storage_key: String,
+    /// Attribute set on `<html>` to the effective scheme (`light` or `dark`),
+    /// for selectors like `[data-theme="dark"]`; `None` leaves `<html>` alone.
+    pub attribute: Option<String>,
+}
+
+impl Default for DarkModeOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: format!("{STORAGE_NAMESPACE}color-scheme"),
+            attribute: Some("data-theme".to_string()),
+        }
+    }
+}
+
+/// State handle for the [`use_dark_mode`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseDarkModeHandle {
+    preference: UseLocalStorageDefaultHandle<ColorSchemePreference>,
+    system_dark: bool,
+}
+
+impl UseDarkModeHandle {
+    /// The stored preference; `Auto` until the user picks a scheme.
+    pub fn preference(&self) -> ColorSchemePreference {
+        *self.preference
+    }
+
+    /// The scheme in effect: the preference, or the system's under `Auto`.
+    pub fn scheme(&self) -> ColorScheme {
+        match *self.preference {
+            ColorSchemePreference::Light => ColorScheme::Light,
+            ColorSchemePreference::Dark => ColorScheme::Dark,
+            ColorSchemePreference::Auto if self.system_dark => ColorScheme::Dark,
+            ColorSchemePreference::Auto => ColorScheme::Light,
+        }
+    }
+
+    /// Whether the dark scheme is in effect.
+    pub fn is_dark(&self) -> bool {
+        self.scheme() == ColorScheme::Dark
+    }
+
+    /// Store a preference; `Auto` forgets the override.
+    pub fn set(&self, preference: ColorSchemePreference) {
+        match preference {
+            ColorSchemePreference::Auto => self.preference.delete(),
+            preference => self.preference.set(preference),
+        }
+    }
+
+    /// Switch to the opposite of the scheme in effect, as an explicit override.
+    pub fn toggle(&self) {
+        self.set(match self.scheme() {
+            ColorScheme::Light => ColorSchemePreference::Dark,
+            ColorScheme::Dark => ColorSchemePreference::Light,
+        });
+    }
+}
+
+/// A hook for a light/dark theme switcher: follows the system's
+/// `prefers-color-scheme` unless the user picked a scheme, which is kept in
+/// `localStorage` (synced across tabs) as `auto`, `light` or `dark`.
+///
+/// The effective scheme is written to [`DarkModeOptions::attribute`] on
+/// `<html>` (`data-theme` by default) and removed on unmount, so mount the
+/// hook once near the root of the app and read the scheme from CSS elsewhere.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// // In your CSS: [data-theme="dark"] { --bg: #111; --fg: #eee; }
+/// #[function_component(ThemeSwitcher)]
+/// fn theme_switcher() -> Html {
+///     let theme = use_dark_mode(DarkModeOptions::default());
+///
+///     let ontoggle = {
+///         let theme = theme.clone();
+///         Callback::from(move |_| theme.toggle())
+///     };
+///     let onauto = {
+///         let theme = theme.clone();
+///         Callback::from(move |_| theme.set(ColorSchemePreference::Auto))
+///     };
+///
+///     html! {
+///         <div role="group" aria-label="Theme">
+///             <button onclick={ontoggle} aria-pressed={theme.is_dark().to_string()}>
+///                 { "Dark mode" }
+///             </button>
+///             <button
+///                 onclick={onauto}
+///                 disabled={theme.preference() == ColorSchemePreference::Auto}
+///             >
+///                 { "Use system setting" }
+///             </button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_dark_mode(options: DarkModeOptions) -> UseDarkModeHandle {
+    let preference = use_local_storage_default::<ColorSchemePreference>(options.storage_key);
+    let system_dark = use_media_query(PREFERS_DARK);
+    let handle = UseDarkModeHandle {
+        preference,
+        system_dark,
+    };
+
+    use_effect_with(
+        (handle.scheme(), options.attribute),
+        |(scheme, attribute)| {
+            let root = document().document_element();
+            if let (Some(root), Some(attribute)) = (&root, attribute) {
+                let _ = root.set_attribute(attribute, scheme.as_str());
+            }
+            let attribute = attribute.clone();
+            move || {
+                if let (Some(root), Some(attribute)) = (root, attribute) {
+                    let _ = root.remove_attribute(&attribute);
+                }
+            }
+        },
+    );
+
+    handle
+}
@@ -0,0 +1,260 @@
+use gloo::{events::EventListener, utils::window};
+use wasm_bindgen::JsCast;
+use web_sys::{
+    DeviceAcceleration, DeviceMotionEvent, DeviceOrientationEvent, Event, PermissionState,
+};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// The device's orientation, as reported by [`use_device_orientation`], in
+/// degrees. Each angle is `None` if the device has no sensor for it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeviceOrientation {
+    /// Rotation around the z axis (compass direction), 0 to 360.
+    pub alpha: Option<f64>,
+    /// Front-to-back tilt, -180 to 180.
+    pub beta: Option<f64>,
+    /// Left-to-right tilt, -90 to 90.
+    pub gamma: Option<f64>,
+    /// Whether `alpha` is relative to the Earth rather than to an arbitrary
+    /// start.
+    pub absolute: bool,
+}
+
+impl DeviceOrientation {
+    fn read(event: &Event) -> Self {
+        let event: &DeviceOrientationEvent = event.unchecked_ref();
+        Self {
+            alpha: event.alpha(),
+            beta: event.beta(),
+            gamma: event.gamma(),
+            absolute: event.absolute(),
+        }
+    }
+}
+
+/// Acceleration along each axis, in meters per second squared.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Acceleration {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub z: Option<f64>,
+}
+
+impl From<DeviceAcceleration> for Acceleration {
+    fn from(acceleration: DeviceAcceleration) -> Self {
+        Self {
+            x: acceleration.x(),
+            y: acceleration.y(),
+            z: acceleration.z(),
+        }
+    }
+}
+
+/// Rate of rotation around each axis, in degrees per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RotationRate {
+    pub alpha: Option<f64>,
+    pub beta: Option<f64>,
+    pub gamma: Option<f64>,
+}
+
+/// The device's motion, as reported by [`use_device_motion`]. Each part is
+/// `None` if the device has no sensor for it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeviceMotion {
+    /// Acceleration caused by the user.
+    pub acceleration: Option<Acceleration>,
+    /// Acceleration including gravity, available on more devices.
+    pub acceleration_including_gravity: Option<Acceleration>,
+    pub rotation_rate: Option<RotationRate>,
+    /// Milliseconds between readings.
+    pub interval: Option<f64>,
+}
+
+impl DeviceMotion {
+    fn read(event: &Event) -> Self {
+        let event: &DeviceMotionEvent = event.unchecked_ref();
+        Self {
+            acceleration: event.acceleration().map(Into::into),
+            acceleration_including_gravity: event.acceleration_including_gravity().map(Into::into),
+            rotation_rate: event.rotation_rate().map(|rate| RotationRate {
+                alpha: rate.alpha(),
+                beta: rate.beta(),
+                gamma: rate.gamma(),
+            }),
+            interval: event.interval(),
+        }
+    }
+}
+
+/// State handle for [`use_device_orientation`] and [`use_device_motion`].
+#[derive(Clone, PartialEq)]
+pub struct UseDeviceSensorHandle<T> {
+    value: Option<T>,
+    permission: UseStateHandle<Option<PermissionState>>,
+    interface: &'static str,
+}
+
+impl<T: Copy> UseDeviceSensorHandle<T> {
+    /// The latest reading; `None` until the first event.
+    pub fn value(&self) -> Option<T> {
+        self.value
+    }
+
+    /// Whether the browser has the sensor API. Desktop browsers have it but
+    /// never fire events, so also check [`value`](Self::value).
+    pub fn is_supported(&self) -> bool {
+        js::has(&window(), self.interface)
+    }
+
+    /// Whether [`request_permission`](Self::request_permission) must be
+    /// called before readings arrive (iOS Safari).
+    pub fn needs_permission(&self) -> bool {
+        *self.permission != Some(PermissionState::Granted)
+            && js::get(&window(), self.interface)
+                .is_some_and(|interface| js::has(&interface, "requestPermission"))
+    }
+
+    /// `Granted` once readings may arrive; `Denied` if the user refused.
+    pub fn permission(&self) -> Option<PermissionState> {
+        *self.permission
+    }
+
+    /// Ask for access to motion sensors where required (iOS Safari). Must be
+    /// called from a user gesture. Elsewhere it grants immediately.
+    pub fn request_permission(&self) {
+        let permission = self.permission.clone();
+        let Some(interface) = js::get(&window(), self.interface) else {
+            return;
+        };
+        if !js::has(&interface, "requestPermission") {
+            permission.set(Some(PermissionState::Granted));
+            return;
+        }
+        spawn_local(async move {
+            let result = js::call_async(&interface, "requestPermission", &[]).await;
+            permission.set(Some(
+                if result.ok().and_then(|r| r.as_string()).as_deref() == Some("granted") {
+                    PermissionState::Granted
+                } else {
+                    PermissionState::Denied
+                },
+            ));
+        });
+    }
+}
+
+#[hook]
+fn use_device_sensor<T>(
+    interface: &'static str,
+    event: &'static str,
+    read: fn(&Event) -> T,
+) -> UseDeviceSensorHandle<T>
+where
+    T: Copy + PartialEq + 'static,
+{
+    let value = use_state_eq(|| None);
+    let permission = use_state_eq(|| {
+        let needs_permission = js::get(&window(), interface)
+            .is_some_and(|interface| js::has(&interface, "requestPermission"));
+        (!needs_permission).then_some(PermissionState::Granted)
+    });
+
+    {
+        let value = value.clone();
+        // Listen again once granted.
+        use_effect_with(*permission, move |_| {
+            let listener = EventListener::new(&window(), event, move |event| {
+                value.set(Some(read(event)));
+            });
+            move || drop(listener)
+        });
+    }
+
+    UseDeviceSensorHandle {
+        value: *value,
+        permission,
+        interface,
+    }
+}
+
+/// A sensor hook for `deviceorientation`: the device's compass direction and
+/// tilt, for tilt-based UI, panoramas and AR-lite features.
+///
+/// iOS Safari only reports orientation after
+/// [`request_permission`](UseDeviceSensorHandle::request_permission), which
+/// must follow a user gesture; check
+/// [`needs_permission`](UseDeviceSensorHandle::needs_permission) to offer a
+/// button. Elsewhere, readings arrive right away on devices with sensors.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Level)]
+/// fn level() -> Html {
+///     let orientation = use_device_orientation();
+///
+///     let onclick = {
+///         let orientation = orientation.clone();
+///         Callback::from(move |_| orientation.request_permission())
+///     };
+///     let tilt = orientation.value().and_then(|o| o.gamma).unwrap_or(0.0);
+///
+///     html! {
+///         if orientation.needs_permission() {
+///             <button {onclick}>{ "Enable tilt" }</button>
+///         } else {
+///             <div class="bubble" style={format!("transform: translateX({tilt}px)")} />
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_device_orientation() -> UseDeviceSensorHandle<DeviceOrientation> {
+    use_device_sensor(
+        "DeviceOrientationEvent",
+        "deviceorientation",
+        DeviceOrientation::read,
+    )
+}
+
+/// A sensor hook for `devicemotion`: the device's acceleration and rotation
+/// rate, e.g. for shake gestures.
+///
+/// Like [`use_device_orientation`], iOS Safari requires
+/// [`request_permission`](UseDeviceSensorHandle::request_permission) from a
+/// user gesture first.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ShakeToUndo)]
+/// fn shake_to_undo() -> Html {
+///     let motion = use_device_motion();
+///     let shaking = motion
+///         .value()
+///         .and_then(|m| m.acceleration)
+///         .and_then(|a| a.x)
+///         .is_some_and(|x| x.abs() > 15.0);
+///
+///     html! {
+///         if shaking {
+///             <p>{ "Undo?" }</p>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_device_motion() -> UseDeviceSensorHandle<DeviceMotion> {
+    use_device_sensor("DeviceMotionEvent", "devicemotion", DeviceMotion::read)
+}
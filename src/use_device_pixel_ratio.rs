@@ -0,0 +1,48 @@
+use gloo::utils::window;
+use yew::prelude::*;
+
+use crate::use_media_query;
+
+/// A sensor hook returning `window.devicePixelRatio`, updated when it changes,
+/// e.g. when the window moves to a monitor with a different density or the
+/// user zooms, so canvas components can re-render at the right resolution.
+///
+/// There is no event for this; the hook watches a `(resolution: Ndppx)`
+/// media query for the current ratio, which stops matching when the ratio
+/// changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Chart)]
+/// fn chart() -> Html {
+///     let dpr = use_device_pixel_ratio();
+///     let (width, height) = (600.0, 300.0);
+///
+///     html! {
+///         <canvas
+///             width={(width * dpr).round().to_string()}
+///             height={(height * dpr).round().to_string()}
+///             style={format!("width: {width}px; height: {height}px")}
+///         />
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_device_pixel_ratio() -> f64 {
+    let ratio = use_state_eq(|| window().device_pixel_ratio());
+    let matches = use_media_query(format!("(resolution: {}dppx)", *ratio));
+
+    {
+        let ratio = ratio.clone();
+        use_effect_with(matches, move |_| {
+            ratio.set(window().device_pixel_ratio());
+        });
+    }
+
+    *ratio
+}
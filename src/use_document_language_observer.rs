@@ -0,0 +1,95 @@
+use gloo::utils::document;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{MutationObserver, MutationObserverInit};
+use yew::prelude::*;
+
+/// The `lang` attribute of `<html>`, or `None` when it is missing or empty.
+fn document_lang() -> Option<String> {
+    document()
+        .document_element()?
+        .get_attribute("lang")
+        .filter(|lang| !lang.is_empty())
+}
+
+/// State handle for the [`use_document_language_observer`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseDocumentLanguageHandle {
+    lang: UseStateHandle<Option<String>>,
+}
+
+impl UseDocumentLanguageHandle {
+    /// The document language, e.g. `"en-US"`.
+    pub fn lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+
+    /// Set the `lang` attribute of `<html>`. Every component observing it
+    /// re-renders, including ones in other apps on the page.
+    pub fn set(&self, lang: &str) {
+        if let Some(root) = document().document_element() {
+            let _ = root.set_attribute("lang", lang);
+        }
+        self.lang.set(document_lang());
+    }
+}
+
+/// A hook that exposes the `lang` attribute of `<html>` and re-renders when it
+/// changes, whether through [`set`](UseDocumentLanguageHandle::set), a
+/// language switcher elsewhere in the app or the host page, so
+/// locale-sensitive formatting stays in sync.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Price)]
+/// fn price() -> Html {
+///     let language = use_document_language_observer();
+///     let amount = match language.lang() {
+///         Some(lang) if lang.starts_with("de") => "12,50 €",
+///         _ => "€12.50",
+///     };
+///     let to_german = {
+///         let language = language.clone();
+///         Callback::from(move |_| language.set("de-DE"))
+///     };
+///
+///     html! {
+///         <div>
+///             <p>{ amount }</p>
+///             <button onclick={to_german}>{ "Deutsch" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_document_language_observer() -> UseDocumentLanguageHandle {
+    let lang = use_state_eq(document_lang);
+
+    {
+        let lang = lang.clone();
+        use_effect_with((), move |_| {
+            let callback = Closure::<dyn FnMut()>::new(move || lang.set(document_lang()));
+            let observer = MutationObserver::new(callback.as_ref().unchecked_ref())
+                .ok()
+                .zip(document().document_element())
+                .map(|(observer, root)| {
+                    let init = MutationObserverInit::new();
+                    init.set_attribute_filter(&js_sys::Array::of1(&JsValue::from_str("lang")));
+                    let _ = observer.observe_with_options(&root, &init);
+                    observer
+                });
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                drop(callback);
+            }
+        });
+    }
+
+    UseDocumentLanguageHandle { lang }
+}
@@ -0,0 +1,254 @@
+use gloo::{events::EventListener, utils::window};
+use wasm_bindgen::JsCast;
+use web_sys::{CssStyleSheet, Document, Element, Window};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// Options for the [`use_document_pip_widget`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DocumentPipOptions {
+    /// Initial width of the Picture-in-Picture window in CSS pixels.
+    pub width: u32,
+    /// Initial height of the Picture-in-Picture window in CSS pixels.
+    pub height: u32,
+    /// Copy the page's stylesheets into the Picture-in-Picture window.
+    pub copy_styles: bool,
+}
+
+impl Default for DocumentPipOptions {
+    fn default() -> Self {
+        Self {
+            width: 320,
+            height: 180,
+            copy_styles: true,
+        }
+    }
+}
+
+/// Whether the Document Picture-in-Picture API is available; it is not in
+/// Firefox and Safari, or inside iframes.
+fn is_supported() -> bool {
+    js::has(&window(), "documentPictureInPicture")
+        && window()
+            .top()
+            .ok()
+            .flatten()
+            .is_some_and(|top| top == window())
+}
+
+/// Copy every stylesheet of `from` into `to`, inlining rules where the
+/// stylesheet is readable and linking it where it is cross-origin.
+fn copy_styles(from: &Document, to: &Document) {
+    let (Some(head), sheets) = (to.head(), from.style_sheets()) else {
+        return;
+    };
+    for i in 0..sheets.length() {
+        let Some(sheet) = sheets.item(i) else {
+            continue;
+        };
+        let rules = sheet
+            .dyn_ref::<CssStyleSheet>()
+            .and_then(|sheet| sheet.css_rules().ok());
+        let element = match rules {
+            Some(rules) => {
+                let css: String = (0..rules.length())
+                    .filter_map(|i| rules.item(i))
+                    .map(|rule| rule.css_text())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                to.create_element("style").ok().inspect(|style| {
+                    style.set_text_content(Some(&css));
+                })
+            }
+            None => {
+                let Some(href) = sheet.href().ok().flatten() else {
+                    continue;
+                };
+                to.create_element("link").ok().inspect(|link| {
+                    let _ = link.set_attribute("rel", "stylesheet");
+                    let _ = link.set_attribute("href", &href);
+                })
+            }
+        };
+        if let Some(element) = element {
+            let _ = head.append_child(&element);
+        }
+    }
+}
+
+/// State handle for the [`use_document_pip_widget`] hook.
+#[derive(Clone)]
+pub struct UseDocumentPipWidgetHandle {
+    pip: UseStateHandle<Option<Window>>,
+    error: UseStateHandle<Option<String>>,
+    open: Callback<()>,
+}
+
+impl UseDocumentPipWidgetHandle {
+    /// Whether the browser supports Document Picture-in-Picture; render a
+    /// fallback (or nothing) otherwise.
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// Whether the Picture-in-Picture window is open.
+    pub fn is_open(&self) -> bool {
+        self.pip.is_some()
+    }
+
+    /// The Picture-in-Picture window, while open.
+    pub fn window(&self) -> Option<&Window> {
+        self.pip.as_ref()
+    }
+
+    /// Why the last [`open`](Self::open) failed.
+    pub fn error(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+
+    /// Open the Picture-in-Picture window. Browsers only allow this from a user
+    /// gesture, such as a click handler.
+    pub fn open(&self) {
+        self.open.emit(());
+    }
+
+    /// Close the Picture-in-Picture window.
+    pub fn close(&self) {
+        if let Some(pip) = &*self.pip {
+            let _ = pip.close();
+        }
+    }
+
+    /// Render `content` into the Picture-in-Picture window while it is open,
+    /// or in place otherwise.
+    pub fn render(&self, content: Html) -> Html {
+        match self.pip.as_ref().and_then(|pip| pip.document()?.body()) {
+            Some(body) => create_portal(content, Element::from(body)),
+            None => content,
+        }
+    }
+}
+
+impl PartialEq for UseDocumentPipWidgetHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.pip == *other.pip && *self.error == *other.error
+    }
+}
+
+/// A hook wrapping the Document Picture-in-Picture API, which opens a small
+/// always-on-top window for mini players or call controls.
+///
+/// Content passed to [`render`](UseDocumentPipWidgetHandle::render) is rendered
+/// into that window through a portal, so it stays part of the component tree
+/// (state and event handlers keep working), and moves back in place when the
+/// window is closed. The page's styles are copied over by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(CallControls)]
+/// fn call_controls() -> Html {
+///     let pip = use_document_pip_widget(DocumentPipOptions::default());
+///     let muted = use_state(|| false);
+///
+///     let ontoggle = {
+///         let muted = muted.clone();
+///         Callback::from(move |_| muted.set(!*muted))
+///     };
+///     let onpopout = {
+///         let pip = pip.clone();
+///         Callback::from(move |_| if pip.is_open() { pip.close() } else { pip.open() })
+///     };
+///
+///     html! {
+///         <div>
+///             if pip.is_supported() {
+///                 <button onclick={onpopout}>{ "Pop out" }</button>
+///             }
+///             { pip.render(html! {
+///                 <button onclick={ontoggle}>{ if *muted { "Unmute" } else { "Mute" } }</button>
+///             }) }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_document_pip_widget(options: DocumentPipOptions) -> UseDocumentPipWidgetHandle {
+    let pip = use_state(|| None::<Window>);
+    let error = use_state(|| None);
+    // The open window and its `pagehide` listener, for closing it on unmount.
+    let opened = use_mut_ref(|| None::<(Window, EventListener)>);
+
+    let open = {
+        let pip = pip.clone();
+        let error = error.clone();
+        let opened = opened.clone();
+        use_callback(options, move |_, options| {
+            if opened.borrow().is_some() {
+                return;
+            }
+            if !is_supported() {
+                error.set(Some(
+                    "Document Picture-in-Picture is not supported".to_string(),
+                ));
+                return;
+            }
+            let Some(api) = js::get(&window(), "documentPictureInPicture") else {
+                return;
+            };
+            let request = js::object(&[
+                ("width", options.width.into()),
+                ("height", options.height.into()),
+            ]);
+            let options = *options;
+            let pip = pip.clone();
+            let error = error.clone();
+            let current = opened.clone();
+            spawn_local(async move {
+                let result = js::call_async(&api, "requestWindow", &[request.into()]).await;
+                let opened: Window = match result {
+                    Ok(opened) => opened.unchecked_into(),
+                    Err(e) => {
+                        let message = e
+                            .dyn_ref::<js_sys::Error>()
+                            .map(|e| String::from(e.message()))
+                            .unwrap_or_else(|| format!("{e:?}"));
+                        error.set(Some(message));
+                        return;
+                    }
+                };
+                if let (true, Some(document), Some(target)) =
+                    (options.copy_styles, window().document(), opened.document())
+                {
+                    copy_styles(&document, &target);
+                }
+                let state = pip.clone();
+                let listener = {
+                    let current = current.clone();
+                    EventListener::new(&opened, "pagehide", move |_| {
+                        current.borrow_mut().take();
+                        state.set(None);
+                    })
+                };
+                *current.borrow_mut() = Some((opened.clone(), listener));
+                error.set(None);
+                pip.set(Some(opened));
+            });
+        })
+    };
+
+    use_effect_with((), move |_| {
+        move || {
+            if let Some((pip, _listener)) = opened.borrow_mut().take() {
+                let _ = pip.close();
+            }
+        }
+    });
+
+    UseDocumentPipWidgetHandle { pip, error, open }
+}
@@ -0,0 +1,90 @@
+use gloo::utils::document;
+use std::{cell::RefCell, collections::BTreeMap};
+use yew::prelude::*;
+
+/// Titles set by mounted [`use_document_title`] hooks, and the title from
+/// before the first of them.
+#[derive(Default)]
+struct TitleStack {
+    original: Option<String>,
+    /// By hook id; ids are assigned on first render, so components mounted
+    /// later get higher ids, and children higher ids than their parents.
+    entries: BTreeMap<u64, String>,
+    next_id: u64,
+}
+
+impl TitleStack {
+    /// Show the most recently mounted title, or the original once none is left.
+    fn apply(&mut self) {
+        match self.entries.last_key_value() {
+            Some((_, title)) => document().set_title(title),
+            None => {
+                if let Some(original) = self.original.take() {
+                    document().set_title(&original);
+                }
+            }
+        }
+    }
+}
+
+thread_local! {
+    static TITLES: RefCell<TitleStack> = RefCell::new(TitleStack::default());
+}
+
+/// A hook that sets `document.title` to `title`, updates it when `title`
+/// changes, and restores the previous title on unmount.
+///
+/// When several mounted components set a title, the one mounted last wins.
+/// Children mount after their parents, so a page's title overrides the
+/// layout's default, but a sibling or parent mounted later overrides both.
+/// Unmounting the winner reveals the title mounted before it.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Inbox)]
+/// fn inbox() -> Html {
+///     let unread = use_state(|| 3);
+///     use_document_title(format!("Inbox ({}) · Mail", *unread));
+///
+///     html! { <h1>{ "Inbox" }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_document_title(title: String) {
+    let id = use_memo((), |_| {
+        TITLES.with(|titles| {
+            let mut titles = titles.borrow_mut();
+            titles.next_id += 1;
+            titles.next_id
+        })
+    });
+
+    {
+        let id = *id;
+        use_effect_with((), move |_| {
+            move || {
+                TITLES.with(|titles| {
+                    let mut titles = titles.borrow_mut();
+                    titles.entries.remove(&id);
+                    titles.apply();
+                })
+            }
+        });
+    }
+
+    use_effect_with(title, move |title| {
+        TITLES.with(|titles| {
+            let mut titles = titles.borrow_mut();
+            if titles.entries.is_empty() {
+                titles.original = Some(document().title());
+            }
+            titles.entries.insert(*id, title.clone());
+            titles.apply();
+        })
+    });
+}
@@ -0,0 +1,329 @@
+use gloo::{
+    timers::callback::Timeout,
+    utils::{document, window},
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AbortController, Blob, BlobPropertyBag, HtmlAnchorElement, ReadableStreamDefaultReader,
+    RequestInit, Response, Url,
+};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_update;
+
+use crate::js;
+
+/// Lifecycle of a [`use_download`] download.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DownloadStatus {
+    /// No download was started yet.
+    #[default]
+    Idle,
+    /// Bytes received so far out of `total` (`total` is 0 when unknown).
+    Downloading { loaded: f64, total: f64 },
+    /// The file was received and handed to the browser to save.
+    Done,
+    /// The request failed or the server responded with a non-2xx status.
+    Failed(String),
+    /// The download was cancelled.
+    Cancelled,
+}
+
+impl DownloadStatus {
+    /// Progress between 0 and 1, when known.
+    pub fn progress(&self) -> Option<f64> {
+        match self {
+            Self::Downloading { loaded, total } if *total > 0.0 => Some(loaded / total),
+            Self::Done => Some(1.0),
+            _ => None,
+        }
+    }
+}
+
+/// How long an object URL stays valid after its download was triggered. The
+/// browser reads it asynchronously, so revoking it right away can cancel the save.
+const REVOKE_DELAY_MILLIS: u32 = 40_000;
+
+/// Offer `blob` to the user as a file download.
+pub(crate) fn save_blob(blob: &Blob, filename: &str) -> Result<(), JsValue> {
+    let url = Url::create_object_url_with_blob(blob)?;
+    let anchor: HtmlAnchorElement = document().create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    Timeout::new(REVOKE_DELAY_MILLIS, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+/// The filename from a `Content-Disposition` header, or the last URL segment.
+fn filename(response: &Response, url: &str) -> String {
+    let disposition = response
+        .headers()
+        .get("content-disposition")
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    let from_header = disposition.split(';').find_map(|part| {
+        let value = part.trim().strip_prefix("filename=")?;
+        Some(value.trim_matches('"').to_string())
+    });
+    from_header
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            let path = url.split(['?', '#']).next().unwrap_or_default();
+            let name = path.rsplit('/').next().unwrap_or_default();
+            js_sys::decode_uri_component(name)
+                .ok()
+                .map(String::from)
+                .filter(|name| !name.is_empty())
+        })
+        .unwrap_or_else(|| "download".to_string())
+}
+
+struct Download {
+    status: RefCell<DownloadStatus>,
+    controller: RefCell<Option<AbortController>>,
+    /// Incremented per download, so a cancelled one can't report late.
+    generation: Cell<u64>,
+    /// Bumped on every change, so handles from different renders compare unequal.
+    version: Cell<u64>,
+    update: Rc<dyn Fn()>,
+}
+
+impl Download {
+    fn changed(&self) {
+        self.version.set(self.version.get() + 1);
+        (self.update)();
+    }
+
+    fn set_status(&self, generation: u64, status: DownloadStatus) {
+        if self.generation.get() == generation {
+            *self.status.borrow_mut() = status;
+            self.changed();
+        }
+    }
+
+    fn cancel(&self) {
+        let controller = self.controller.borrow_mut().take();
+        if let Some(controller) = controller {
+            controller.abort();
+            self.generation.set(self.generation.get() + 1);
+            *self.status.borrow_mut() = DownloadStatus::Cancelled;
+            self.changed();
+        }
+    }
+
+    fn start(self: &Rc<Self>, url: String, filename: Option<String>) {
+        self.cancel();
+        let Ok(controller) = AbortController::new() else {
+            self.set_status(
+                self.generation.get(),
+                DownloadStatus::Failed("AbortController is not supported".to_string()),
+            );
+            return;
+        };
+        let init = RequestInit::new();
+        init.set_signal(Some(&controller.signal()));
+        *self.controller.borrow_mut() = Some(controller);
+        let generation = self.generation.get();
+        self.set_status(
+            generation,
+            DownloadStatus::Downloading {
+                loaded: 0.0,
+                total: 0.0,
+            },
+        );
+
+        let download = self.clone();
+        spawn_local(async move {
+            let result = download.fetch(generation, &url, &init).await;
+            if download.generation.get() != generation {
+                return;
+            }
+            download.controller.borrow_mut().take();
+            let status = match result
+                .and_then(|(blob, name)| save_blob(&blob, filename.as_deref().unwrap_or(&name)))
+            {
+                Ok(()) => DownloadStatus::Done,
+                Err(e) => DownloadStatus::Failed(
+                    e.as_string()
+                        .or_else(|| js::get(&e, "message").and_then(|m| m.as_string()))
+                        .unwrap_or_else(|| format!("{e:?}")),
+                ),
+            };
+            download.set_status(generation, status);
+        });
+    }
+
+    /// Fetch `url`, reading the body in chunks to report progress.
+    async fn fetch(
+        &self,
+        generation: u64,
+        url: &str,
+        init: &RequestInit,
+    ) -> Result<(Blob, String), JsValue> {
+        let response: Response = JsFuture::from(window().fetch_with_str_and_init(url, init))
+            .await?
+            .dyn_into()?;
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!("HTTP {}", response.status())));
+        }
+        let total = response
+            .headers()
+            .get("content-length")
+            .ok()
+            .flatten()
+            .and_then(|length| length.parse::<f64>().ok())
+            .unwrap_or(0.0);
+        let body = response
+            .body()
+            .ok_or_else(|| JsValue::from_str("empty response"))?;
+        let reader: ReadableStreamDefaultReader = body.get_reader().unchecked_into();
+        let chunks = js_sys::Array::new();
+        let mut loaded = 0.0;
+        loop {
+            let chunk = JsFuture::from(reader.read()).await?;
+            if js::get(&chunk, "done").and_then(|done| done.as_bool()) == Some(true) {
+                break;
+            }
+            let Some(value) = js::get(&chunk, "value") else {
+                continue;
+            };
+            loaded += value.unchecked_ref::<js_sys::Uint8Array>().length() as f64;
+            chunks.push(&value);
+            self.set_status(generation, DownloadStatus::Downloading { loaded, total });
+        }
+        let options = BlobPropertyBag::new();
+        if let Ok(Some(content_type)) = response.headers().get("content-type") {
+            options.set_type(&content_type);
+        }
+        let blob = Blob::new_with_u8_array_sequence_and_options(&chunks, &options)?;
+        Ok((blob, filename(&response, url)))
+    }
+}
+
+/// State handle for the [`use_download`] hook.
+#[derive(Clone)]
+pub struct UseDownloadHandle {
+    url: Rc<String>,
+    download: Rc<Download>,
+    version: u64,
+}
+
+impl UseDownloadHandle {
+    /// Download the file and save it under the name from the response's
+    /// `Content-Disposition` header (or the URL), cancelling any download in
+    /// progress.
+    pub fn start(&self) {
+        self.download.start((*self.url).clone(), None);
+    }
+
+    /// Download the file and save it as `filename`.
+    pub fn start_as(&self, filename: &str) {
+        self.download
+            .start((*self.url).clone(), Some(filename.to_string()));
+    }
+
+    /// Cancel the download in progress.
+    pub fn cancel(&self) {
+        self.download.cancel();
+    }
+
+    /// Status of the latest download.
+    pub fn status(&self) -> DownloadStatus {
+        self.download.status.borrow().clone()
+    }
+
+    /// Progress of the latest download between 0 and 1, when known.
+    pub fn progress(&self) -> Option<f64> {
+        self.download.status.borrow().progress()
+    }
+
+    /// Whether a download is in progress.
+    pub fn is_downloading(&self) -> bool {
+        self.download.controller.borrow().is_some()
+    }
+}
+
+impl PartialEq for UseDownloadHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && Rc::ptr_eq(&self.download, &other.download)
+            && self.version == other.version
+    }
+}
+
+/// A hook that downloads `url` with progress and saves the result as a file,
+/// e.g. for "Export report" buttons backed by a slow endpoint.
+///
+/// The body is streamed so progress can be shown (as a fraction when the
+/// server sends `Content-Length`), then handed to the browser with an object
+/// URL and a temporary `<a download>`. The download is cancelled when the
+/// component unmounts.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ExportButton)]
+/// fn export_button() -> Html {
+///     let export = use_download("/api/reports/export.csv".to_string());
+///
+///     let onclick = {
+///         let export = export.clone();
+///         Callback::from(move |_| {
+///             if export.is_downloading() {
+///                 export.cancel();
+///             } else {
+///                 export.start_as("report.csv");
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <button {onclick}>
+///             {
+///                 match export.progress() {
+///                     Some(p) if export.is_downloading() => format!("Exporting {:.0}%", p * 100.0),
+///                     _ if export.is_downloading() => "Exporting…".to_string(),
+///                     _ => "Export report".to_string(),
+///                 }
+///             }
+///         </button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_download(url: String) -> UseDownloadHandle {
+    let update = use_update();
+    let download = use_memo((), |_| Download {
+        status: RefCell::new(DownloadStatus::Idle),
+        controller: RefCell::new(None),
+        generation: Cell::new(0),
+        version: Cell::new(0),
+        update,
+    });
+    let url = use_memo(url, |url| url.clone());
+
+    {
+        let download = download.clone();
+        use_effect_with((), move |_| move || download.cancel());
+    }
+
+    let version = download.version.get();
+    UseDownloadHandle {
+        url,
+        download,
+        version,
+    }
+}
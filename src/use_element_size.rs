@@ -0,0 +1,146 @@
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{JsCast, prelude::Closure};
+use web_sys::{Element, ResizeObserver, ResizeObserverEntry, ResizeObserverSize};
+use yew::prelude::*;
+
+use crate::use_window_size::{ResizeLimiter, ResizeRate};
+
+/// Width and height in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BoxSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Size of an element observed by [`use_element_size`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElementSize {
+    /// The content box, without padding and borders.
+    pub content_box: BoxSize,
+    /// The border box, including padding and borders.
+    pub border_box: BoxSize,
+}
+
+impl ElementSize {
+    fn from_entry(entry: &ResizeObserverEntry) -> Self {
+        let rect = entry.content_rect();
+        let content_box = BoxSize {
+            width: rect.width(),
+            height: rect.height(),
+        };
+        // `borderBoxSize` is missing in older browsers. Sizes are reported
+        // in logical dimensions, assumed to be horizontal.
+        let border_box = entry
+            .border_box_size()
+            .get(0)
+            .dyn_into::<ResizeObserverSize>()
+            .map_or(content_box, |size| BoxSize {
+                width: size.inline_size(),
+                height: size.block_size(),
+            });
+        Self {
+            content_box,
+            border_box,
+        }
+    }
+}
+
+/// A sensor hook returning the content and border box size of the element
+/// behind `node`, observed with a `ResizeObserver` that is disconnected on
+/// unmount or when `node` changes.
+///
+/// Unlike the window size, this follows any layout change affecting the
+/// element, such as a sidebar collapsing, which makes it the right input for
+/// sizing a canvas or a virtualized list. The size is zero until the first
+/// observation, right after mount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Canvas)]
+/// fn canvas() -> Html {
+///     let container = use_node_ref();
+///     let size = use_element_size(container.clone());
+///
+///     html! {
+///         <div ref={container} style="width: 100%; height: 50vh">
+///             <canvas
+///                 width={size.content_box.width.to_string()}
+///                 height={size.content_box.height.to_string()}
+///             />
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_size(node: NodeRef) -> ElementSize {
+    use_element_size_with_rate(node, ResizeRate::Immediate)
+}
+
+/// [`use_element_size`] re-rendering at most at `rate` while the element
+/// keeps resizing.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Panel)]
+/// fn panel() -> Html {
+///     let panel = use_node_ref();
+///     let size = use_element_size_with_rate(
+///         panel.clone(),
+///         ResizeRate::Throttle(Duration::from_millis(100)),
+///     );
+///
+///     html! {
+///         <section ref={panel}>{ format!("{}px wide", size.border_box.width) }</section>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_size_with_rate(node: NodeRef, rate: ResizeRate) -> ElementSize {
+    let size = use_state_eq(ElementSize::default);
+
+    {
+        let size = size.clone();
+        use_effect_with((node, rate), move |(node, rate)| {
+            let limiter = ResizeLimiter::new(*rate);
+            // A delayed update applies the latest measurement, not the one
+            // that scheduled it.
+            let latest = Rc::new(Cell::new(ElementSize::default()));
+            let callback = {
+                let limiter = limiter.clone();
+                Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                    let Some(entry) = entries.iter().last() else {
+                        return;
+                    };
+                    latest.set(ElementSize::from_entry(entry.unchecked_ref()));
+                    let size = size.clone();
+                    let latest = latest.clone();
+                    limiter.run(move || size.set(latest.get()));
+                })
+            };
+            let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok();
+            if let (Some(observer), Some(element)) = (&observer, node.cast::<Element>()) {
+                observer.observe(&element);
+            }
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                limiter.cancel();
+                drop(callback);
+            }
+        });
+    }
+
+    *size
+}
@@ -0,0 +1,132 @@
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+use yew::prelude::*;
+
+/// Options for the [`use_element_visibility`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElementVisibilityOptions {
+    /// Visible ratios (`0.0` to `1.0`) at which to update; `[0.0]` reports
+    /// entering and leaving the viewport, `[0.0, 0.5, 1.0]` also half and
+    /// fully visible.
+    pub thresholds: Vec<f64>,
+    /// Grows (or with negative values, shrinks) the viewport, in CSS margin
+    /// syntax, e.g. `"200px 0px"` to start lazy-loading ahead of scrolling.
+    pub root_margin: String,
+    /// Stop observing once the element has been visible, e.g. for lazy
+    /// loading or one-off impressions.
+    pub once: bool,
+}
+
+impl Default for ElementVisibilityOptions {
+    fn default() -> Self {
+        Self {
+            thresholds: vec![0.0],
+            root_margin: "0px".to_string(),
+            once: false,
+        }
+    }
+}
+
+/// Visibility reported by [`use_element_visibility`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElementVisibility {
+    /// Whether the element intersects the (margin-adjusted) viewport.
+    pub is_visible: bool,
+    /// Fraction of the element that is visible, at the last crossed threshold.
+    pub ratio: f64,
+}
+
+/// A sensor hook returning whether, and how much of, the element behind
+/// `node` is in the viewport, observed with an `IntersectionObserver` that is
+/// disconnected on unmount or when `node` or the options change.
+///
+/// Updates only when a [threshold](ElementVisibilityOptions::thresholds) is
+/// crossed, so it is cheap enough for lazy-loading images, starting
+/// animations on entry, and impression tracking.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     src: AttrValue,
+/// }
+///
+/// #[function_component(LazyImage)]
+/// fn lazy_image(props: &Props) -> Html {
+///     let node = use_node_ref();
+///     let visibility = use_element_visibility(
+///         node.clone(),
+///         ElementVisibilityOptions {
+///             root_margin: "200px".to_string(),
+///             once: true,
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <div ref={node} class="image-placeholder">
+///             if visibility.is_visible {
+///                 <img src={props.src.clone()} />
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_visibility(
+    node: NodeRef,
+    options: ElementVisibilityOptions,
+) -> ElementVisibility {
+    let visibility = use_state_eq(ElementVisibility::default);
+
+    {
+        let visibility = visibility.clone();
+        use_effect_with((node, options), move |(node, options)| {
+            let once = options.once;
+            let callback = Closure::<dyn FnMut(js_sys::Array, IntersectionObserver)>::new(
+                move |entries: js_sys::Array, observer: IntersectionObserver| {
+                    let Some(entry) = entries.iter().last() else {
+                        return;
+                    };
+                    let entry: IntersectionObserverEntry = entry.unchecked_into();
+                    let is_visible = entry.is_intersecting();
+                    visibility.set(ElementVisibility {
+                        is_visible,
+                        ratio: entry.intersection_ratio(),
+                    });
+                    if once && is_visible {
+                        observer.disconnect();
+                    }
+                },
+            );
+            let init = IntersectionObserverInit::new();
+            init.set_root_margin(&options.root_margin);
+            let thresholds: js_sys::Array = options
+                .thresholds
+                .iter()
+                .copied()
+                .map(JsValue::from)
+                .collect();
+            init.set_threshold(&thresholds);
+            let observer =
+                IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+                    .ok();
+            if let (Some(observer), Some(element)) = (&observer, node.cast::<Element>()) {
+                observer.observe(&element);
+            }
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                drop(callback);
+            }
+        });
+    }
+
+    *visibility
+}
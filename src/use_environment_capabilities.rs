@@ -0,0 +1,147 @@
+use wasm_bindgen::JsValue;
+use yew::prelude::*;
+
+use crate::js;
+
+/// Browser APIs available in the current environment, as detected by
+/// [`use_environment_capabilities`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EnvironmentCapabilities {
+    /// `localStorage` exists and accepts writes (it throws in some private
+    /// browsing modes and when storage is disabled).
+    pub local_storage: bool,
+    /// `sessionStorage` exists and accepts writes.
+    pub session_storage: bool,
+    pub indexed_db: bool,
+    /// The origin private file system (`navigator.storage.getDirectory`).
+    pub opfs: bool,
+    /// `navigator.storage.estimate`, for quota usage.
+    pub storage_estimate: bool,
+    pub service_worker: bool,
+    pub periodic_background_sync: bool,
+    pub broadcast_channel: bool,
+    pub web_locks: bool,
+    pub web_workers: bool,
+    pub intersection_observer: bool,
+    pub resize_observer: bool,
+    pub mutation_observer: bool,
+    /// `navigator.share`.
+    pub web_share: bool,
+    /// The async clipboard API (`navigator.clipboard`).
+    pub clipboard: bool,
+    pub notifications: bool,
+    pub push: bool,
+    pub permissions: bool,
+    pub geolocation: bool,
+    /// `navigator.sendBeacon`.
+    pub beacon: bool,
+    pub event_source: bool,
+    pub web_socket: bool,
+    pub web_transport: bool,
+    pub webrtc: bool,
+    /// `navigator.connection` (Network Information API).
+    pub network_information: bool,
+    pub wake_lock: bool,
+    pub vibration: bool,
+    pub fullscreen: bool,
+    /// The CSS Custom Highlight API (`CSS.highlights`).
+    pub css_highlights: bool,
+    pub document_picture_in_picture: bool,
+    /// `<script type="speculationrules">`.
+    pub speculation_rules: bool,
+    /// Touch input is available (`navigator.maxTouchPoints > 0`).
+    pub touch: bool,
+}
+
+/// Whether `storage` can be written to.
+fn storage_writable(storage: Result<Option<web_sys::Storage>, JsValue>) -> bool {
+    const PROBE: &str = "more-yew-hooks:probe";
+    storage.ok().flatten().is_some_and(|storage| {
+        storage.set_item(PROBE, PROBE).is_ok() && storage.remove_item(PROBE).is_ok()
+    })
+}
+
+impl EnvironmentCapabilities {
+    /// Feature-detect every capability. Outside a browser (e.g. during
+    /// server-side rendering) everything is reported as unavailable.
+    pub fn detect() -> Self {
+        let Some(browser) = web_sys::window() else {
+            return Self::default();
+        };
+        let window: JsValue = browser.clone().into();
+        let navigator: JsValue = browser.navigator().into();
+        let storage_manager = js::get(&navigator, "storage").unwrap_or(JsValue::UNDEFINED);
+        let css = js::get(&window, "CSS").unwrap_or(JsValue::UNDEFINED);
+        let has = |name: &str| js::has(&window, name);
+        let navigator_has = |name: &str| js::has(&navigator, name);
+
+        Self {
+            local_storage: storage_writable(browser.local_storage()),
+            session_storage: storage_writable(browser.session_storage()),
+            indexed_db: has("indexedDB"),
+            opfs: js::has(&storage_manager, "getDirectory"),
+            storage_estimate: js::has(&storage_manager, "estimate"),
+            service_worker: navigator_has("serviceWorker"),
+            periodic_background_sync: has("PeriodicSyncManager"),
+            broadcast_channel: has("BroadcastChannel"),
+            web_locks: navigator_has("locks"),
+            web_workers: has("Worker"),
+            intersection_observer: has("IntersectionObserver"),
+            resize_observer: has("ResizeObserver"),
+            mutation_observer: has("MutationObserver"),
+            web_share: navigator_has("share"),
+            clipboard: navigator_has("clipboard"),
+            notifications: has("Notification"),
+            push: has("PushManager"),
+            permissions: navigator_has("permissions"),
+            geolocation: navigator_has("geolocation"),
+            beacon: navigator_has("sendBeacon"),
+            event_source: has("EventSource"),
+            web_socket: has("WebSocket"),
+            web_transport: has("WebTransport"),
+            webrtc: has("RTCPeerConnection"),
+            network_information: navigator_has("connection"),
+            wake_lock: navigator_has("wakeLock"),
+            vibration: navigator_has("vibrate"),
+            fullscreen: browser.document().is_some_and(|d| d.fullscreen_enabled()),
+            css_highlights: js::has(&css, "highlights"),
+            document_picture_in_picture: has("documentPictureInPicture"),
+            speculation_rules: js::get(&window, "HTMLScriptElement")
+                .and_then(|script| js::call(&script, "supports", &["speculationrules".into()]).ok())
+                .and_then(|supported| supported.as_bool())
+                .unwrap_or(false),
+            touch: browser.navigator().max_touch_points() > 0,
+        }
+    }
+}
+
+/// A hook that feature-detects, once per component, the browser APIs this
+/// crate wraps and returns them as a typed report, so UI can be gated
+/// declaratively (e.g. only render a share button when `caps.web_share`).
+///
+/// Use [`EnvironmentCapabilities::detect`] outside of components.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ShareButton)]
+/// fn share_button() -> Html {
+///     let caps = use_environment_capabilities();
+///
+///     html! {
+///         if caps.web_share {
+///             <button>{ "Share" }</button>
+///         } else if caps.clipboard {
+///             <button>{ "Copy link" }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_environment_capabilities() -> EnvironmentCapabilities {
+    *use_memo((), |_| EnvironmentCapabilities::detect())
+}
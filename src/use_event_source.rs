@@ -0,0 +1,230 @@
+#![cfg(feature = "serde")]
+
+use gloo::events::EventListener;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsCast;
+use web_sys::{EventSource, EventSourceInit, MessageEvent};
+use yew::prelude::*;
+
+/// Connection state of [`use_event_source`], mirroring `EventSource.readyState`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventSourceState {
+    /// Connecting, or reconnecting after the connection dropped.
+    Connecting,
+    Open,
+    /// Closed by [`close`](UseEventSourceHandle::close), or the server refused
+    /// the connection (the browser does not retry then).
+    Closed,
+}
+
+impl EventSourceState {
+    fn of(source: &EventSource) -> Self {
+        match source.ready_state() {
+            EventSource::OPEN => Self::Open,
+            EventSource::CLOSED => Self::Closed,
+            _ => Self::Connecting,
+        }
+    }
+}
+
+/// Options for the [`use_event_source`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventSourceOptions {
+    /// Named events (`event:` lines) to subscribe to. Unnamed events are
+    /// delivered as `message`.
+    pub events: Vec<String>,
+    /// Send cookies with cross-origin requests.
+    pub with_credentials: bool,
+}
+
+impl Default for EventSourceOptions {
+    fn default() -> Self {
+        Self {
+            events: vec!["message".to_string()],
+            with_credentials: false,
+        }
+    }
+}
+
+/// A message received by [`use_event_source`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventSourceMessage<T> {
+    /// The event name, `message` for unnamed events.
+    pub event: String,
+    /// The `data:` payload, deserialized from JSON.
+    pub data: T,
+    /// The `id:` of the event, which the browser resends as `Last-Event-ID`
+    /// when reconnecting.
+    pub last_event_id: String,
+}
+
+/// State handle for the [`use_event_source`] hook.
+pub struct UseEventSourceHandle<T> {
+    state: UseStateHandle<EventSourceState>,
+    message: UseStateHandle<Option<EventSourceMessage<T>>>,
+    error: UseStateHandle<Option<String>>,
+    source: UseStateHandle<Option<EventSource>>,
+}
+
+impl<T> UseEventSourceHandle<T> {
+    /// The current connection state.
+    pub fn state(&self) -> EventSourceState {
+        *self.state
+    }
+
+    /// The latest message that deserialized successfully.
+    pub fn message(&self) -> Option<&EventSourceMessage<T>> {
+        self.message.as_ref()
+    }
+
+    /// Why the latest message could not be deserialized.
+    pub fn error(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+
+    /// Close the connection; it is not reopened until `url` changes.
+    pub fn close(&self) {
+        if let Some(source) = &*self.source {
+            source.close();
+        }
+        self.state.set(EventSourceState::Closed);
+    }
+}
+
+impl<T> Clone for UseEventSourceHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            message: self.message.clone(),
+            error: self.error.clone(),
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseEventSourceHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.state == *other.state
+            && *self.message == *other.message
+            && *self.error == *other.error
+    }
+}
+
+/// A hook wrapping `EventSource` (Server-Sent Events) for live feeds that
+/// don't need a full WebSocket: subscribes to named events, deserializes each
+/// message's data from JSON, and closes the connection on unmount.
+///
+/// The browser reconnects dropped connections by itself, reported as
+/// [`EventSourceState::Connecting`].
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Clone, PartialEq, Deserialize)]
+/// struct Score {
+///     home: u32,
+///     away: u32,
+/// }
+///
+/// #[function_component(LiveScore)]
+/// fn live_score() -> Html {
+///     let feed = use_event_source::<Score>(
+///         "/api/match/42/events".to_string(),
+///         EventSourceOptions {
+///             events: vec!["score".to_string()],
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <div>
+///             if feed.state() == EventSourceState::Connecting {
+///                 <p>{ "Connecting..." }</p>
+///             }
+///             if let Some(message) = feed.message() {
+///                 <p>{ format!("{} : {}", message.data.home, message.data.away) }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_event_source<T>(url: String, options: EventSourceOptions) -> UseEventSourceHandle<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    let state = use_state(|| EventSourceState::Connecting);
+    let message = use_state(|| None);
+    let error = use_state(|| None);
+    let source = use_state(|| None);
+
+    {
+        let state = state.clone();
+        let message = message.clone();
+        let error = error.clone();
+        let source = source.clone();
+        use_effect_with((url, options), move |(url, options)| {
+            let init = EventSourceInit::new();
+            init.set_with_credentials(options.with_credentials);
+            let mut listeners = Vec::new();
+            let opened = EventSource::new_with_event_source_init_dict(url, &init).ok();
+            match &opened {
+                Some(es) => {
+                    state.set(EventSourceState::Connecting);
+                    for event in ["open", "error"] {
+                        let state = state.clone();
+                        let target = es.clone();
+                        listeners.push(EventListener::new(es, event, move |_| {
+                            state.set(EventSourceState::of(&target));
+                        }));
+                    }
+                    for event in &options.events {
+                        let message = message.clone();
+                        let error = error.clone();
+                        listeners.push(EventListener::new(es, event.clone(), move |e| {
+                            let Some(e) = e.dyn_ref::<MessageEvent>() else {
+                                return;
+                            };
+                            let data = e.data().as_string().unwrap_or_default();
+                            match serde_json::from_str(&data) {
+                                Ok(data) => {
+                                    message.set(Some(EventSourceMessage {
+                                        event: e.type_(),
+                                        data,
+                                        last_event_id: e.last_event_id(),
+                                    }));
+                                    error.set(None);
+                                }
+                                Err(e) => error.set(Some(e.to_string())),
+                            }
+                        }));
+                    }
+                }
+                None => state.set(EventSourceState::Closed),
+            }
+            source.set(opened.clone());
+
+            move || {
+                drop(listeners);
+                if let Some(es) = opened {
+                    es.close();
+                }
+            }
+        });
+    }
+
+    UseEventSourceHandle {
+        state,
+        message,
+        error,
+        source,
+    }
+}
@@ -0,0 +1,68 @@
+use gloo::utils::{document, head};
+use web_sys::Element;
+use yew::prelude::*;
+
+/// A hook that sets the page's favicon to `href`, e.g. a variant with a red
+/// dot while there are unread notifications, and restores the original on
+/// unmount.
+///
+/// Updates the `href` of the page's `<link rel="icon">`, or adds one to
+/// `<head>` (and removes it again) when the page has none. Any image format
+/// the browser supports works, including `data:` URLs drawn on a canvas.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Notifications)]
+/// fn notifications() -> Html {
+///     let unread = use_state(|| 0);
+///     use_favicon(if *unread > 0 {
+///         "/favicon-unread.svg".to_string()
+///     } else {
+///         "/favicon.svg".to_string()
+///     });
+///
+///     html! { <p>{ format!("{} unread", *unread) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_favicon(href: String) {
+    use_effect_with(href, |href| {
+        let existing = document()
+            .query_selector("link[rel~='icon']")
+            .ok()
+            .flatten();
+        let added = existing.is_none();
+        let link = existing.or_else(|| {
+            let link = document().create_element("link").ok()?;
+            let _ = link.set_attribute("rel", "icon");
+            head().append_child(&link).ok()?;
+            Some(link)
+        });
+        let original = link.as_ref().and_then(|link| link.get_attribute("href"));
+        if let Some(link) = &link {
+            let _ = link.set_attribute("href", href);
+        }
+        move || {
+            if let Some(link) = link {
+                restore(&link, added, original);
+            }
+        }
+    });
+}
+
+/// Remove the `link` if the hook `added` it, otherwise put back its
+/// `original` href.
+fn restore(link: &Element, added: bool, original: Option<String>) {
+    if added {
+        link.remove();
+    } else if let Some(href) = original {
+        let _ = link.set_attribute("href", &href);
+    } else {
+        let _ = link.remove_attribute("href");
+    }
+}
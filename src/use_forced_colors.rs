@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+use crate::use_media_query;
+
+/// A sensor hook for the `forced-colors` media feature: whether the browser
+/// replaces the page's colors with a user palette, as in Windows High
+/// Contrast themes, updated live when that is switched.
+///
+/// Under forced colors, backgrounds, shadows and most colors are overridden,
+/// so information conveyed only by color or a box shadow (focus rings,
+/// selection, status dots) disappears. Use it to render borders, outlines or
+/// text instead, ideally with CSS system colors like `CanvasText` and
+/// `Highlight`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(StatusDot)]
+/// fn status_dot() -> Html {
+///     let forced_colors = use_forced_colors();
+///
+///     html! {
+///         if forced_colors {
+///             <span>{ "(online)" }</span>
+///         } else {
+///             <span class="dot dot--green" aria-label="online" />
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_forced_colors() -> bool {
+    use_media_query("(forced-colors: active)")
+}
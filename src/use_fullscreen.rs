@@ -0,0 +1,146 @@
+use gloo::{events::EventListener, utils::document};
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// Standard and WebKit (Safari before 16.4) names of each part of the
+/// Fullscreen API.
+const ENABLED: [&str; 2] = ["fullscreenEnabled", "webkitFullscreenEnabled"];
+const ELEMENT: [&str; 2] = ["fullscreenElement", "webkitFullscreenElement"];
+const REQUEST: [&str; 2] = ["requestFullscreen", "webkitRequestFullscreen"];
+const EXIT: [&str; 2] = ["exitFullscreen", "webkitExitFullscreen"];
+const CHANGE: [&str; 2] = ["fullscreenchange", "webkitfullscreenchange"];
+
+fn is_supported() -> bool {
+    let document: JsValue = document().into();
+    ENABLED
+        .iter()
+        .any(|key| js::get(&document, key).and_then(|v| v.as_bool()) == Some(true))
+}
+
+fn fullscreen_element() -> Option<JsValue> {
+    let document: JsValue = document().into();
+    ELEMENT.iter().find_map(|key| js::get(&document, key))
+}
+
+/// Call the first of `methods` that `target` has, awaiting and discarding
+/// the result so a rejection (e.g. no user gesture) isn't reported as
+/// unhandled.
+fn call_prefixed(target: JsValue, methods: [&'static str; 2]) {
+    let Some(method) = methods.into_iter().find(|method| js::has(&target, method)) else {
+        return;
+    };
+    spawn_local(async move {
+        let _ = js::call_async(&target, method, &[]).await;
+    });
+}
+
+/// State handle for the [`use_fullscreen`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFullscreenHandle {
+    node: NodeRef,
+    is_fullscreen: bool,
+}
+
+impl UseFullscreenHandle {
+    /// Whether the page may go fullscreen. `false` on iPhone, and in iframes
+    /// without `allow="fullscreen"`.
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// Whether the element is fullscreen.
+    pub fn is_fullscreen(&self) -> bool {
+        self.is_fullscreen
+    }
+
+    /// Show the element fullscreen. Call from a user gesture.
+    pub fn enter(&self) {
+        if let Some(element) = self.node.cast::<Element>() {
+            call_prefixed(element.into(), REQUEST);
+        }
+    }
+
+    /// Leave fullscreen, if the element is fullscreen.
+    pub fn exit(&self) {
+        if self.is_fullscreen {
+            call_prefixed(document().into(), EXIT);
+        }
+    }
+
+    /// [`exit`](Self::exit) if fullscreen, otherwise [`enter`](Self::enter).
+    pub fn toggle(&self) {
+        if self.is_fullscreen {
+            self.exit();
+        } else {
+            self.enter();
+        }
+    }
+}
+
+/// A hook for the Fullscreen API on the element behind `node`, e.g. a video
+/// player, slideshow or map, with the WebKit-prefixed API as a fallback.
+///
+/// [`is_fullscreen`](UseFullscreenHandle::is_fullscreen) follows
+/// `fullscreenchange`, so it also turns `false` when the user leaves with
+/// Escape.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Player)]
+/// fn player() -> Html {
+///     let container = use_node_ref();
+///     let fullscreen = use_fullscreen(container.clone());
+///
+///     let onclick = {
+///         let fullscreen = fullscreen.clone();
+///         Callback::from(move |_| fullscreen.toggle())
+///     };
+///
+///     html! {
+///         <div ref={container} class="player">
+///             <video src="/talk.mp4" controls=true />
+///             if fullscreen.is_supported() {
+///                 <button {onclick}>
+///                     { if fullscreen.is_fullscreen() { "Exit fullscreen" } else { "Fullscreen" } }
+///                 </button>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_fullscreen(node: NodeRef) -> UseFullscreenHandle {
+    let is_fullscreen = use_state_eq(|| false);
+
+    {
+        let is_fullscreen = is_fullscreen.clone();
+        use_effect_with(node.clone(), move |node| {
+            let update = {
+                let node = node.clone();
+                move || {
+                    let element = node.get().map(JsValue::from);
+                    is_fullscreen.set(element.is_some() && fullscreen_element() == element);
+                }
+            };
+            update();
+            let listeners = CHANGE.map(|event| {
+                let update = update.clone();
+                EventListener::new(&document(), event, move |_| update())
+            });
+            move || drop(listeners)
+        });
+    }
+
+    UseFullscreenHandle {
+        node,
+        is_fullscreen: *is_fullscreen,
+    }
+}
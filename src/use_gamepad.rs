@@ -0,0 +1,236 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Gamepad, GamepadButton, GamepadEvent, GamepadMappingType};
+use yew::prelude::*;
+use yew_hooks::use_latest;
+
+use crate::use_page_visibility;
+
+/// A button of a [`GamepadState`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ButtonState {
+    pub pressed: bool,
+    pub touched: bool,
+    /// How far an analog button (e.g. a trigger) is pressed, 0.0 to 1.0.
+    pub value: f64,
+}
+
+/// A connected gamepad, as reported by [`use_gamepad`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GamepadState {
+    /// Stable while the gamepad stays connected.
+    pub index: u32,
+    /// The controller's name and USB ids, as given by the browser.
+    pub id: String,
+    /// Whether buttons and axes follow the standard layout (Xbox-style
+    /// positions: 0 is the bottom face button, axes 0/1 the left stick).
+    pub standard: bool,
+    pub buttons: Vec<ButtonState>,
+    /// Stick positions, -1.0 to 1.0.
+    pub axes: Vec<f64>,
+    /// When the state last changed, in milliseconds since page load.
+    pub timestamp: f64,
+}
+
+impl GamepadState {
+    fn read(gamepad: &Gamepad) -> Self {
+        Self {
+            index: gamepad.index(),
+            id: gamepad.id(),
+            standard: gamepad.mapping() == GamepadMappingType::Standard,
+            buttons: gamepad
+                .buttons()
+                .iter()
+                .map(|button| {
+                    let button: GamepadButton = button.unchecked_into();
+                    ButtonState {
+                        pressed: button.pressed(),
+                        touched: button.touched(),
+                        value: button.value(),
+                    }
+                })
+                .collect(),
+            axes: gamepad.axes().iter().filter_map(|a| a.as_f64()).collect(),
+            timestamp: gamepad.timestamp(),
+        }
+    }
+
+    /// Whether button `index` is pressed.
+    pub fn pressed(&self, index: usize) -> bool {
+        self.buttons.get(index).is_some_and(|button| button.pressed)
+    }
+}
+
+/// Callbacks for [`use_gamepad_with_callbacks`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GamepadCallbacks {
+    pub on_connect: Option<Callback<GamepadState>>,
+    pub on_disconnect: Option<Callback<GamepadState>>,
+}
+
+/// The connected gamepads, ordered by index.
+fn gamepads() -> Vec<GamepadState> {
+    let Ok(gamepads) = window().navigator().get_gamepads() else {
+        return Vec::new();
+    };
+    gamepads
+        .iter()
+        .filter_map(|gamepad| gamepad.dyn_into::<Gamepad>().ok())
+        .filter(Gamepad::connected)
+        .map(|gamepad| GamepadState::read(&gamepad))
+        .collect()
+}
+
+/// A `requestAnimationFrame` loop. The closure is reused for every frame and
+/// only dropped from outside it.
+#[derive(Default)]
+struct FrameLoop {
+    id: Cell<Option<i32>>,
+    closure: RefCell<Option<Closure<dyn FnMut()>>>,
+}
+
+impl FrameLoop {
+    fn schedule(&self) {
+        if let Some(closure) = self.closure.borrow().as_ref() {
+            let id = window().request_animation_frame(closure.as_ref().unchecked_ref());
+            self.id.set(id.ok());
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(id) = self.id.take() {
+            let _ = window().cancel_animation_frame(id);
+        }
+        self.closure.borrow_mut().take();
+    }
+}
+
+/// A sensor hook for the Gamepad API, returning the connected gamepads with
+/// their buttons and axes, for games and kiosk navigation.
+///
+/// Gamepads are polled every animation frame while one is connected and the
+/// page is visible; the component only re-renders when their state changes.
+/// Browsers only reveal a gamepad once a button is pressed on it. Use
+/// [`use_gamepad_with_callbacks`] to react to connections.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(PadStatus)]
+/// fn pad_status() -> Html {
+///     let gamepads = use_gamepad();
+///
+///     html! {
+///         if let Some(pad) = gamepads.first() {
+///             <p>
+///                 { format!("{}: stick {:.2}, {:.2}", pad.id, pad.axes[0], pad.axes[1]) }
+///                 if pad.pressed(0) {
+///                     <b>{ " Jump!" }</b>
+///                 }
+///             </p>
+///         } else {
+///             <p>{ "Press a button on your controller" }</p>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_gamepad() -> Vec<GamepadState> {
+    use_gamepad_with_callbacks(GamepadCallbacks::default())
+}
+
+/// [`use_gamepad`] with callbacks for gamepads connecting and disconnecting,
+/// e.g. to pause a game when its controller goes away.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Game)]
+/// fn game() -> Html {
+///     let paused = use_state(|| false);
+///     let gamepads = {
+///         let paused = paused.clone();
+///         use_gamepad_with_callbacks(GamepadCallbacks {
+///             on_disconnect: Some(Callback::from(move |_| paused.set(true))),
+///             ..Default::default()
+///         })
+///     };
+///
+///     html! {
+///         <p>{ format!("{} players", gamepads.len()) }{ if *paused { " (paused)" } else { "" } }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_gamepad_with_callbacks(callbacks: GamepadCallbacks) -> Vec<GamepadState> {
+    let state = use_state_eq(gamepads);
+    let callbacks = use_latest(callbacks);
+    let visibility = use_page_visibility();
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            let on_change = |connected: bool| {
+                let state = state.clone();
+                let callbacks = callbacks.clone();
+                move |event: &web_sys::Event| {
+                    state.set(gamepads());
+                    let Some(event) = event.dyn_ref::<GamepadEvent>() else {
+                        return;
+                    };
+                    let Some(gamepad) = event.gamepad() else {
+                        return;
+                    };
+                    let gamepad = GamepadState::read(&gamepad);
+                    let callbacks = callbacks.current();
+                    let callback = if connected {
+                        &callbacks.on_connect
+                    } else {
+                        &callbacks.on_disconnect
+                    };
+                    if let Some(callback) = callback {
+                        callback.emit(gamepad);
+                    }
+                }
+            };
+            let listeners = [
+                EventListener::new(&window(), "gamepadconnected", on_change(true)),
+                EventListener::new(&window(), "gamepaddisconnected", on_change(false)),
+            ];
+            move || drop(listeners)
+        });
+    }
+
+    {
+        let state = state.clone();
+        let active = visibility.is_visible() && !state.is_empty();
+        use_effect_with(active, move |&active| {
+            let frame_loop = Rc::new(FrameLoop::default());
+            if active {
+                let weak = Rc::downgrade(&frame_loop);
+                *frame_loop.closure.borrow_mut() = Some(Closure::new(move || {
+                    state.set(gamepads());
+                    if let Some(frame_loop) = weak.upgrade() {
+                        frame_loop.schedule();
+                    }
+                }));
+                frame_loop.schedule();
+            }
+            move || frame_loop.stop()
+        });
+    }
+
+    (*state).clone()
+}
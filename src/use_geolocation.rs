@@ -0,0 +1,361 @@
+use gloo::utils::window;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt,
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use web_sys::{Geolocation, Position, PositionError, PositionOptions};
+use yew::prelude::*;
+
+use crate::js;
+
+/// Options for the [`use_geolocation`] hook.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GeolocationOptions {
+    /// Keep following the position with `watchPosition` after
+    /// [`request`](UseGeolocationHandle::request), instead of reading it once.
+    pub watch: bool,
+    /// Ask for the most accurate position (GPS), at the cost of time and
+    /// battery.
+    pub high_accuracy: bool,
+    /// Give up on a reading after this long; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+    /// Accept a cached position up to this old.
+    pub maximum_age: Duration,
+    /// Request on mount. Leave `false` to request from a user gesture, so the
+    /// permission prompt comes with context.
+    pub immediate: bool,
+}
+
+impl GeolocationOptions {
+    fn position_options(&self) -> PositionOptions {
+        let millis = |d: Duration| u32::try_from(d.as_millis()).unwrap_or(u32::MAX);
+        let options = PositionOptions::new();
+        options.set_enable_high_accuracy(self.high_accuracy);
+        options.set_maximum_age(millis(self.maximum_age));
+        if let Some(timeout) = self.timeout {
+            options.set_timeout(millis(timeout));
+        }
+        options
+    }
+}
+
+/// A position reported by [`use_geolocation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeolocationPosition {
+    /// Degrees.
+    pub latitude: f64,
+    /// Degrees.
+    pub longitude: f64,
+    /// Radius of uncertainty of the latitude and longitude, in meters.
+    pub accuracy: f64,
+    /// Meters above the WGS 84 ellipsoid.
+    pub altitude: Option<f64>,
+    /// Meters.
+    pub altitude_accuracy: Option<f64>,
+    /// Degrees clockwise from true north.
+    pub heading: Option<f64>,
+    /// Meters per second.
+    pub speed: Option<f64>,
+    /// When the position was acquired, in milliseconds since the epoch.
+    pub timestamp: f64,
+}
+
+impl From<Position> for GeolocationPosition {
+    fn from(position: Position) -> Self {
+        let coords = position.coords();
+        Self {
+            latitude: coords.latitude(),
+            longitude: coords.longitude(),
+            accuracy: coords.accuracy(),
+            altitude: coords.altitude(),
+            altitude_accuracy: coords.altitude_accuracy(),
+            heading: coords.heading(),
+            speed: coords.speed(),
+            timestamp: position.timestamp(),
+        }
+    }
+}
+
+/// Why [`use_geolocation`] has no position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeolocationError {
+    /// The user or a policy denied the permission.
+    PermissionDenied,
+    /// The position could not be determined, e.g. no signal.
+    PositionUnavailable(String),
+    /// No position within [`GeolocationOptions::timeout`].
+    Timeout,
+    /// The Geolocation API is not available (it requires a secure context).
+    Unsupported,
+}
+
+impl From<PositionError> for GeolocationError {
+    fn from(error: PositionError) -> Self {
+        match error.code() {
+            PositionError::PERMISSION_DENIED => Self::PermissionDenied,
+            PositionError::TIMEOUT => Self::Timeout,
+            _ => Self::PositionUnavailable(error.message()),
+        }
+    }
+}
+
+impl fmt::Display for GeolocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PermissionDenied => write!(f, "location permission denied"),
+            Self::PositionUnavailable(message) if message.is_empty() => {
+                write!(f, "position unavailable")
+            }
+            Self::PositionUnavailable(message) => write!(f, "position unavailable: {message}"),
+            Self::Timeout => write!(f, "timed out acquiring position"),
+            Self::Unsupported => write!(f, "Geolocation API is not supported"),
+        }
+    }
+}
+
+impl std::error::Error for GeolocationError {}
+
+type PositionCallback = Closure<dyn FnMut(JsValue)>;
+
+/// The running `watchPosition`, if any.
+#[derive(Default)]
+struct Watch {
+    id: Cell<Option<i32>>,
+    /// Kept alive while watching.
+    callbacks: RefCell<Option<(PositionCallback, PositionCallback)>>,
+    /// Callbacks of one-shot readings in flight, by reading id. Both are
+    /// dropped once either runs, as the browser never calls the other.
+    readings: RefCell<HashMap<u64, (PositionCallback, PositionCallback)>>,
+    next_reading: Cell<u64>,
+    /// Cleared on unmount, so pending one-shot readings are ignored.
+    mounted: Cell<bool>,
+}
+
+impl Watch {
+    fn clear(&self) {
+        if let (Some(id), Some(geolocation)) = (self.id.take(), geolocation()) {
+            geolocation.clear_watch(id);
+        }
+        self.callbacks.borrow_mut().take();
+    }
+}
+
+fn geolocation() -> Option<Geolocation> {
+    let navigator = window().navigator();
+    js::has(&navigator, "geolocation")
+        .then(|| navigator.geolocation().ok())
+        .flatten()
+}
+
+/// State handle for the [`use_geolocation`] hook.
+#[derive(Clone)]
+pub struct UseGeolocationHandle {
+    position: UseStateHandle<Option<GeolocationPosition>>,
+    error: UseStateHandle<Option<GeolocationError>>,
+    loading: UseStateHandle<bool>,
+    watching: UseStateHandle<bool>,
+    watch: Rc<Watch>,
+    options: GeolocationOptions,
+}
+
+impl UseGeolocationHandle {
+    /// The latest position.
+    pub fn position(&self) -> Option<GeolocationPosition> {
+        *self.position
+    }
+
+    /// The error of the latest reading, if it failed.
+    pub fn error(&self) -> Option<&GeolocationError> {
+        self.error.as_ref()
+    }
+
+    /// Whether a reading is pending.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// Whether the position is being watched.
+    pub fn watching(&self) -> bool {
+        *self.watching
+    }
+
+    /// Read the position, or start watching it with
+    /// [`GeolocationOptions::watch`]. Prompts for permission the first time;
+    /// call from a user gesture.
+    pub fn request(&self) {
+        let Some(geolocation) = geolocation() else {
+            self.error.set(Some(GeolocationError::Unsupported));
+            return;
+        };
+        self.loading.set(true);
+        let on_position = {
+            let position = self.position.clone();
+            let error = self.error.clone();
+            let loading = self.loading.clone();
+            let watch = self.watch.clone();
+            move |value: JsValue| {
+                if watch.mounted.get() {
+                    position.set(Some(value.unchecked_into::<Position>().into()));
+                    error.set(None);
+                    loading.set(false);
+                }
+            }
+        };
+        let on_error = {
+            let error = self.error.clone();
+            let loading = self.loading.clone();
+            let watch = self.watch.clone();
+            move |value: JsValue| {
+                if watch.mounted.get() {
+                    error.set(Some(value.unchecked_into::<PositionError>().into()));
+                    loading.set(false);
+                }
+            }
+        };
+        let options = self.options.position_options();
+
+        if self.options.watch {
+            self.watch.clear();
+            let on_position = Closure::<dyn FnMut(JsValue)>::new(on_position);
+            let on_error = Closure::<dyn FnMut(JsValue)>::new(on_error);
+            let id = geolocation.watch_position_with_error_callback_and_options(
+                on_position.as_ref().unchecked_ref(),
+                Some(on_error.as_ref().unchecked_ref()),
+                &options,
+            );
+            if let Ok(id) = id {
+                self.watch.id.set(Some(id));
+                *self.watch.callbacks.borrow_mut() = Some((on_position, on_error));
+                self.watching.set(true);
+            }
+        } else {
+            let id = self.watch.next_reading.get();
+            self.watch.next_reading.set(id + 1);
+            let once = |callback: Box<dyn Fn(JsValue)>| {
+                let watch = self.watch.clone();
+                Closure::<dyn FnMut(JsValue)>::new(move |value| {
+                    callback(value);
+                    watch.readings.borrow_mut().remove(&id);
+                })
+            };
+            let on_position = once(Box::new(on_position));
+            let on_error = once(Box::new(on_error));
+            let result = geolocation.get_current_position_with_error_callback_and_options(
+                on_position.as_ref().unchecked_ref(),
+                Some(on_error.as_ref().unchecked_ref()),
+                &options,
+            );
+            if result.is_ok() {
+                self.watch
+                    .readings
+                    .borrow_mut()
+                    .insert(id, (on_position, on_error));
+            }
+        }
+    }
+
+    /// Stop watching the position.
+    pub fn stop(&self) {
+        self.watch.clear();
+        self.watching.set(false);
+        self.loading.set(false);
+    }
+}
+
+impl PartialEq for UseGeolocationHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.position == *other.position
+            && *self.error == *other.error
+            && *self.loading == *other.loading
+            && *self.watching == *other.watching
+            && Rc::ptr_eq(&self.watch, &other.watch)
+            && self.options == other.options
+    }
+}
+
+/// A hook for the Geolocation API: reads the device's position once, or
+/// keeps following it with `watchPosition`, and reports it with its accuracy
+/// and any error.
+///
+/// Nothing happens until [`request`](UseGeolocationHandle::request), so the
+/// permission prompt follows a user action that explains it (or set
+/// [`GeolocationOptions::immediate`]). The watch is cleared on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(RunTracker)]
+/// fn run_tracker() -> Html {
+///     let geo = use_geolocation(GeolocationOptions {
+///         watch: true,
+///         high_accuracy: true,
+///         ..Default::default()
+///     });
+///
+///     let onstart = {
+///         let geo = geo.clone();
+///         Callback::from(move |_| geo.request())
+///     };
+///     let onstop = {
+///         let geo = geo.clone();
+///         Callback::from(move |_| geo.stop())
+///     };
+///
+///     html! {
+///         <div>
+///             if geo.watching() {
+///                 <button onclick={onstop}>{ "Stop" }</button>
+///             } else {
+///                 <button onclick={onstart}>{ "Start run" }</button>
+///             }
+///             if let Some(position) = geo.position() {
+///                 <p>{ format!("{:.5}, {:.5} (±{:.0} m)", position.latitude, position.longitude, position.accuracy) }</p>
+///             }
+///             if let Some(error) = geo.error() {
+///                 <p class="error">{ error.to_string() }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_geolocation(options: GeolocationOptions) -> UseGeolocationHandle {
+    let position = use_state_eq(|| None);
+    let error = use_state_eq(|| None);
+    let loading = use_state_eq(|| false);
+    let watching = use_state_eq(|| false);
+    let watch = use_memo((), |_| Watch::default());
+
+    let handle = UseGeolocationHandle {
+        position,
+        error,
+        loading,
+        watching,
+        watch,
+        options,
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with((), move |_| {
+            handle.watch.mounted.set(true);
+            if handle.options.immediate {
+                handle.request();
+            }
+            move || {
+                handle.watch.mounted.set(false);
+                handle.watch.clear();
+            }
+        });
+    }
+
+    handle
+}
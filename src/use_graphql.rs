@@ -0,0 +1,311 @@
+#![cfg(feature = "serde")]
+
+use gloo::net::http::Request;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{fmt, rc::Rc};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::inflight::dedupe;
+
+/// An entry of a GraphQL response's `errors` array.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+    /// Path of the response field that failed, e.g. `["user", "posts", 0]`.
+    #[serde(default)]
+    pub path: Option<Vec<serde_json::Value>>,
+    /// Server-specific details, e.g. an error `code`.
+    #[serde(default)]
+    pub extensions: Option<serde_json::Value>,
+}
+
+/// Errors reported by the [`use_graphql`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphQLRequestError {
+    /// The request could not be sent or its body could not be read.
+    Network(String),
+    /// The server responded with a non-2xx status and no GraphQL errors.
+    Http { status: u16, body: String },
+    /// The response is not a GraphQL response for the expected data type.
+    Deserialize(String),
+    /// The server returned GraphQL errors. Partial data, if any, is still set.
+    GraphQL(Vec<GraphQLError>),
+}
+
+impl fmt::Display for GraphQLRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(e) => write!(f, "request failed: {e}"),
+            Self::Http { status, .. } => write!(f, "server responded with status {status}"),
+            Self::Deserialize(e) => write!(f, "invalid response: {e}"),
+            Self::GraphQL(errors) => {
+                let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
+                write!(f, "{}", messages.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphQLRequestError {}
+
+#[derive(Deserialize)]
+struct GraphQLResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQLError>,
+}
+
+/// Post `body` to `endpoint` and split the response into data and errors.
+async fn send<T: DeserializeOwned>(
+    endpoint: &str,
+    body: &str,
+) -> (Option<T>, Option<GraphQLRequestError>) {
+    let request = Request::post(endpoint)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(body);
+    let response = match request {
+        Ok(request) => request.send().await,
+        Err(e) => Err(e),
+    };
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => return (None, Some(GraphQLRequestError::Network(e.to_string()))),
+    };
+    let status = response.status();
+    let text = match response.text().await {
+        Ok(text) => text,
+        Err(e) => return (None, Some(GraphQLRequestError::Network(e.to_string()))),
+    };
+    match serde_json::from_str::<GraphQLResponse<T>>(&text) {
+        Ok(GraphQLResponse { data, errors }) if !errors.is_empty() => {
+            (data, Some(GraphQLRequestError::GraphQL(errors)))
+        }
+        _ if !response.ok() => (None, Some(GraphQLRequestError::Http { status, body: text })),
+        Ok(GraphQLResponse { data, .. }) => (data, None),
+        Err(e) => (None, Some(GraphQLRequestError::Deserialize(e.to_string()))),
+    }
+}
+
+/// Whether `document` only defines queries, i.e. has no top-level `mutation`
+/// or `subscription` operation.
+fn is_query(document: &str) -> bool {
+    let mut depth = 0usize;
+    let mut word = String::new();
+    let mut chars = document.chars();
+    while let Some(c) = chars.next() {
+        if depth == 0 && (c.is_alphanumeric() || c == '_') {
+            word.push(c);
+            continue;
+        }
+        if word == "mutation" || word == "subscription" {
+            return false;
+        }
+        word.clear();
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '#' => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            '"' => {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    word != "mutation" && word != "subscription"
+}
+
+/// State handle for the [`use_graphql`] hook.
+pub struct UseGraphQLHandle<T> {
+    data: UseStateHandle<Option<Rc<T>>>,
+    loading: UseStateHandle<bool>,
+    error: UseStateHandle<Option<GraphQLRequestError>>,
+    refetch: Callback<bool>,
+}
+
+impl<T> UseGraphQLHandle<T> {
+    /// The `data` of the last response. Kept while refetching and when a
+    /// refetch fails.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_deref()
+    }
+
+    /// Whether a request is in flight.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// The error of the last request, if it failed.
+    pub fn error(&self) -> Option<&GraphQLRequestError> {
+        self.error.as_ref()
+    }
+
+    /// Send the request again. Always makes a new network call, even when
+    /// another component has the same request in flight.
+    pub fn refetch(&self) {
+        self.refetch.emit(true);
+    }
+}
+
+impl<T> Clone for UseGraphQLHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            loading: self.loading.clone(),
+            error: self.error.clone(),
+            refetch: self.refetch.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseGraphQLHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_data = match (&*self.data, &*other.data) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        same_data
+            && *self.loading == *other.loading
+            && *self.error == *other.error
+            && self.refetch == other.refetch
+    }
+}
+
+/// A hook that runs a GraphQL `query` (or mutation) against `endpoint` with
+/// serialized `variables` and deserializes the response `data` into `T`.
+///
+/// The request is sent on mount and again whenever `endpoint`, `query` or
+/// `variables` change; responses to superseded requests are ignored. GraphQL
+/// errors are reported alongside any partial data. Components sending the
+/// same query at the same time share a single network call; mutations and
+/// explicit [`refetch`](UseGraphQLHandle::refetch)es are always sent.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Deserialize)]
+/// struct UserData {
+///     user: User,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[derive(PartialEq, Serialize)]
+/// struct UserVariables {
+///     id: u32,
+/// }
+///
+/// const USER_QUERY: &str = "query User($id: ID!) { user(id: $id) { name } }";
+///
+/// #[function_component(UserName)]
+/// fn user_name(props: &UserNameProps) -> Html {
+///     let user = use_graphql::<UserData, _>(
+///         "/graphql".to_string(),
+///         USER_QUERY.to_string(),
+///         UserVariables { id: props.id },
+///     );
+///
+///     if let Some(error) = user.error() {
+///         return html! { <p>{ error.to_string() }</p> };
+///     }
+///     match user.data() {
+///         Some(data) => html! { <p>{ &data.user.name }</p> },
+///         None => html! { <p>{ "Loading..." }</p> },
+///     }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct UserNameProps {
+///     id: u32,
+/// }
+/// ```
+#[hook]
+pub fn use_graphql<T, V>(endpoint: String, query: String, variables: V) -> UseGraphQLHandle<T>
+where
+    T: DeserializeOwned + 'static,
+    V: Serialize + PartialEq + 'static,
+{
+    let data = use_state(|| None);
+    let loading = use_state(|| false);
+    let error = use_state(|| None);
+    let generation = use_mut_ref(|| 0u64);
+
+    let request = use_memo(
+        (endpoint, query, variables),
+        |(endpoint, query, variables)| {
+            let body = serde_json::json!({ "query": query, "variables": variables });
+            (endpoint.clone(), body.to_string(), is_query(query))
+        },
+    );
+
+    let refetch = {
+        let data = data.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        use_callback(request, move |forced: bool, request| {
+            let current = {
+                let mut generation = generation.borrow_mut();
+                *generation += 1;
+                *generation
+            };
+            let (endpoint, body, query) = (**request).clone();
+            let generation = generation.clone();
+            let data = data.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            loading.set(true);
+            spawn_local(async move {
+                let key = format!("{endpoint}\n{body}");
+                let request = || async move {
+                    let (response, e) = send::<T>(&endpoint, &body).await;
+                    (response.map(Rc::new), e)
+                };
+                let result = if query && !forced {
+                    dedupe(&key, request).await
+                } else {
+                    Rc::new(request().await)
+                };
+                if *generation.borrow() != current {
+                    return;
+                }
+                let (response, e) = &*result;
+                if let Some(response) = response {
+                    data.set(Some(response.clone()));
+                }
+                error.set(e.clone());
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let refetch = refetch.clone();
+        use_effect_with(refetch, move |refetch| refetch.emit(false));
+    }
+
+    UseGraphQLHandle {
+        data,
+        loading,
+        error,
+        refetch,
+    }
+}
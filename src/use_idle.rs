@@ -0,0 +1,148 @@
+use gloo::{
+    events::{EventListener, EventListenerOptions},
+    timers::callback::Interval,
+    utils::{document, window},
+};
+use std::{cell::Cell, rc::Rc, time::Duration};
+use yew::prelude::*;
+
+/// Events that count as user activity.
+const ACTIVITY_EVENTS: [&str; 6] = [
+    "mousemove",
+    "mousedown",
+    "keydown",
+    "touchstart",
+    "wheel",
+    "scroll",
+];
+
+struct Tracker {
+    last_active: Cell<f64>,
+    idle: Cell<bool>,
+}
+
+/// State handle for the [`use_idle`] hook.
+#[derive(Clone)]
+pub struct UseIdleHandle {
+    idle: bool,
+    tracker: Rc<Tracker>,
+}
+
+impl UseIdleHandle {
+    /// Whether there was no activity for the timeout.
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// When the user was last active, in milliseconds since the epoch; mount
+    /// time before any activity.
+    pub fn last_active(&self) -> f64 {
+        self.tracker.last_active.get()
+    }
+}
+
+impl PartialEq for UseIdleHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.idle == other.idle && Rc::ptr_eq(&self.tracker, &other.tracker)
+    }
+}
+
+/// A sensor hook that tracks mouse, keyboard, touch and scroll activity and
+/// reports whether the user has been idle for longer than `timeout`, for
+/// auto-logout warnings or pausing expensive polling.
+///
+/// Activity is recorded without re-rendering; the component only re-renders
+/// when the user becomes idle or returns. Returning to a hidden tab counts
+/// as activity.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(SessionGuard)]
+/// fn session_guard() -> Html {
+///     let idle = use_idle(Duration::from_secs(15 * 60));
+///
+///     html! {
+///         if idle.is_idle() {
+///             <div role="alertdialog">{ "Are you still there? You will be signed out soon." }</div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_idle(timeout: Duration) -> UseIdleHandle {
+    let idle = use_state_eq(|| false);
+    let tracker = use_memo((), |_| Tracker {
+        last_active: Cell::new(js_sys::Date::now()),
+        idle: Cell::new(false),
+    });
+
+    {
+        let idle = idle.clone();
+        let tracker = tracker.clone();
+        use_effect_with(timeout, move |timeout| {
+            let timeout = *timeout;
+            let set_idle = {
+                let tracker = tracker.clone();
+                Rc::new(move |value: bool| {
+                    if tracker.idle.replace(value) != value {
+                        idle.set(value);
+                    }
+                })
+            };
+            // Polled rather than re-armed on every event, which would create
+            // a timer per mouse move.
+            let interval = {
+                let tracker = tracker.clone();
+                let set_idle = set_idle.clone();
+                let period = timeout
+                    .min(Duration::from_secs(1))
+                    .max(Duration::from_millis(100));
+                Interval::new(period.as_millis() as u32, move || {
+                    let elapsed = js_sys::Date::now() - tracker.last_active.get();
+                    if elapsed >= timeout.as_secs_f64() * 1000.0 {
+                        set_idle(true);
+                    }
+                })
+            };
+            let on_activity = Rc::new(move || {
+                tracker.last_active.set(js_sys::Date::now());
+                set_idle(false);
+            });
+            let options = EventListenerOptions::run_in_capture_phase();
+            let mut listeners: Vec<EventListener> = ACTIVITY_EVENTS
+                .iter()
+                .map(|event| {
+                    let on_activity = on_activity.clone();
+                    EventListener::new_with_options(&window(), *event, options, move |_| {
+                        on_activity()
+                    })
+                })
+                .collect();
+            listeners.push(EventListener::new(
+                &document(),
+                "visibilitychange",
+                move |_| {
+                    if !document().hidden() {
+                        on_activity();
+                    }
+                },
+            ));
+
+            move || {
+                drop(interval);
+                drop(listeners);
+            }
+        });
+    }
+
+    UseIdleHandle {
+        idle: *idle,
+        tracker,
+    }
+}
@@ -0,0 +1,313 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{AbortController, EventTarget, PermissionState};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{js, use_permission};
+
+/// Options for the [`use_idle_detection`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdleDetectionOptions {
+    /// How long without input before the user counts as idle. Browsers
+    /// reject anything under a minute.
+    pub threshold: Duration,
+    /// Start on mount when the permission is already granted. Otherwise
+    /// detection waits for [`start`](UseIdleDetectionHandle::start).
+    pub immediate: bool,
+}
+
+impl Default for IdleDetectionOptions {
+    fn default() -> Self {
+        Self {
+            threshold: Duration::from_secs(60),
+            immediate: true,
+        }
+    }
+}
+
+/// Whether the user is interacting with the device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserIdleState {
+    Active,
+    Idle,
+}
+
+/// Whether the device's screen is locked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScreenIdleState {
+    Locked,
+    Unlocked,
+}
+
+/// The state reported by [`use_idle_detection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdleDetectionState {
+    pub user: UserIdleState,
+    pub screen: ScreenIdleState,
+}
+
+impl IdleDetectionState {
+    fn read(detector: &JsValue) -> Self {
+        let is = |key: &str, value: &str| {
+            js::get(detector, key)
+                .and_then(|v| v.as_string())
+                .as_deref()
+                == Some(value)
+        };
+        Self {
+            user: if is("userState", "idle") {
+                UserIdleState::Idle
+            } else {
+                UserIdleState::Active
+            },
+            screen: if is("screenState", "locked") {
+                ScreenIdleState::Locked
+            } else {
+                ScreenIdleState::Unlocked
+            },
+        }
+    }
+}
+
+/// The running `IdleDetector`, if any.
+#[derive(Default)]
+struct Detector {
+    controller: RefCell<Option<AbortController>>,
+    listener: RefCell<Option<EventListener>>,
+    /// Cleared on unmount, so a pending start is abandoned.
+    mounted: Cell<bool>,
+}
+
+impl Detector {
+    fn stop(&self) {
+        if let Some(controller) = self.controller.take() {
+            controller.abort();
+        }
+        self.listener.take();
+    }
+}
+
+fn idle_detector() -> Option<js_sys::Function> {
+    js::get(&window(), "IdleDetector").and_then(|ctor| ctor.dyn_into().ok())
+}
+
+fn error_message(e: &JsValue) -> String {
+    js::get(e, "message")
+        .and_then(|message| message.as_string())
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+/// State handle for the [`use_idle_detection`] hook.
+#[derive(Clone)]
+pub struct UseIdleDetectionHandle {
+    state: UseStateHandle<Option<IdleDetectionState>>,
+    running: UseStateHandle<bool>,
+    error: UseStateHandle<Option<String>>,
+    permission: Option<PermissionState>,
+    detector: Rc<Detector>,
+    threshold: Duration,
+}
+
+impl UseIdleDetectionHandle {
+    /// Whether the Idle Detection API is available (Chromium only, in a
+    /// secure context).
+    pub fn is_supported(&self) -> bool {
+        idle_detector().is_some()
+    }
+
+    /// The latest user and screen state, once detection has started.
+    pub fn state(&self) -> Option<IdleDetectionState> {
+        *self.state
+    }
+
+    /// Whether detection is running.
+    pub fn running(&self) -> bool {
+        *self.running
+    }
+
+    /// The error of the last [`start`](Self::start), if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The `idle-detection` permission; `None` until known or where it
+    /// cannot be queried.
+    pub fn permission(&self) -> Option<PermissionState> {
+        self.permission
+    }
+
+    /// Ask for the permission if needed and start detecting. Prompts the
+    /// first time; call from a user gesture.
+    pub fn start(&self) {
+        let Some(constructor) = idle_detector() else {
+            self.error
+                .set(Some("Idle Detection API is not supported".to_string()));
+            return;
+        };
+        self.detector.stop();
+        let state = self.state.clone();
+        let running = self.running.clone();
+        let error = self.error.clone();
+        let detector = self.detector.clone();
+        let threshold = u32::try_from(self.threshold.as_millis()).unwrap_or(u32::MAX);
+        spawn_local(async move {
+            let permission = js::call_async(&constructor, "requestPermission", &[]).await;
+            if !detector.mounted.get() {
+                return;
+            }
+            if permission.ok().and_then(|p| p.as_string()).as_deref() != Some("granted") {
+                error.set(Some("idle detection permission denied".to_string()));
+                return;
+            }
+            let (idle_detector, controller) = match (
+                js_sys::Reflect::construct(&constructor, &js_sys::Array::new()),
+                AbortController::new(),
+            ) {
+                (Ok(idle_detector), Ok(controller)) => (idle_detector, controller),
+                (Err(e), _) | (_, Err(e)) => {
+                    error.set(Some(error_message(&e)));
+                    return;
+                }
+            };
+            let target = idle_detector.clone().unchecked_into::<EventTarget>();
+            let listener = {
+                let state = state.clone();
+                let idle_detector = idle_detector.clone();
+                EventListener::new(&target, "change", move |_| {
+                    state.set(Some(IdleDetectionState::read(&idle_detector)));
+                })
+            };
+            let options = js::object(&[
+                ("threshold", threshold.into()),
+                ("signal", controller.signal().into()),
+            ]);
+            *detector.controller.borrow_mut() = Some(controller.clone());
+            *detector.listener.borrow_mut() = Some(listener);
+
+            let started = js::call_async(&idle_detector, "start", &[options.into()]).await;
+            // Stopped, restarted or unmounted while starting.
+            if controller.signal().aborted() {
+                return;
+            }
+            match started {
+                Ok(_) => {
+                    state.set(Some(IdleDetectionState::read(&idle_detector)));
+                    running.set(true);
+                    error.set(None);
+                }
+                Err(e) => {
+                    detector.stop();
+                    error.set(Some(error_message(&e)));
+                }
+            }
+        });
+    }
+
+    /// Stop detecting and forget the last state.
+    pub fn stop(&self) {
+        self.detector.stop();
+        self.running.set(false);
+        self.state.set(None);
+    }
+}
+
+impl PartialEq for UseIdleDetectionHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.state == *other.state
+            && *self.running == *other.running
+            && *self.error == *other.error
+            && self.permission == other.permission
+            && Rc::ptr_eq(&self.detector, &other.detector)
+            && self.threshold == other.threshold
+    }
+}
+
+/// A hook for the Idle Detection API, which reports whether the user is idle
+/// across the whole device (not just this page) and whether the screen is
+/// locked, e.g. for chat presence or kiosks that reset between visitors.
+///
+/// The API needs the `idle-detection` permission, so detection starts with
+/// [`start`](UseIdleDetectionHandle::start) from a user gesture, or on mount
+/// once granted with [`IdleDetectionOptions::immediate`]. Check
+/// [`is_supported`](UseIdleDetectionHandle::is_supported) first: only
+/// Chromium browsers have it. For activity within the page, use
+/// [`use_idle`](crate::use_idle).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Presence)]
+/// fn presence() -> Html {
+///     let idle = use_idle_detection(IdleDetectionOptions::default());
+///
+///     let onclick = {
+///         let idle = idle.clone();
+///         Callback::from(move |_| idle.start())
+///     };
+///
+///     html! {
+///         if !idle.is_supported() {
+///             <></>
+///         } else if let Some(state) = idle.state() {
+///             <p>
+///                 { match (state.user, state.screen) {
+///                     (_, ScreenIdleState::Locked) => "Away (screen locked)",
+///                     (UserIdleState::Idle, _) => "Away",
+///                     (UserIdleState::Active, _) => "Online",
+///                 } }
+///             </p>
+///         } else {
+///             <button {onclick}>{ "Share my away status" }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_idle_detection(options: IdleDetectionOptions) -> UseIdleDetectionHandle {
+    let state = use_state_eq(|| None);
+    let running = use_state_eq(|| false);
+    let error = use_state_eq(|| None);
+    let permission = use_permission("idle-detection".to_string());
+    let detector = use_memo((), |_| Detector::default());
+
+    let handle = UseIdleDetectionHandle {
+        state,
+        running,
+        error,
+        permission,
+        detector,
+        threshold: options.threshold,
+    };
+
+    {
+        let detector = handle.detector.clone();
+        use_effect_with((), move |_| {
+            detector.mounted.set(true);
+            move || {
+                detector.mounted.set(false);
+                detector.stop();
+            }
+        });
+    }
+
+    {
+        let handle = handle.clone();
+        let granted = permission == Some(PermissionState::Granted);
+        use_effect_with(granted && options.immediate, move |start| {
+            if *start && !handle.running() {
+                handle.start();
+            }
+        });
+    }
+
+    handle
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "serde")]
+
+use gloo::utils::{body, window};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{HtmlElement, ResizeObserver};
+use yew::prelude::*;
+
+use crate::{MessageTarget, WindowMessagingOptions, use_window_messaging};
+
+/// Document size reported by [`use_iframe_resize`], in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IframeSize {
+    /// Width of `<body>`, including its margins.
+    pub width: f64,
+    /// Height of `<body>`, including its margins.
+    pub height: f64,
+}
+
+/// Embedded-side hook that measures `<body>` (with its margins) whenever it resizes
+/// and posts the size to the embedding page on `target_origin` through
+/// [`use_window_messaging`].
+/// Pair it with [`use_iframe_resize_host`] in the parent page.
+///
+/// `options.target` is forced to [`MessageTarget::Parent`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Widget)]
+/// fn widget() -> Html {
+///     use_iframe_resize(
+///         "https://host.example".to_string(),
+///         WindowMessagingOptions {
+///             channel: "comments-widget".to_string(),
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! { <p>{ "Content that grows and shrinks" }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_iframe_resize(target_origin: String, options: WindowMessagingOptions) {
+    let messaging = use_window_messaging::<(), IframeSize>(
+        target_origin.clone(),
+        WindowMessagingOptions {
+            target: MessageTarget::Parent,
+            ..options
+        },
+    );
+    let last_sent = use_mut_ref(|| None::<IframeSize>);
+
+    // Observing again reports the current size to a new origin right away.
+    use_effect_with(target_origin, move |_| {
+        last_sent.borrow_mut().take();
+        let report = Closure::<dyn FnMut()>::new(move || {
+            // Measure the observed `<body>` itself: the root element is as tall
+            // as the viewport under `html { height: 100% }`, so the iframe
+            // could grow but never shrink.
+            let body = body();
+            let rect = body.get_bounding_client_rect();
+            let style = window().get_computed_style(&body).ok().flatten();
+            let margin = |side: &str| {
+                style
+                    .as_ref()
+                    .and_then(|style| style.get_property_value(&format!("margin-{side}")).ok())
+                    .and_then(|margin| margin.trim_end_matches("px").parse::<f64>().ok())
+                    .unwrap_or(0.0)
+            };
+            let size = IframeSize {
+                width: (rect.width() + margin("left") + margin("right")).ceil(),
+                height: (rect.height() + margin("top") + margin("bottom")).ceil(),
+            };
+            if *last_sent.borrow() != Some(size) && messaging.send(&size).is_ok() {
+                *last_sent.borrow_mut() = Some(size);
+            }
+        });
+        let observer = ResizeObserver::new(report.as_ref().unchecked_ref()).ok();
+        if let Some(observer) = &observer {
+            observer.observe(&body());
+        }
+        move || {
+            if let Some(observer) = observer {
+                observer.disconnect();
+            }
+            drop(report);
+        }
+    });
+}
+
+/// Parent-side counterpart of [`use_iframe_resize`]: listens for size updates
+/// from the iframe behind `iframe`, whose page is served from `target_origin`,
+/// and applies the reported height to it.
+/// Returns the last reported size.
+///
+/// Use the same `channel` on both sides, and a distinct one per embedded
+/// iframe when the page hosts several.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Host)]
+/// fn host() -> Html {
+///     let iframe = use_node_ref();
+///     use_iframe_resize_host(
+///         iframe.clone(),
+///         "https://widget.example".to_string(),
+///         WindowMessagingOptions {
+///             allowed_origins: vec!["https://widget.example".to_string()],
+///             channel: "comments-widget".to_string(),
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! { <iframe ref={iframe} src="https://widget.example/comments" /> }
+/// }
+/// ```
+#[hook]
+pub fn use_iframe_resize_host(
+    iframe: NodeRef,
+    target_origin: String,
+    options: WindowMessagingOptions,
+) -> Option<IframeSize> {
+    let messaging = use_window_messaging::<IframeSize, ()>(
+        target_origin,
+        WindowMessagingOptions {
+            target: MessageTarget::Frame(iframe.clone()),
+            ..options
+        },
+    );
+    let size = messaging.last_message().map(|m| m.data);
+
+    use_effect_with((iframe, size), |(iframe, size)| {
+        if let (Some(element), Some(size)) = (iframe.cast::<HtmlElement>(), size) {
+            let _ = element
+                .style()
+                .set_property("height", &format!("{}px", size.height));
+        }
+    });
+
+    size
+}
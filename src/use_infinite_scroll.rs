@@ -0,0 +1,108 @@
+use yew::prelude::*;
+use yew_hooks::use_latest;
+
+use crate::{ElementVisibilityOptions, use_element_visibility};
+
+/// Options for the [`use_infinite_scroll`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfiniteScrollOptions {
+    /// Load when the sentinel comes within this many pixels of the viewport.
+    pub distance: u32,
+    /// Whether a page is being loaded; no further load is requested meanwhile.
+    pub loading: bool,
+    /// Whether there is more to load; `false` stops observing the sentinel.
+    pub has_more: bool,
+}
+
+impl Default for InfiniteScrollOptions {
+    fn default() -> Self {
+        Self {
+            distance: 200,
+            loading: false,
+            has_more: true,
+        }
+    }
+}
+
+/// A hook for infinite lists: emits `on_load_more` when the sentinel element
+/// behind `node`, placed after the last item, scrolls within
+/// [`distance`](InfiniteScrollOptions::distance) of the viewport.
+///
+/// Nothing is requested while [`loading`](InfiniteScrollOptions::loading);
+/// once it turns `false`, the next page is requested right away if the
+/// sentinel is still in range, so short pages keep filling the viewport.
+/// When [`has_more`](InfiniteScrollOptions::has_more) turns `false`, the
+/// sentinel is no longer observed.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Feed)]
+/// fn feed() -> Html {
+///     let items = use_state(Vec::<String>::new);
+///     let loading = use_state(|| false);
+///     let sentinel = use_node_ref();
+///
+///     let on_load_more = {
+///         let items = items.clone();
+///         let loading = loading.clone();
+///         Callback::from(move |_| {
+///             loading.set(true);
+///             // Fetch the next page, then append it and reset `loading`.
+///             let mut next = (*items).clone();
+///             next.extend((next.len()..next.len() + 20).map(|i| format!("Item {i}")));
+///             items.set(next);
+///             loading.set(false);
+///         })
+///     };
+///     use_infinite_scroll(
+///         sentinel.clone(),
+///         on_load_more,
+///         InfiniteScrollOptions {
+///             loading: *loading,
+///             has_more: items.len() < 200,
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <ul>
+///             { for items.iter().map(|item| html! { <li>{ item }</li> }) }
+///             <li ref={sentinel} aria-hidden="true" />
+///         </ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_infinite_scroll(
+    node: NodeRef,
+    on_load_more: Callback<()>,
+    options: InfiniteScrollOptions,
+) {
+    let on_load_more = use_latest(on_load_more);
+    let observed = if options.has_more {
+        node
+    } else {
+        NodeRef::default()
+    };
+    let visibility = use_element_visibility(
+        observed,
+        ElementVisibilityOptions {
+            root_margin: format!("{}px", options.distance),
+            ..Default::default()
+        },
+    );
+
+    use_effect_with(
+        (visibility.is_visible, options.loading, options.has_more),
+        move |(visible, loading, has_more)| {
+            if *visible && !*loading && *has_more {
+                on_load_more.current().emit(());
+            }
+        },
+    );
+}
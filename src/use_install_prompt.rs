@@ -0,0 +1,131 @@
+use gloo::{
+    events::{EventListener, EventListenerOptions},
+    utils::window,
+};
+use web_sys::Event;
+use yew::prelude::*;
+
+use crate::{js, use_media_query::matches};
+
+/// How the user answered the install prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstallChoice {
+    Accepted,
+    Dismissed,
+}
+
+/// Whether the page runs as an installed app.
+fn is_standalone() -> bool {
+    matches("(display-mode: standalone)")
+}
+
+/// State handle for the [`use_install_prompt`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseInstallPromptHandle {
+    deferred: UseStateHandle<Option<Event>>,
+    installed: UseStateHandle<bool>,
+}
+
+impl UseInstallPromptHandle {
+    /// Whether the browser offered to install the app, so an "Install app"
+    /// button can be shown. Only Chromium browsers fire
+    /// `beforeinstallprompt`.
+    pub fn can_install(&self) -> bool {
+        self.deferred.is_some()
+    }
+
+    /// Whether the app was installed from this page (`appinstalled`), or the
+    /// page is running as the installed app.
+    pub fn installed(&self) -> bool {
+        *self.installed
+    }
+
+    /// Show the browser's install prompt and return the user's choice.
+    /// `None` if there is nothing to prompt for. The prompt can only be shown
+    /// once per `beforeinstallprompt`; call from a user gesture.
+    pub async fn prompt(&self) -> Option<InstallChoice> {
+        let event = (*self.deferred).clone()?;
+        self.deferred.set(None);
+        js::call_async(&event, "prompt", &[]).await.ok()?;
+        let choice = js::get(&event, "userChoice")?;
+        let choice = wasm_bindgen_futures::JsFuture::from(js_sys::Promise::resolve(&choice))
+            .await
+            .ok()?;
+        match js::get(&choice, "outcome")?.as_string()?.as_str() {
+            "accepted" => Some(InstallChoice::Accepted),
+            _ => Some(InstallChoice::Dismissed),
+        }
+    }
+}
+
+/// A hook that captures the `beforeinstallprompt` event, so a PWA can offer
+/// its own "Install app" button instead of the browser's mini-infobar, and
+/// show the install prompt from it.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::{platform::spawn_local, prelude::*};
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(InstallButton)]
+/// fn install_button() -> Html {
+///     let install = use_install_prompt();
+///
+///     let onclick = {
+///         let install = install.clone();
+///         Callback::from(move |_| {
+///             let install = install.clone();
+///             spawn_local(async move {
+///                 if install.prompt().await == Some(InstallChoice::Dismissed) {
+///                     log::info!("install dismissed");
+///                 }
+///             });
+///         })
+///     };
+///
+///     html! {
+///         if install.can_install() && !install.installed() {
+///             <button {onclick}>{ "Install app" }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_install_prompt() -> UseInstallPromptHandle {
+    let deferred = use_state_eq(|| None);
+    let installed = use_state_eq(is_standalone);
+
+    {
+        let deferred = deferred.clone();
+        let installed = installed.clone();
+        use_effect_with((), move |_| {
+            let listeners = [
+                EventListener::new_with_options(
+                    &window(),
+                    "beforeinstallprompt",
+                    EventListenerOptions::enable_prevent_default(),
+                    {
+                        let deferred = deferred.clone();
+                        move |event| {
+                            // Keep the browser from showing its own prompt.
+                            event.prevent_default();
+                            deferred.set(Some(event.clone()));
+                        }
+                    },
+                ),
+                EventListener::new(&window(), "appinstalled", move |_| {
+                    deferred.set(None);
+                    installed.set(true);
+                }),
+            ];
+            move || drop(listeners)
+        });
+    }
+
+    UseInstallPromptHandle {
+        deferred,
+        installed,
+    }
+}
@@ -0,0 +1,321 @@
+use gloo::{
+    events::{EventListener, EventListenerOptions, EventListenerPhase},
+    utils::{document, window},
+};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Element, HtmlElement, MutationObserver, MutationObserverInit, MutationRecord};
+use yew::prelude::*;
+use yew_hooks::use_update;
+
+/// Elements that can take keyboard focus, before `tabindex`, `disabled`
+/// and visibility are checked.
+const FOCUSABLE: &str = "a[href], area[href], button, input, select, textarea, iframe, \
+    summary, audio[controls], video[controls], [contenteditable]:not([contenteditable='false']), \
+    [tabindex]";
+
+/// A key combination, matched against `KeyboardEvent.code` so it works with
+/// any keyboard layout and modifier (e.g. <kbd>Alt</kbd> on macOS changes `key`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardShortcut {
+    /// Physical key, e.g. `"KeyF"` or `"F9"`.
+    pub code: String,
+    /// Whether <kbd>Ctrl</kbd> is held.
+    pub ctrl: bool,
+    /// Whether <kbd>Alt</kbd> (<kbd>Option</kbd> on macOS) is held.
+    pub alt: bool,
+    /// Whether <kbd>Shift</kbd> is held.
+    pub shift: bool,
+    /// Whether <kbd>Meta</kbd> (<kbd>Cmd</kbd> on macOS) is held.
+    pub meta: bool,
+}
+
+impl KeyboardShortcut {
+    /// Whether `event` is this shortcut.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        event.code() == self.code
+            && event.ctrl_key() == self.ctrl
+            && event.alt_key() == self.alt
+            && event.shift_key() == self.shift
+            && event.meta_key() == self.meta
+    }
+}
+
+/// Options for the [`use_keyboard_focus_order_debugger`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusOrderDebuggerOptions {
+    /// Toggles the overlay; `None` leaves toggling to the handle.
+    pub shortcut: Option<KeyboardShortcut>,
+    /// Whether the debugger does anything; defaults to debug builds only, so
+    /// the hook can stay in place for production builds.
+    pub enabled: bool,
+    /// Outline and label color.
+    pub color: String,
+}
+
+impl Default for FocusOrderDebuggerOptions {
+    fn default() -> Self {
+        Self {
+            shortcut: Some(KeyboardShortcut {
+                code: "KeyO".to_string(),
+                ctrl: false,
+                alt: true,
+                shift: true,
+                meta: false,
+            }),
+            enabled: cfg!(debug_assertions),
+            color: "#d6006f".to_string(),
+        }
+    }
+}
+
+/// Elements in `container` reachable with <kbd>Tab</kbd>, in tab order:
+/// positive `tabindex` values first (ascending), then document order.
+fn tab_order(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    let mut elements: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|i| nodes.get(i)?.dyn_into::<HtmlElement>().ok())
+        .filter(|element| {
+            element.tab_index() >= 0
+                && !element.matches(":disabled").unwrap_or(false)
+                && element.closest("[inert]").ok().flatten().is_none()
+                && element.get_client_rects().length() > 0
+        })
+        .collect();
+    // Stable, so equal `tabindex` values keep document order.
+    elements.sort_by_key(|element| match element.tab_index() {
+        0 => i32::MAX,
+        index => index,
+    });
+    elements
+}
+
+/// Replace the contents of `overlay` with a numbered outline per element.
+fn draw(overlay: &Element, container: &Element, color: &str) {
+    overlay.set_inner_html("");
+    for (i, element) in tab_order(container).iter().enumerate() {
+        let rect = element.get_bounding_client_rect();
+        let Ok(outline) = document().create_element("div") else {
+            continue;
+        };
+        let _ = outline.set_attribute(
+            "style",
+            &format!(
+                "position:absolute;left:{}px;top:{}px;width:{}px;height:{}px;\
+                 outline:2px solid {color};outline-offset:1px;",
+                rect.left(),
+                rect.top(),
+                rect.width(),
+                rect.height(),
+            ),
+        );
+        if let Ok(label) = document().create_element("span") {
+            let _ = label.set_attribute(
+                "style",
+                &format!(
+                    "position:absolute;left:-3px;top:-19px;padding:0 4px;border-radius:3px;\
+                     background:{color};color:#fff;font:bold 11px/16px monospace;"
+                ),
+            );
+            let tab_index = element.tab_index();
+            label.set_text_content(Some(&if tab_index > 0 {
+                format!("{} (tabindex={tab_index})", i + 1)
+            } else {
+                (i + 1).to_string()
+            }));
+            let _ = outline.append_child(&label);
+        }
+        let _ = overlay.append_child(&outline);
+    }
+}
+
+/// Show or hide the overlay, re-rendering if that changes anything.
+fn set_visible(visible: &Cell<bool>, update: &dyn Fn(), value: bool) {
+    if visible.replace(value) != value {
+        update();
+    }
+}
+
+/// State handle for the [`use_keyboard_focus_order_debugger`] hook.
+#[derive(Clone)]
+pub struct UseFocusOrderDebuggerHandle {
+    visible: Rc<Cell<bool>>,
+    update: Rc<dyn Fn()>,
+    /// `visible` when the handle was created, so handles from different
+    /// renders compare unequal.
+    shown: bool,
+}
+
+impl UseFocusOrderDebuggerHandle {
+    /// Whether the overlay is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible.get()
+    }
+
+    /// Show the overlay.
+    pub fn show(&self) {
+        set_visible(&self.visible, &*self.update, true);
+    }
+
+    /// Hide the overlay.
+    pub fn hide(&self) {
+        set_visible(&self.visible, &*self.update, false);
+    }
+
+    /// Show the overlay if it is hidden, hide it otherwise.
+    pub fn toggle(&self) {
+        set_visible(&self.visible, &*self.update, !self.visible.get());
+    }
+}
+
+impl PartialEq for UseFocusOrderDebuggerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.visible, &other.visible) && self.shown == other.shown
+    }
+}
+
+/// A development hook that overlays the keyboard tab order of `container`:
+/// every element reachable with <kbd>Tab</kbd> gets a numbered outline, so
+/// regressions in focus order (stray positive `tabindex`, CSS reordering
+/// that diverges from DOM order) are visible at a glance.
+///
+/// The overlay is toggled with [`FocusOrderDebuggerOptions::shortcut`]
+/// (<kbd>Alt</kbd>+<kbd>Shift</kbd>+<kbd>O</kbd> by default) or the handle,
+/// and follows DOM changes, scrolling and resizing. It ignores pointer
+/// events. By default the hook does nothing in release builds.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(CheckoutForm)]
+/// fn checkout_form() -> Html {
+///     let form = use_node_ref();
+///     let _focus_order = use_keyboard_focus_order_debugger(form.clone(), Default::default());
+///
+///     html! {
+///         <form ref={form}>
+///             <input name="name" />
+///             <input name="card" />
+///             <button>{ "Pay" }</button>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_keyboard_focus_order_debugger(
+    container: NodeRef,
+    options: FocusOrderDebuggerOptions,
+) -> UseFocusOrderDebuggerHandle {
+    let visible = use_memo((), |_| Cell::new(false));
+    let update = use_update();
+    let shown = visible.get();
+
+    {
+        let visible = visible.clone();
+        let update = update.clone();
+        use_effect_with(
+            (options.shortcut.clone(), options.enabled),
+            move |(shortcut, enabled)| {
+                let listener = shortcut.clone().filter(|_| *enabled).map(|shortcut| {
+                    EventListener::new(&window(), "keydown", move |e| {
+                        if let Some(e) = e.dyn_ref::<KeyboardEvent>()
+                            && shortcut.matches(e)
+                        {
+                            e.prevent_default();
+                            // Read the shared cell: a captured state handle
+                            // would keep the value of the render that added the listener.
+                            set_visible(&visible, &*update, !visible.get());
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+        );
+    }
+
+    use_effect_with(
+        (container, shown && options.enabled, options.color),
+        move |(container, show, color)| {
+            let overlay = container
+                .cast::<Element>()
+                .filter(|_| *show)
+                .and_then(|container| {
+                    let overlay = document().create_element("div").ok()?;
+                    let _ = overlay.set_attribute("aria-hidden", "true");
+                    let _ = overlay.set_attribute(
+                        "style",
+                        "position:fixed;inset:0;pointer-events:none;z-index:2147483647;",
+                    );
+                    document().body()?.append_child(&overlay).ok()?;
+
+                    let redraw = {
+                        let overlay = overlay.clone();
+                        let container = container.clone();
+                        let color = color.clone();
+                        Rc::new(move || draw(&overlay, &container, &color))
+                    };
+                    redraw();
+
+                    let callback = {
+                        let overlay = overlay.clone();
+                        let redraw = redraw.clone();
+                        Closure::<dyn FnMut(js_sys::Array)>::new(move |records: js_sys::Array| {
+                            // Drawing mutates the overlay, which may be inside the container.
+                            let external = records.iter().any(|record| {
+                                let record: MutationRecord = record.unchecked_into();
+                                !record
+                                    .target()
+                                    .is_some_and(|target| overlay.contains(Some(&target)))
+                            });
+                            if external {
+                                redraw();
+                            }
+                        })
+                    };
+                    let observer = MutationObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                    let init = MutationObserverInit::new();
+                    init.set_child_list(true);
+                    init.set_subtree(true);
+                    init.set_attributes(true);
+                    let _ = observer.observe_with_options(&container, &init);
+
+                    let listeners =
+                        [("scroll", true), ("resize", false)].map(|(event, capture)| {
+                            let redraw = redraw.clone();
+                            let options = EventListenerOptions {
+                                phase: if capture {
+                                    EventListenerPhase::Capture
+                                } else {
+                                    EventListenerPhase::Bubble
+                                },
+                                passive: true,
+                            };
+                            EventListener::new_with_options(&window(), event, options, move |_| {
+                                redraw()
+                            })
+                        });
+                    Some((overlay, observer, callback, listeners))
+                });
+            move || {
+                if let Some((overlay, observer, callback, listeners)) = overlay {
+                    observer.disconnect();
+                    drop(callback);
+                    drop(listeners);
+                    overlay.remove();
+                }
+            }
+        },
+    );
+
+    UseFocusOrderDebuggerHandle {
+        visible,
+        update,
+        shown,
+    }
+}
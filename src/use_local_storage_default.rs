@@ -3,17 +3,20 @@
 use gloo::storage::{LocalStorage, Storage};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use std::{ops::Deref, rc::Rc};
+use std::{cell::Cell, ops::Deref, rc::Rc};
 use web_sys::StorageEvent;
 use yew::prelude::*;
 
 use yew_hooks::use_event_with_window;
 
+use crate::storage::{StorageOptions, debounce};
+
 /// State handle for the [`use_local_storage_default`] hook.
 #[derive(Clone, Debug)]
 pub struct UseLocalStorageDefaultHandle<T> {
     inner: UseStateHandle<T>,
     key: Rc<String>,
+    consent: Rc<Cell<bool>>,
 }
 
 impl<T> UseLocalStorageDefaultHandle<T>
@@ -25,13 +28,33 @@ where
     where
         T: Serialize + Clone,
     {
-        if LocalStorage::set(&*self.key, value.clone()).is_ok() {
-            let ser = serde_json::to_string(&value).unwrap_or_default();
+        if !self.consent.get() {
+            self.inner.set(value);
+            return;
+        }
+        // Serialized once, from a reference, for both storage and the log.
+        let Ok(ser) = serde_json::to_string(&value) else {
+            return;
+        };
+        if LocalStorage::raw().set_item(&self.key, &ser).is_ok() {
             info!("Set storage: {} = {ser}", &*self.key);
             self.inner.set(value);
         }
     }
 
+    /// Update part of the stored value (e.g. `|s| s.font_size = 14`) and
+    /// persist it once. The current value is copied once to become the new
+    /// state; it is not copied again to be stored.
+    pub fn set_with<F>(&self, f: F)
+    where
+        T: Serialize + Clone,
+        F: FnOnce(&mut T),
+    {
+        let mut value = (*self.inner).clone();
+        f(&mut value);
+        self.set(value);
+    }
+
     /// Delete a key and it's stored value.
     /// Resets stored value to [`Default`].
     #[allow(unused)]
@@ -69,7 +92,7 @@ where
 /// ```rust
 /// # use yew::prelude::*;
 /// #
-/// use yew_hooks::prelude::*;
+/// use more_yew_hooks::*;
 ///
 /// #[function_component(LocalStorage)]
 /// fn local_storage() -> Html {
@@ -103,14 +126,152 @@ pub fn use_local_storage_default<T>(key: String) -> UseLocalStorageDefaultHandle
 where
     T: for<'de> Deserialize<'de> + Default + 'static,
 {
-    let inner: UseStateHandle<T> =
-        use_state(|| LocalStorage::get(&key).ok().flatten().unwrap_or_default());
-    let key = use_memo((), |_| key);
+    use_local_storage_default_with_options(key, StorageOptions::default())
+}
+
+/// [`use_local_storage_default`] with [`StorageOptions`], e.g. to debounce
+/// `storage` events when another tab writes the key rapidly.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Draft)]
+/// fn draft() -> Html {
+///     let draft = use_local_storage_default_with_options::<String>(
+///         "draft".to_string(),
+///         StorageOptions {
+///             listen_debounce: Duration::from_millis(300),
+///         },
+///     );
+///
+///     html! { <p>{ &*draft }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_local_storage_default_with_options<T>(
+    key: String,
+    options: StorageOptions,
+) -> UseLocalStorageDefaultHandle<T>
+where
+    T: for<'de> Deserialize<'de> + Default + 'static,
+{
+    use_local_storage_default_impl(key, options, true)
+}
+
+/// [`use_local_storage_default`] that keeps the value purely in memory until
+/// `consent` is granted (e.g. from a cookie banner), then flushes the current
+/// value to `localStorage` and persists from then on.
+///
+/// Without consent the stored value is neither read nor written, and `storage`
+/// events are ignored. Revoking consent stops persisting but does not delete
+/// what is already stored; call [`UseLocalStorageDefaultHandle::delete`] for that.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Preferences)]
+/// fn preferences() -> Html {
+///     let consent = use_local_storage_default::<bool>("cookie-consent".to_string());
+///     let theme = use_local_storage_default_with_consent::<String>("theme".to_string(), *consent);
+///
+///     let onaccept = {
+///         let consent = consent.clone();
+///         Callback::from(move |_| consent.set(true))
+///     };
+///
+///     html! {
+///         <div>
+///             <p>{ format!("Theme: {}", &*theme) }</p>
+///             if !*consent {
+///                 <button onclick={onaccept}>{ "Accept cookies" }</button>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_local_storage_default_with_consent<T>(
+    key: String,
+    consent: bool,
+) -> UseLocalStorageDefaultHandle<T>
+where
+    T: for<'de> Deserialize<'de> + Serialize + Default + 'static,
+{
+    let handle: UseLocalStorageDefaultHandle<T> =
+        use_local_storage_default_impl(key, StorageOptions::default(), consent);
+    let previous = use_mut_ref(|| consent);
+
+    {
+        let key = handle.key.clone();
+        let inner = handle.inner.clone();
+        use_effect_with(consent, move |&consent| {
+            let granted = consent && !previous.replace(consent);
+            if granted && LocalStorage::set(&*key, &*inner).is_ok() {
+                info!("Consent granted, flushed storage: {}", &*key);
+            }
+        });
+    }
+
+    handle
+}
+
+#[hook]
+fn use_local_storage_default_impl<T>(
+    key: String,
+    options: StorageOptions,
+    consent: bool,
+) -> UseLocalStorageDefaultHandle<T>
+where
+    T: for<'de> Deserialize<'de> + Default + 'static,
+{
+    let inner: UseStateHandle<T> = use_state(|| {
+        if consent {
+            LocalStorage::get(&key).ok().flatten().unwrap_or_default()
+        } else {
+            T::default()
+        }
+    });
+    let key = use_memo(key, |key| key.clone());
+    let pending = use_mut_ref(|| None);
+    let consent = {
+        let cell = use_memo((), |_| Cell::new(consent));
+        cell.set(consent);
+        cell
+    };
+
+    {
+        // Load the value stored under a new key.
+        let inner = inner.clone();
+        let consent = consent.clone();
+        let loaded = use_mut_ref(|| key.clone());
+        use_effect_with(key.clone(), move |key| {
+            if !Rc::ptr_eq(&loaded.replace(key.clone()), key) {
+                inner.set(if consent.get() {
+                    LocalStorage::get(&**key).ok().flatten().unwrap_or_default()
+                } else {
+                    T::default()
+                });
+            }
+        });
+    }
 
     {
         let key = key.clone();
         let inner = inner.clone();
+        let consent = consent.clone();
         use_event_with_window("storage", move |e: StorageEvent| {
+            if !consent.get() {
+                return;
+            }
             let Some(k) = e.key() else {
                 return;
             };
@@ -120,10 +281,18 @@ where
             }
             if k == *key {
                 info!("Storage event for key: {k}");
-                inner.set(LocalStorage::get(&*key).unwrap_or_default());
+                let key = key.clone();
+                let inner = inner.clone();
+                debounce(&pending, options.listen_debounce, move || {
+                    inner.set(LocalStorage::get(&*key).unwrap_or_default());
+                });
             }
         });
     }
 
-    UseLocalStorageDefaultHandle { inner, key }
+    UseLocalStorageDefaultHandle {
+        inner,
+        key,
+        consent,
+    }
 }
@@ -0,0 +1,220 @@
+#![cfg(feature = "serde")]
+
+use futures::future::{Either, select};
+use gloo::{net::http::Request, timers::future::TimeoutFuture};
+use serde::de::DeserializeOwned;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+use web_sys::{AbortController, RequestCache};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{use_online, use_page_visibility};
+
+/// Delay before retrying after a failed request; doubled per failure.
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Outcome of one long-poll request.
+enum Poll<T> {
+    Message(T),
+    /// Timed out or answered without a message (`204`); re-issue right away.
+    Empty,
+    Failed(String),
+}
+
+async fn poll<T: DeserializeOwned>(
+    url: &str,
+    timeout: Duration,
+    controller: &AbortController,
+) -> Poll<T> {
+    let request = Request::get(url)
+        .cache(RequestCache::NoStore)
+        .abort_signal(Some(&controller.signal()))
+        .send();
+    let millis = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+    let response = match select(Box::pin(request), TimeoutFuture::new(millis)).await {
+        Either::Left((Ok(response), _)) => response,
+        Either::Left((Err(e), _)) => return Poll::Failed(e.to_string()),
+        Either::Right(_) => {
+            controller.abort();
+            return Poll::Empty;
+        }
+    };
+    match response.status() {
+        204 => Poll::Empty,
+        _ if response.ok() => match response.json::<T>().await {
+            Ok(message) => Poll::Message(message),
+            Err(e) => Poll::Failed(e.to_string()),
+        },
+        status => Poll::Failed(format!("{status} {}", response.status_text())),
+    }
+}
+
+#[derive(Default)]
+struct Poller {
+    /// Bumped to stop the running poll loop.
+    generation: Cell<u64>,
+    /// Aborts the request held open.
+    controller: RefCell<Option<AbortController>>,
+}
+
+impl Poller {
+    fn stop(&self) {
+        self.generation.set(self.generation.get() + 1);
+        if let Some(controller) = self.controller.borrow_mut().take() {
+            controller.abort();
+        }
+    }
+}
+
+/// State handle for the [`use_long_poll`] hook.
+pub struct UseLongPollHandle<T> {
+    message: UseStateHandle<Option<Rc<T>>>,
+    error: UseStateHandle<Option<String>>,
+    active: bool,
+}
+
+impl<T> UseLongPollHandle<T> {
+    /// The latest message received.
+    pub fn message(&self) -> Option<Rc<T>> {
+        (*self.message).clone()
+    }
+
+    /// The error of the last request, if it failed; cleared by the next
+    /// successful one. Failed requests are retried with backoff.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Whether a request is held open: the page is visible and online.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl<T> Clone for UseLongPollHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            message: self.message.clone(),
+            error: self.error.clone(),
+            active: self.active,
+        }
+    }
+}
+
+impl<T> PartialEq for UseLongPollHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_message = match (&*self.message, &*other.message) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        same_message && *self.error == *other.error && self.active == other.active
+    }
+}
+
+/// A hook that long-polls `url`: it holds a request open for up to
+/// `timeout`, and re-issues it as soon as it is answered or times out, for
+/// backends that offer neither WebSockets nor server-sent events.
+///
+/// A `200` response body is deserialized from JSON into `T` and becomes the
+/// latest [`message`](UseLongPollHandle::message); a `204` or a timeout just
+/// re-issues the request. Failed requests are retried with exponential
+/// backoff. Polling pauses while the page is hidden or the browser is
+/// offline, and the open request is aborted on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct JobUpdate {
+///     progress: u8,
+/// }
+///
+/// #[function_component(JobProgress)]
+/// fn job_progress() -> Html {
+///     let updates = use_long_poll::<JobUpdate>(
+///         "/api/jobs/42/updates".to_string(),
+///         Duration::from_secs(30),
+///     );
+///
+///     html! {
+///         <progress max="100" value={updates.message().map_or(0, |u| u.progress).to_string()} />
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_long_poll<T>(url: String, timeout: Duration) -> UseLongPollHandle<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    let message = use_state(|| None);
+    let error = use_state_eq(|| None);
+    let visibility = use_page_visibility();
+    let online = use_online();
+    let poller = use_memo((), |_| Poller::default());
+
+    let active = visibility.is_visible() && *online;
+
+    {
+        let message = message.clone();
+        let error = error.clone();
+        use_effect_with((url, timeout, active), move |(url, timeout, active)| {
+            poller.stop();
+            let id = poller.generation.get();
+            if *active {
+                let url = url.clone();
+                let timeout = *timeout;
+                let poller = poller.clone();
+                spawn_local(async move {
+                    let mut retry_delay = MIN_RETRY_DELAY;
+                    while poller.generation.get() == id {
+                        let Ok(controller) = AbortController::new() else {
+                            error.set(Some("AbortController is not supported".to_string()));
+                            break;
+                        };
+                        *poller.controller.borrow_mut() = Some(controller.clone());
+                        let result = poll::<T>(&url, timeout, &controller).await;
+                        if poller.generation.get() != id {
+                            break;
+                        }
+                        match result {
+                            Poll::Message(value) => {
+                                message.set(Some(Rc::new(value)));
+                                error.set(None);
+                                retry_delay = MIN_RETRY_DELAY;
+                            }
+                            Poll::Empty => {
+                                error.set(None);
+                                retry_delay = MIN_RETRY_DELAY;
+                            }
+                            Poll::Failed(e) => {
+                                error.set(Some(e));
+                                let millis =
+                                    u32::try_from(retry_delay.as_millis()).unwrap_or(u32::MAX);
+                                TimeoutFuture::new(millis).await;
+                                retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                            }
+                        }
+                    }
+                });
+            }
+            move || poller.stop()
+        });
+    }
+
+    UseLongPollHandle {
+        message,
+        error,
+        active,
+    }
+}
@@ -0,0 +1,134 @@
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{HtmlVideoElement, IntersectionObserver, IntersectionObserverEntry};
+use yew::prelude::*;
+
+use crate::{
+    use_battery, use_network_information, use_page_visibility, use_prefers_reduced_motion,
+};
+
+/// Battery level below which [`use_low_power_video`] treats a discharging
+/// device as being in battery-saver mode.
+pub const LOW_BATTERY_LEVEL: f64 = 0.2;
+
+/// Conditions tracked by [`use_low_power_video`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LowPowerVideoState {
+    /// The video intersects the viewport.
+    pub in_viewport: bool,
+    /// The tab is hidden (`document.hidden`).
+    pub page_hidden: bool,
+    /// The user enabled data saving (`navigator.connection.saveData`).
+    pub save_data: bool,
+    /// The device is discharging at or below [`LOW_BATTERY_LEVEL`].
+    pub low_battery: bool,
+    /// The user prefers reduced motion; the poster is shown instead.
+    pub reduced_motion: bool,
+}
+
+impl LowPowerVideoState {
+    /// Whether the video is allowed to play.
+    pub fn should_play(&self) -> bool {
+        self.in_viewport
+            && !self.page_hidden
+            && !self.save_data
+            && !self.low_battery
+            && !self.reduced_motion
+    }
+}
+
+/// A hook that manages a background/hero `<video>`: it plays only while the
+/// video is on-screen and the tab is visible, and pauses when data saving or a
+/// low, discharging battery suggest saving power. Under `prefers-reduced-motion`
+/// the video is paused and reset so its `poster` is shown.
+///
+/// Render the video `muted` (and `playsinline`) so browsers allow it to play
+/// without a user gesture.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Hero)]
+/// fn hero() -> Html {
+///     let video = use_node_ref();
+///     let state = use_low_power_video(video.clone());
+///
+///     html! {
+///         <div>
+///             <video ref={video} src="/hero.mp4" poster="/hero.jpg" muted=true loop=true playsinline=true />
+///             if state.save_data {
+///                 <p>{ "Video paused to save data" }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_low_power_video(video: NodeRef) -> LowPowerVideoState {
+    let in_viewport = use_state_eq(|| false);
+    let visibility = use_page_visibility();
+    let network = use_network_information();
+    let battery = use_battery();
+    let reduced_motion = use_prefers_reduced_motion();
+
+    {
+        let in_viewport = in_viewport.clone();
+        use_effect_with(video.clone(), move |video| {
+            let observer = video.cast::<HtmlVideoElement>().and_then(|element| {
+                let callback =
+                    Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                        let Some(entry) = entries.iter().last() else {
+                            return;
+                        };
+                        let entry: IntersectionObserverEntry = entry.unchecked_into();
+                        in_viewport.set(entry.is_intersecting());
+                    });
+                let observer = IntersectionObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+                Some((observer, callback))
+            });
+            move || {
+                if let Some((observer, callback)) = observer {
+                    observer.disconnect();
+                    drop(callback);
+                }
+            }
+        });
+    }
+
+    let state = LowPowerVideoState {
+        in_viewport: *in_viewport,
+        page_hidden: visibility.is_hidden(),
+        save_data: network.save_data,
+        low_battery: battery.is_some_and(|battery| battery.is_low(LOW_BATTERY_LEVEL)),
+        reduced_motion,
+    };
+
+    // Reset once when reduced motion is turned on: reloading an element that
+    // is not autoplaying shows its poster again, but also rewinds it.
+    use_effect_with(
+        (video.clone(), reduced_motion),
+        |(video, reduced_motion)| {
+            if let (Some(element), true) = (video.cast::<HtmlVideoElement>(), *reduced_motion) {
+                element.set_autoplay(false);
+                element.pause().ok();
+                element.load();
+            }
+        },
+    );
+
+    use_effect_with((video, state), |(video, state)| {
+        if let Some(element) = video.cast::<HtmlVideoElement>() {
+            if !state.should_play() {
+                element.pause().ok();
+            } else if element.paused() {
+                let _ = element.play();
+            }
+        }
+    });
+
+    state
+}
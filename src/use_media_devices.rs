@@ -0,0 +1,207 @@
+use gloo::{events::EventListener, utils::window};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{MediaDeviceInfo, MediaDeviceKind, MediaDevices};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{js, use_permission};
+
+/// A camera, microphone or speaker, as reported by [`use_media_devices`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaDevice {
+    /// Identifies the device to `getUserMedia` or `setSinkId`; stable for the
+    /// origin, except in private browsing.
+    pub device_id: String,
+    /// Shared by the inputs and outputs of one physical device, e.g. a
+    /// headset.
+    pub group_id: String,
+    /// E.g. `"FaceTime HD Camera"`; empty until the user grants camera or
+    /// microphone access.
+    pub label: String,
+    pub kind: MediaDeviceKind,
+}
+
+impl From<MediaDeviceInfo> for MediaDevice {
+    fn from(info: MediaDeviceInfo) -> Self {
+        Self {
+            device_id: info.device_id(),
+            group_id: info.group_id(),
+            label: info.label(),
+            kind: info.kind(),
+        }
+    }
+}
+
+/// `navigator.mediaDevices`, missing outside secure contexts.
+fn media_devices() -> Option<MediaDevices> {
+    let navigator = window().navigator();
+    js::has(&navigator, "mediaDevices")
+        .then(|| navigator.media_devices().ok())
+        .flatten()
+}
+
+/// State handle for the [`use_media_devices`] hook.
+#[derive(Clone)]
+pub struct UseMediaDevicesHandle {
+    devices: UseStateHandle<Vec<MediaDevice>>,
+    error: UseStateHandle<Option<String>>,
+    mounted: Rc<Cell<bool>>,
+}
+
+impl UseMediaDevicesHandle {
+    /// Whether `navigator.mediaDevices` is available; it requires a secure
+    /// context.
+    pub fn is_supported(&self) -> bool {
+        media_devices().is_some()
+    }
+
+    /// All devices, in the browser's order.
+    pub fn devices(&self) -> &[MediaDevice] {
+        &self.devices
+    }
+
+    /// Video inputs.
+    pub fn cameras(&self) -> Vec<&MediaDevice> {
+        self.of_kind(MediaDeviceKind::Videoinput)
+    }
+
+    /// Audio inputs.
+    pub fn microphones(&self) -> Vec<&MediaDevice> {
+        self.of_kind(MediaDeviceKind::Audioinput)
+    }
+
+    /// Audio outputs. Firefox and Safari only list them after
+    /// `selectAudioOutput`, or not at all.
+    pub fn speakers(&self) -> Vec<&MediaDevice> {
+        self.of_kind(MediaDeviceKind::Audiooutput)
+    }
+
+    /// Whether the devices have labels, which browsers withhold until camera
+    /// or microphone access is granted. Until then, show generic names or ask
+    /// for access with [`use_user_media`](crate::use_user_media).
+    pub fn labels_available(&self) -> bool {
+        self.devices.iter().any(|device| !device.label.is_empty())
+    }
+
+    /// The error of the last enumeration, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// List the devices again.
+    pub fn refresh(&self) {
+        let Some(media_devices) = media_devices() else {
+            return;
+        };
+        let Ok(promise) = media_devices.enumerate_devices() else {
+            return;
+        };
+        let devices = self.devices.clone();
+        let error = self.error.clone();
+        let mounted = self.mounted.clone();
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            if !mounted.get() {
+                return;
+            }
+            match result {
+                Ok(list) => {
+                    devices.set(
+                        js_sys::Array::from(&list)
+                            .iter()
+                            .filter_map(|info| info.dyn_into::<MediaDeviceInfo>().ok())
+                            .map(MediaDevice::from)
+                            .collect(),
+                    );
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(
+                    js::get(&e, "message")
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                )),
+            }
+        });
+    }
+
+    fn of_kind(&self, kind: MediaDeviceKind) -> Vec<&MediaDevice> {
+        self.devices.iter().filter(|d| d.kind == kind).collect()
+    }
+}
+
+impl PartialEq for UseMediaDevicesHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.devices == *other.devices
+            && *self.error == *other.error
+            && Rc::ptr_eq(&self.mounted, &other.mounted)
+    }
+}
+
+/// A sensor hook listing the cameras, microphones and speakers from
+/// `navigator.mediaDevices.enumerateDevices()`, e.g. for device pickers in a
+/// call's settings.
+///
+/// The list is refreshed on `devicechange`, when a headset or webcam is
+/// plugged in or removed, and when the camera or microphone permission
+/// changes, as granting access reveals the labels (see
+/// [`labels_available`](UseMediaDevicesHandle::labels_available)).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(CameraPicker)]
+/// fn camera_picker() -> Html {
+///     let media = use_media_devices();
+///
+///     html! {
+///         <select>
+///             { for media.cameras().into_iter().enumerate().map(|(i, camera)| html! {
+///                 <option value={camera.device_id.clone()}>
+///                     { if media.labels_available() { camera.label.clone() } else { format!("Camera {}", i + 1) } }
+///                 </option>
+///             }) }
+///         </select>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_media_devices() -> UseMediaDevicesHandle {
+    let devices = use_state_eq(Vec::new);
+    let error = use_state_eq(|| None);
+    let mounted = use_memo((), |_| Cell::new(true));
+    let camera = use_permission("camera".to_string());
+    let microphone = use_permission("microphone".to_string());
+
+    let handle = UseMediaDevicesHandle {
+        devices,
+        error,
+        mounted,
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with((), move |_| {
+            handle.mounted.set(true);
+            let listener = media_devices().map(|target| {
+                let handle = handle.clone();
+                EventListener::new(&target, "devicechange", move |_| handle.refresh())
+            });
+            move || {
+                handle.mounted.set(false);
+                drop(listener);
+            }
+        });
+    }
+    {
+        // Also lists the devices on mount.
+        let handle = handle.clone();
+        use_effect_with((camera, microphone), move |_| handle.refresh());
+    }
+
+    handle
+}
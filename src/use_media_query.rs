@@ -0,0 +1,57 @@
+use gloo::{events::EventListener, utils::window};
+use yew::prelude::*;
+
+/// Whether `query` currently matches; `false` when it is invalid.
+pub(crate) fn matches(query: &str) -> bool {
+    window()
+        .match_media(query)
+        .ok()
+        .flatten()
+        .is_some_and(|list| list.matches())
+}
+
+/// A sensor hook wrapping `window.matchMedia`: returns whether the media
+/// `query` matches and re-renders when that changes, e.g. on resize, rotation
+/// or when the user switches their system color scheme.
+///
+/// Changing `query` re-evaluates it. Invalid queries never match.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Navigation)]
+/// fn navigation() -> Html {
+///     let wide = use_media_query("(min-width: 768px)");
+///
+///     html! {
+///         if wide {
+///             <nav>{ "Home · Docs · Blog" }</nav>
+///         } else {
+///             <button>{ "Menu" }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_media_query(query: impl Into<String>) -> bool {
+    let query = query.into();
+    let matched = use_state_eq(|| matches(&query));
+
+    {
+        let matched = matched.clone();
+        use_effect_with(query, move |query| {
+            matched.set(matches(query));
+            let listener = window().match_media(query).ok().flatten().map(|list| {
+                let target = list.clone();
+                EventListener::new(&target, "change", move |_| matched.set(list.matches()))
+            });
+            move || drop(listener)
+        });
+    }
+
+    *matched
+}
@@ -0,0 +1,71 @@
+use gloo::utils::{document, head};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Every `<meta name="theme-color">` of the page.
+fn theme_color_metas() -> Vec<Element> {
+    let Ok(list) = document().query_selector_all("meta[name='theme-color']") else {
+        return Vec::new();
+    };
+    (0..list.length())
+        .filter_map(|i| list.item(i)?.dyn_into::<Element>().ok())
+        .collect()
+}
+
+/// A hook that sets the `<meta name="theme-color">` of the page to `color`,
+/// so the browser and installed-PWA chrome follow the app's current theme or
+/// page section, and restores the original on unmount.
+///
+/// Every existing theme color tag is updated, including ones scoped to a
+/// `media` query (e.g. light and dark variants); when the page has none, one
+/// is added to `<head>` and removed again.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Checkout)]
+/// fn checkout() -> Html {
+///     use_meta_theme_color("#0b6e4f".to_string());
+///
+///     html! { <h1>{ "Checkout" }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_meta_theme_color(color: String) {
+    use_effect_with(color, |color| {
+        let mut metas: Vec<(Element, Option<String>)> = theme_color_metas()
+            .into_iter()
+            .map(|meta| {
+                let original = meta.get_attribute("content");
+                (meta, original)
+            })
+            .collect();
+        let added = metas.is_empty();
+        if added
+            && let Ok(meta) = document().create_element("meta")
+            && head().append_child(&meta).is_ok()
+        {
+            let _ = meta.set_attribute("name", "theme-color");
+            metas.push((meta, None));
+        }
+        for (meta, _) in &metas {
+            let _ = meta.set_attribute("content", color);
+        }
+        move || {
+            for (meta, original) in metas {
+                if added {
+                    meta.remove();
+                } else if let Some(content) = original {
+                    let _ = meta.set_attribute("content", &content);
+                } else {
+                    let _ = meta.remove_attribute("content");
+                }
+            }
+        }
+    });
+}
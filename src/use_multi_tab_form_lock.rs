@@ -0,0 +1,268 @@
+#![cfg(feature = "storage")]
+
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+    timers::callback::Interval,
+    utils::window,
+};
+use serde::{Deserialize, Serialize};
+use std::{rc::Rc, time::Duration};
+use wasm_bindgen::JsCast;
+use web_sys::StorageEvent;
+use yew::prelude::*;
+
+/// Options for the [`use_multi_tab_form_lock`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormLockOptions {
+    /// Name shown to other tabs while this tab holds the lock, e.g. the
+    /// signed-in user.
+    pub holder_name: String,
+    /// Report [`is_read_only`](UseFormLockHandle::is_read_only) while another
+    /// tab holds the lock.
+    pub read_only: bool,
+    /// How often the holder refreshes the lock.
+    pub heartbeat: Duration,
+    /// A lock not refreshed for this long is considered abandoned (e.g. the
+    /// tab crashed) and is taken over.
+    pub stale_after: Duration,
+    /// Prefix of the `localStorage` keys holding the locks.
+    pub key_prefix: String,
+}
+
+impl Default for FormLockOptions {
+    fn default() -> Self {
+        Self {
+            holder_name: String::new(),
+            read_only: true,
+            heartbeat: Duration::from_secs(2),
+            stale_after: Duration::from_secs(6),
+            key_prefix: "form-lock:".to_string(),
+        }
+    }
+}
+
+/// The tab holding a [`use_multi_tab_form_lock`] lock.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FormLockHolder {
+    /// Random id of the holding tab.
+    pub tab: String,
+    /// [`FormLockOptions::holder_name`] of the holding tab.
+    pub name: String,
+    /// When the lock was acquired (ms since the epoch).
+    pub since: f64,
+}
+
+/// Lock state of [`use_multi_tab_form_lock`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum FormLockState {
+    /// Not checked yet (before the first effect runs).
+    #[default]
+    Pending,
+    /// This tab holds the lock and may edit the record.
+    Acquired,
+    /// Another tab is editing the record.
+    HeldElsewhere(FormLockHolder),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    holder: FormLockHolder,
+    /// Last refresh by the holder (ms since the epoch).
+    heartbeat: f64,
+}
+
+struct Lock {
+    key: String,
+    tab: Rc<String>,
+    options: FormLockOptions,
+    state: UseStateHandle<FormLockState>,
+}
+
+impl Lock {
+    fn write(&self, since: f64) {
+        let now = js_sys::Date::now();
+        let record = Record {
+            holder: FormLockHolder {
+                tab: (*self.tab).clone(),
+                name: self.options.holder_name.clone(),
+                since,
+            },
+            heartbeat: now,
+        };
+        let _ = LocalStorage::set(&self.key, record);
+        self.state.set(FormLockState::Acquired);
+    }
+
+    /// Refresh the lock if this tab holds it, acquire it if it is free or
+    /// stale, and otherwise report who holds it.
+    fn check(&self) {
+        let now = js_sys::Date::now();
+        match LocalStorage::get::<Record>(&self.key) {
+            Ok(record) if record.holder.tab == *self.tab => self.write(record.holder.since),
+            Ok(record)
+                if now - record.heartbeat < self.options.stale_after.as_secs_f64() * 1000.0 =>
+            {
+                self.state.set(FormLockState::HeldElsewhere(record.holder));
+            }
+            _ => self.write(now),
+        }
+    }
+
+    fn take_over(&self) {
+        self.write(js_sys::Date::now());
+    }
+
+    fn release(&self) {
+        if let Ok(record) = LocalStorage::get::<Record>(&self.key)
+            && record.holder.tab == *self.tab
+        {
+            LocalStorage::delete(&self.key);
+        }
+    }
+}
+
+/// State handle for the [`use_multi_tab_form_lock`] hook.
+#[derive(Clone)]
+pub struct UseFormLockHandle {
+    state: UseStateHandle<FormLockState>,
+    lock: Rc<Lock>,
+}
+
+impl UseFormLockHandle {
+    /// The current lock state.
+    pub fn state(&self) -> &FormLockState {
+        &self.state
+    }
+
+    /// Whether this tab holds the lock.
+    pub fn is_owner(&self) -> bool {
+        *self.state == FormLockState::Acquired
+    }
+
+    /// The other tab holding the lock, if any.
+    pub fn holder(&self) -> Option<&FormLockHolder> {
+        match &*self.state {
+            FormLockState::HeldElsewhere(holder) => Some(holder),
+            _ => None,
+        }
+    }
+
+    /// Whether the form should be read-only because another tab holds the
+    /// lock and [`FormLockOptions::read_only`] is set.
+    pub fn is_read_only(&self) -> bool {
+        self.lock.options.read_only && self.holder().is_some()
+    }
+
+    /// Take the lock from the other tab, which switches to
+    /// [`FormLockState::HeldElsewhere`].
+    pub fn take_over(&self) {
+        self.lock.take_over();
+    }
+}
+
+impl PartialEq for UseFormLockHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.state == *other.state && self.lock.key == other.lock.key
+    }
+}
+
+/// A hook that guards a record's edit form against being edited in two tabs
+/// at once, e.g. to warn "Alice is editing this in another tab" and make the
+/// form read-only until the user takes over.
+///
+/// The lock for `record_key` is kept in `localStorage` by heartbeats, so a
+/// tab that crashes or is closed without cleanup loses it after
+/// [`FormLockOptions::stale_after`]. Other tabs are notified through
+/// `storage` events. The lock is released on unmount and when the page is
+/// hidden for good (`pagehide`). Every hook instance counts as its own tab.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     id: u32,
+/// }
+///
+/// #[function_component(EditInvoice)]
+/// fn edit_invoice(props: &Props) -> Html {
+///     let lock = use_multi_tab_form_lock(
+///         format!("invoice:{}", props.id),
+///         FormLockOptions {
+///             holder_name: "Alice".to_string(),
+///             ..Default::default()
+///         },
+///     );
+///
+///     let ontakeover = {
+///         let lock = lock.clone();
+///         Callback::from(move |_| lock.take_over())
+///     };
+///
+///     html! {
+///         <form>
+///             if let Some(holder) = lock.holder() {
+///                 <p role="alert">{ format!("{} is editing this invoice in another tab.", holder.name) }</p>
+///                 <button type="button" onclick={ontakeover}>{ "Edit here instead" }</button>
+///             }
+///             <fieldset disabled={lock.is_read_only()}>
+///                 <input name="amount" />
+///             </fieldset>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_multi_tab_form_lock(record_key: String, options: FormLockOptions) -> UseFormLockHandle {
+    let state = use_state(FormLockState::default);
+    let tab = use_memo((), |_| {
+        format!("{:x}", (js_sys::Math::random() * u64::MAX as f64) as u64)
+    });
+    let deps = (record_key, options);
+    let lock = use_memo(deps.clone(), |(record_key, options)| Lock {
+        key: format!("{}{record_key}", options.key_prefix),
+        tab: tab.clone(),
+        options: options.clone(),
+        state: state.clone(),
+    });
+
+    {
+        let lock = lock.clone();
+        use_effect_with(deps, move |_| {
+            lock.check();
+            let millis = u32::try_from(lock.options.heartbeat.as_millis()).unwrap_or(u32::MAX);
+            let heartbeat = {
+                let lock = lock.clone();
+                Interval::new(millis, move || lock.check())
+            };
+            let storage = {
+                let lock = lock.clone();
+                EventListener::new(&window(), "storage", move |e| {
+                    let Some(e) = e.dyn_ref::<StorageEvent>() else {
+                        return;
+                    };
+                    if e.key().is_none_or(|key| key == lock.key) {
+                        lock.check();
+                    }
+                })
+            };
+            let pagehide = {
+                let lock = lock.clone();
+                EventListener::new(&window(), "pagehide", move |_| lock.release())
+            };
+            move || {
+                drop(heartbeat);
+                drop(storage);
+                drop(pagehide);
+                lock.release();
+            }
+        });
+    }
+
+    UseFormLockHandle { state, lock }
+}
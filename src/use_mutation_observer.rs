@@ -0,0 +1,132 @@
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use web_sys::{Element, MutationObserver, MutationObserverInit, MutationRecord};
+use yew::prelude::*;
+use yew_hooks::use_latest;
+
+/// What the [`use_mutation_observer`] hook observes; mirrors
+/// `MutationObserverInit`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MutationObserverOptions {
+    /// Children being added or removed.
+    pub child_list: bool,
+    /// Attribute changes.
+    pub attributes: bool,
+    /// Text content changes of text nodes.
+    pub character_data: bool,
+    /// Extend the above to all descendants, not just the node itself.
+    pub subtree: bool,
+    /// Only these attributes; implies `attributes`.
+    pub attribute_filter: Option<Vec<String>>,
+    /// Record previous attribute values in `MutationRecord::old_value`.
+    pub attribute_old_value: bool,
+    /// Record previous text in `MutationRecord::old_value`.
+    pub character_data_old_value: bool,
+}
+
+impl Default for MutationObserverOptions {
+    fn default() -> Self {
+        Self {
+            child_list: true,
+            attributes: false,
+            character_data: false,
+            subtree: true,
+            attribute_filter: None,
+            attribute_old_value: false,
+            character_data_old_value: false,
+        }
+    }
+}
+
+impl MutationObserverOptions {
+    fn init(&self) -> MutationObserverInit {
+        let init = MutationObserverInit::new();
+        init.set_child_list(self.child_list);
+        init.set_subtree(self.subtree);
+        // Only set when requested: the browser infers `attributes` and
+        // `characterData` from the filter and old-value flags.
+        if self.attributes {
+            init.set_attributes(true);
+        }
+        if self.character_data {
+            init.set_character_data(true);
+        }
+        if let Some(filter) = &self.attribute_filter {
+            let filter: js_sys::Array = filter.iter().map(|name| JsValue::from_str(name)).collect();
+            init.set_attribute_filter(&filter);
+        }
+        if self.attribute_old_value {
+            init.set_attribute_old_value(true);
+        }
+        if self.character_data_old_value {
+            init.set_character_data_old_value(true);
+        }
+        init
+    }
+}
+
+/// A hook that observes DOM mutations under the element behind `node` and
+/// passes each batch of `MutationRecord`s to `callback`, so components
+/// embedding third-party widgets can react to DOM changes they don't
+/// control.
+///
+/// The observer is disconnected on unmount and re-created when `node` or the
+/// options change; the latest `callback` is always used without re-observing.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ChatWidget)]
+/// fn chat_widget() -> Html {
+///     let container = use_node_ref();
+///     let unread = use_state(|| 0);
+///
+///     {
+///         let unread = unread.clone();
+///         use_mutation_observer(
+///             container.clone(),
+///             MutationObserverOptions::default(),
+///             Callback::from(move |records: Vec<web_sys::MutationRecord>| {
+///                 let added: u32 = records.iter().map(|r| r.added_nodes().length()).sum();
+///                 unread.set(*unread + added);
+///             }),
+///         );
+///     }
+///
+///     html! {
+///         <>
+///             <span class="badge">{ *unread }</span>
+///             // A third-party script renders messages in here.
+///             <div ref={container} id="chat-widget" />
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_mutation_observer(
+    node: NodeRef,
+    options: MutationObserverOptions,
+    callback: Callback<Vec<MutationRecord>>,
+) {
+    let callback = use_latest(callback);
+
+    use_effect_with((node, options), move |(node, options)| {
+        let closure = Closure::<dyn FnMut(js_sys::Array)>::new(move |records: js_sys::Array| {
+            let records = records.iter().map(JsValue::unchecked_into).collect();
+            callback.current().emit(records);
+        });
+        let observer = MutationObserver::new(closure.as_ref().unchecked_ref()).ok();
+        if let (Some(observer), Some(element)) = (&observer, node.cast::<Element>()) {
+            let _ = observer.observe_with_options(&element, &options.init());
+        }
+        move || {
+            if let Some(observer) = observer {
+                observer.disconnect();
+            }
+            drop(closure);
+        }
+    });
+}
@@ -0,0 +1,118 @@
+use gloo::{events::EventListener, utils::window};
+use wasm_bindgen::{JsCast, JsValue};
+use yew::prelude::*;
+
+use crate::{js, network_mock::use_network_mock};
+
+/// `navigator.connection.effectiveType`: the measured connection quality bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EffectiveConnectionType {
+    Slow2G,
+    TwoG,
+    ThreeG,
+    FourG,
+}
+
+impl EffectiveConnectionType {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "slow-2g" => Some(Self::Slow2G),
+            "2g" => Some(Self::TwoG),
+            "3g" => Some(Self::ThreeG),
+            "4g" => Some(Self::FourG),
+            _ => None,
+        }
+    }
+}
+
+/// Snapshot of the Network Information API.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkInformation {
+    /// Whether `navigator.connection` exists (Chromium-based browsers).
+    pub supported: bool,
+    /// Effective connection type, e.g. [`EffectiveConnectionType::ThreeG`].
+    pub effective_type: Option<EffectiveConnectionType>,
+    /// Estimated downlink bandwidth in megabits per second.
+    pub downlink: Option<f64>,
+    /// Estimated round-trip time in milliseconds.
+    pub rtt: Option<f64>,
+    /// The user asked for reduced data usage.
+    pub save_data: bool,
+}
+
+impl NetworkInformation {
+    /// Read the current values of `navigator.connection`.
+    pub(crate) fn read() -> Self {
+        let Some(connection) = connection() else {
+            return Self::default();
+        };
+        Self {
+            supported: true,
+            effective_type: js::get(&connection, "effectiveType")
+                .and_then(|t| t.as_string())
+                .and_then(|t| EffectiveConnectionType::parse(&t)),
+            downlink: js::get(&connection, "downlink").and_then(|d| d.as_f64()),
+            rtt: js::get(&connection, "rtt").and_then(|r| r.as_f64()),
+            save_data: js::get(&connection, "saveData")
+                .and_then(|s| s.as_bool())
+                .unwrap_or(false),
+        }
+    }
+
+    /// Whether media should be downgraded: data saving is on or the
+    /// connection is 3G or slower.
+    pub fn is_constrained(&self) -> bool {
+        self.save_data
+            || self
+                .effective_type
+                .is_some_and(|t| t < EffectiveConnectionType::FourG)
+    }
+}
+
+fn connection() -> Option<JsValue> {
+    js::get(&window().navigator(), "connection")
+}
+
+/// A sensor hook wrapping the Network Information API (`navigator.connection`),
+/// updating on its `change` event, so media-heavy components can downgrade
+/// quality on slow connections.
+///
+/// Returns [`NetworkInformation::default`] (with `supported: false`) where the
+/// API is unavailable. Inside a [`NetworkMockProvider`](crate::NetworkMockProvider)
+/// with `network` set, the simulated values are returned instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Gallery)]
+/// fn gallery() -> Html {
+///     let network = use_network_information();
+///     let src = if network.is_constrained() { "/photo-small.jpg" } else { "/photo-large.jpg" };
+///
+///     html! { <img {src} alt="Photo" /> }
+/// }
+/// ```
+#[hook]
+pub fn use_network_information() -> NetworkInformation {
+    let state = use_state(NetworkInformation::read);
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            let listener = connection().map(|connection| {
+                EventListener::new(connection.unchecked_ref(), "change", move |_| {
+                    state.set(NetworkInformation::read());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    use_network_mock()
+        .network
+        .unwrap_or_else(|| (*state).clone())
+}
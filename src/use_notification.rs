@@ -0,0 +1,240 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Notification, NotificationPermission};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{js, use_permission};
+
+/// The notification permission, or `None` where notifications are unsupported.
+pub(crate) fn notification_permission() -> Option<NotificationPermission> {
+    js::has(&window(), "Notification").then(Notification::permission)
+}
+
+/// Options for the [`use_notification`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationOptions {
+    /// Close the notifications this component showed when it unmounts, e.g.
+    /// for "call ringing" notifications that only make sense while the view
+    /// is up.
+    pub close_on_unmount: bool,
+}
+
+impl Default for NotificationOptions {
+    fn default() -> Self {
+        Self {
+            close_on_unmount: true,
+        }
+    }
+}
+
+/// A notification to show with [`UseNotificationHandle::notify`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NotifyOptions {
+    pub title: String,
+    pub body: Option<String>,
+    /// URL of an icon.
+    pub icon: Option<String>,
+    /// Replaces an earlier notification with the same tag instead of
+    /// stacking.
+    pub tag: Option<String>,
+    /// Keep the notification up until the user acts on it.
+    pub require_interaction: bool,
+    /// No sound or vibration.
+    pub silent: bool,
+    /// Called when the user clicks the notification. Clicking doesn't focus
+    /// the page by itself; call `window().focus()` here to do so.
+    pub on_click: Option<Callback<()>>,
+    /// Called when the notification is closed, by the user or otherwise.
+    pub on_close: Option<Callback<()>>,
+}
+
+/// A shown notification and its event listeners.
+struct Shown {
+    notification: Notification,
+    _listeners: Vec<EventListener>,
+}
+
+/// State handle for the [`use_notification`] hook.
+#[derive(Clone)]
+pub struct UseNotificationHandle {
+    permission: UseStateHandle<Option<NotificationPermission>>,
+    shown: Rc<RefCell<Vec<Shown>>>,
+}
+
+impl UseNotificationHandle {
+    /// Whether the Notification API is available. On iOS it is only
+    /// available to web apps added to the home screen.
+    pub fn is_supported(&self) -> bool {
+        self.permission.is_some()
+    }
+
+    /// The notification permission, or `None` where unsupported.
+    pub fn permission(&self) -> Option<NotificationPermission> {
+        *self.permission
+    }
+
+    /// Show the browser's permission prompt. Call from a user gesture.
+    pub fn request_permission(&self) {
+        if self.permission.is_none() {
+            return;
+        }
+        let Ok(promise) = Notification::request_permission() else {
+            return;
+        };
+        let permission = self.permission.clone();
+        spawn_local(async move {
+            let _ = JsFuture::from(promise).await;
+            permission.set(notification_permission());
+        });
+    }
+
+    /// Show a notification. Returns `None` unless the permission is granted.
+    pub fn notify(&self, options: NotifyOptions) -> Option<Notification> {
+        if notification_permission() != Some(NotificationPermission::Granted) {
+            return None;
+        }
+        let init = web_sys::NotificationOptions::new();
+        if let Some(body) = &options.body {
+            init.set_body(body);
+        }
+        if let Some(icon) = &options.icon {
+            init.set_icon(icon);
+        }
+        if let Some(tag) = &options.tag {
+            init.set_tag(tag);
+        }
+        init.set_require_interaction(options.require_interaction);
+        init.set_silent(Some(options.silent));
+        // Throws where only service workers may show notifications (Chrome
+        // on Android).
+        let notification = Notification::new_with_options(&options.title, &init).ok()?;
+
+        let mut listeners = Vec::new();
+        if let Some(on_click) = options.on_click {
+            listeners.push(EventListener::new(&notification, "click", move |_| {
+                on_click.emit(())
+            }));
+        }
+        {
+            let shown = Rc::downgrade(&self.shown);
+            let target = notification.clone();
+            let on_close = options.on_close;
+            listeners.push(EventListener::once(&notification, "close", move |_| {
+                if let Some(shown) = Weak::upgrade(&shown) {
+                    shown
+                        .borrow_mut()
+                        .retain(|shown| shown.notification != target);
+                }
+                if let Some(on_close) = on_close {
+                    on_close.emit(());
+                }
+            }));
+        }
+
+        self.shown.borrow_mut().push(Shown {
+            notification: notification.clone(),
+            _listeners: listeners,
+        });
+        Some(notification)
+    }
+
+    /// Close every notification this component showed.
+    pub fn close_all(&self) {
+        let notifications: Vec<_> = self
+            .shown
+            .borrow()
+            .iter()
+            .map(|shown| shown.notification.clone())
+            .collect();
+        for notification in notifications {
+            notification.close();
+        }
+    }
+}
+
+impl PartialEq for UseNotificationHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.permission == *other.permission && Rc::ptr_eq(&self.shown, &other.shown)
+    }
+}
+
+/// A hook for the Notification API: follows the permission, requests it, and
+/// shows notifications with click and close callbacks.
+///
+/// The permission is kept in sync with changes made in the browser's site
+/// settings. With [`NotificationOptions::close_on_unmount`], notifications
+/// the component showed are closed when it unmounts. For an opt-in flow that
+/// doesn't nag, see
+/// [`use_notification_permission_funnel`](crate::use_notification_permission_funnel).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::NotificationPermission;
+///
+/// #[function_component(TimerDone)]
+/// fn timer_done() -> Html {
+///     let notification = use_notification(NotificationOptions::default());
+///
+///     let onclick = {
+///         let notification = notification.clone();
+///         Callback::from(move |_| {
+///             if notification.permission() == Some(NotificationPermission::Granted) {
+///                 notification.notify(NotifyOptions {
+///                     title: "Timer finished".to_string(),
+///                     body: Some("Your tea is ready.".to_string()),
+///                     on_click: Some(Callback::from(|_| {
+///                         let _ = gloo::utils::window().focus();
+///                     })),
+///                     ..Default::default()
+///                 });
+///             } else {
+///                 notification.request_permission();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         if notification.is_supported() {
+///             <button {onclick}>{ "Notify me" }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_notification(options: NotificationOptions) -> UseNotificationHandle {
+    let permission = use_state_eq(notification_permission);
+    let shown = use_memo((), |_| RefCell::new(Vec::<Shown>::new()));
+    let close_on_unmount = use_memo((), |_| Cell::new(false));
+    close_on_unmount.set(options.close_on_unmount);
+
+    {
+        // Follow permission changes made in the site settings.
+        let permission = permission.clone();
+        let status = use_permission("notifications".to_string());
+        use_effect_with(status, move |_| permission.set(notification_permission()));
+    }
+    {
+        let shown = shown.clone();
+        let close_on_unmount = close_on_unmount.clone();
+        use_effect_with((), move |_| {
+            move || {
+                let shown: Vec<_> = shown.borrow_mut().drain(..).collect();
+                for shown in shown {
+                    if close_on_unmount.get() {
+                        shown.notification.close();
+                    }
+                }
+            }
+        });
+    }
+
+    UseNotificationHandle { permission, shown }
+}
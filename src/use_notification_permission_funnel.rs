@@ -0,0 +1,206 @@
+#![cfg(feature = "storage")]
+
+use serde::{Deserialize, Serialize};
+use std::{rc::Rc, time::Duration};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Notification, NotificationPermission};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    STORAGE_NAMESPACE, UseLocalStorageDefaultHandle, use_local_storage_default,
+    use_notification::notification_permission, use_permission,
+};
+
+/// How the user answered the in-app (soft) prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoftPromptResponse {
+    /// Agreed; the browser's permission prompt was shown next.
+    Accepted,
+    /// Closed the prompt ("Not now"); asking again is allowed after the cooldown.
+    Dismissed,
+    /// Explicitly refused ("Don't ask again").
+    Declined,
+}
+
+/// Persisted record of the notification opt-in funnel.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationFunnel {
+    /// How many times the soft prompt was shown.
+    pub prompts_shown: u32,
+    /// When the soft prompt was last shown, in milliseconds since the Unix epoch.
+    pub last_prompt_at: Option<f64>,
+    /// The user's latest answer to the soft prompt.
+    pub last_response: Option<SoftPromptResponse>,
+    /// Number of [`SoftPromptResponse::Accepted`] answers.
+    pub accepted: u32,
+    /// Number of [`SoftPromptResponse::Dismissed`] answers.
+    pub dismissed: u32,
+    /// Number of [`SoftPromptResponse::Declined`] answers.
+    pub declined: u32,
+}
+
+/// Options for the [`use_notification_permission_funnel`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationFunnelOptions {
+    /// `localStorage` key the funnel is persisted under.
+    pub storage_key: String,
+    /// Minimum time between two soft prompts.
+    pub cooldown: Duration,
+    /// Stop prompting after this many soft prompts.
+    pub max_prompts: u32,
+}
+
+impl Default for NotificationFunnelOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: format!("{STORAGE_NAMESPACE}notification-funnel"),
+            cooldown: Duration::from_secs(7 * 24 * 60 * 60),
+            max_prompts: 3,
+        }
+    }
+}
+
+/// State handle for the [`use_notification_permission_funnel`] hook.
+#[derive(Clone)]
+pub struct UseNotificationPermissionFunnelHandle {
+    funnel: UseLocalStorageDefaultHandle<NotificationFunnel>,
+    permission: UseStateHandle<Option<NotificationPermission>>,
+    options: Rc<NotificationFunnelOptions>,
+}
+
+impl UseNotificationPermissionFunnelHandle {
+    /// The persisted funnel, e.g. for measuring opt-in rates.
+    pub fn funnel(&self) -> &NotificationFunnel {
+        &self.funnel
+    }
+
+    /// The browser's actual permission, or `None` where notifications are unsupported.
+    pub fn permission(&self) -> Option<NotificationPermission> {
+        *self.permission
+    }
+
+    /// Whether showing the soft prompt now would not nag: permission is still
+    /// undecided, the user has not declined, and the cooldown and prompt budget allow it.
+    pub fn should_prompt(&self) -> bool {
+        let funnel = &*self.funnel;
+        let cooled_down = funnel
+            .last_prompt_at
+            .is_none_or(|at| js_sys::Date::now() - at >= self.options.cooldown.as_millis() as f64);
+        *self.permission == Some(NotificationPermission::Default)
+            && funnel.last_response != Some(SoftPromptResponse::Declined)
+            && funnel.prompts_shown < self.options.max_prompts
+            && cooled_down
+    }
+
+    /// Record that the soft prompt was shown.
+    pub fn prompt_shown(&self) {
+        self.funnel.set_with(|funnel| {
+            funnel.prompts_shown += 1;
+            funnel.last_prompt_at = Some(js_sys::Date::now());
+        });
+    }
+
+    /// Record the user's answer. [`SoftPromptResponse::Accepted`] also shows the
+    /// browser's permission prompt; this must be called from a user gesture.
+    pub fn respond(&self, response: SoftPromptResponse) {
+        self.funnel.set_with(|funnel| {
+            funnel.last_response = Some(response);
+            match response {
+                SoftPromptResponse::Accepted => funnel.accepted += 1,
+                SoftPromptResponse::Dismissed => funnel.dismissed += 1,
+                SoftPromptResponse::Declined => funnel.declined += 1,
+            }
+        });
+        if response != SoftPromptResponse::Accepted || self.permission.is_none() {
+            return;
+        }
+        let Ok(promise) = Notification::request_permission() else {
+            return;
+        };
+        let state = self.permission.clone();
+        spawn_local(async move {
+            let _ = JsFuture::from(promise).await;
+            state.set(notification_permission());
+        });
+    }
+}
+
+impl PartialEq for UseNotificationPermissionFunnelHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.funnel == other.funnel
+            && *self.permission == *other.permission
+            && self.options == other.options
+    }
+}
+
+/// A hook that tracks the notification permission funnel: how often the
+/// in-app soft prompt was shown, how the user answered, and the browser's
+/// actual permission. The funnel is persisted to `localStorage`, so
+/// [`should_prompt`](UseNotificationPermissionFunnelHandle::should_prompt)
+/// can enforce re-prompt cooldowns across sessions.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(NotificationBanner)]
+/// fn notification_banner() -> Html {
+///     let funnel = use_notification_permission_funnel(NotificationFunnelOptions::default());
+///     let visible = use_state(|| false);
+///
+///     {
+///         let funnel = funnel.clone();
+///         let visible = visible.clone();
+///         use_effect_with((), move |_| {
+///             if funnel.should_prompt() {
+///                 funnel.prompt_shown();
+///                 visible.set(true);
+///             }
+///         });
+///     }
+///
+///     let respond = |response| {
+///         let funnel = funnel.clone();
+///         let visible = visible.clone();
+///         Callback::from(move |_| {
+///             funnel.respond(response);
+///             visible.set(false);
+///         })
+///     };
+///
+///     html! {
+///         if *visible {
+///             <div>
+///                 <p>{ "Get notified about replies?" }</p>
+///                 <button onclick={respond(SoftPromptResponse::Accepted)}>{ "Yes" }</button>
+///                 <button onclick={respond(SoftPromptResponse::Dismissed)}>{ "Not now" }</button>
+///                 <button onclick={respond(SoftPromptResponse::Declined)}>{ "Never" }</button>
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_notification_permission_funnel(
+    options: NotificationFunnelOptions,
+) -> UseNotificationPermissionFunnelHandle {
+    let funnel = use_local_storage_default::<NotificationFunnel>(options.storage_key.clone());
+    let permission = use_state(notification_permission);
+    let options = use_memo(options, |options| options.clone());
+
+    {
+        // Follow permission changes made outside the funnel (e.g. site settings).
+        let permission = permission.clone();
+        let status = use_permission("notifications".to_string());
+        use_effect_with(status, move |_| permission.set(notification_permission()));
+    }
+
+    UseNotificationPermissionFunnelHandle {
+        funnel,
+        permission,
+        options,
+    }
+}
@@ -0,0 +1,354 @@
+#![cfg(feature = "storage")]
+
+use futures::future::LocalBoxFuture;
+use gloo::{
+    storage::{LocalStorage, Storage},
+    timers::callback::Timeout,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    cell::{Cell, Ref, RefCell},
+    fmt::Display,
+    future::Future,
+    rc::Rc,
+    time::Duration,
+};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_update;
+
+use crate::{
+    STORAGE_NAMESPACE,
+    network_mock::{navigator_online, use_network_mock},
+    use_online,
+};
+
+/// Delivery status of a [`QueuedMutation`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MutationStatus {
+    /// Waiting for connectivity, its turn, or a retry.
+    Pending,
+    /// Being sent right now.
+    Sending,
+    /// Gave up after [`OfflineQueueOptions::max_attempts`]; see
+    /// [`UseOfflineQueueHandle::retry`].
+    Failed,
+}
+
+/// A mutation buffered by [`use_offline_queue`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueuedMutation<T> {
+    /// Identifier to [`retry`](UseOfflineQueueHandle::retry) or
+    /// [`remove`](UseOfflineQueueHandle::remove) the mutation with.
+    pub id: u64,
+    pub payload: T,
+    pub status: MutationStatus,
+    /// Failed delivery attempts so far.
+    pub attempts: u32,
+    /// Error of the last failed attempt.
+    pub last_error: Option<String>,
+}
+
+/// Options for the [`use_offline_queue`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfflineQueueOptions {
+    /// `localStorage` key the queue is persisted under.
+    pub storage_key: String,
+    /// Give up on a mutation after this many failed attempts.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled after every further failure.
+    pub retry_delay: Duration,
+}
+
+impl Default for OfflineQueueOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: format!("{STORAGE_NAMESPACE}offline-queue"),
+            max_attempts: 5,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+type Sender<T> = Rc<dyn Fn(T) -> LocalBoxFuture<'static, Result<(), String>>>;
+
+struct Queue<T> {
+    options: RefCell<OfflineQueueOptions>,
+    items: RefCell<Vec<QueuedMutation<T>>>,
+    send: RefCell<Sender<T>>,
+    next_id: Cell<u64>,
+    sending: Cell<bool>,
+    alive: Cell<bool>,
+    /// Online status forced by a [`NetworkMockProvider`](crate::NetworkMockProvider).
+    forced_online: Cell<Option<bool>>,
+    retry: RefCell<Option<Timeout>>,
+    /// Bumped on every change, so handles from different renders compare unequal.
+    version: Cell<u64>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<T> Queue<T>
+where
+    T: Serialize + Clone + 'static,
+{
+    fn changed(&self) {
+        let key = self.options.borrow().storage_key.clone();
+        let _ = LocalStorage::set(key, &*self.items.borrow());
+        self.version.set(self.version.get() + 1);
+        (self.update)();
+    }
+
+    fn push(self: &Rc<Self>, payload: T) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.items.borrow_mut().push(QueuedMutation {
+            id,
+            payload,
+            status: MutationStatus::Pending,
+            attempts: 0,
+            last_error: None,
+        });
+        self.changed();
+        self.pump();
+    }
+
+    /// Send the oldest pending mutation, unless one is in flight, a retry is
+    /// scheduled or the browser is offline.
+    fn pump(self: &Rc<Self>) {
+        if !self.alive.get()
+            || self.sending.get()
+            || self.retry.borrow().is_some()
+            || !navigator_online(self.forced_online.get())
+        {
+            return;
+        }
+        let next = self.items.borrow_mut().iter_mut().find_map(|item| {
+            (item.status == MutationStatus::Pending).then(|| {
+                item.status = MutationStatus::Sending;
+                (item.id, item.payload.clone())
+            })
+        });
+        let Some((id, payload)) = next else {
+            return;
+        };
+        self.sending.set(true);
+        self.changed();
+
+        let future = (self.send.borrow())(payload);
+        let queue = self.clone();
+        spawn_local(async move {
+            let result = future.await;
+            queue.sending.set(false);
+            match result {
+                Ok(()) => queue.items.borrow_mut().retain(|item| item.id != id),
+                Err(error) => queue.failed(id, error),
+            }
+            queue.changed();
+            queue.pump();
+        });
+    }
+
+    fn failed(self: &Rc<Self>, id: u64, error: String) {
+        let options = self.options.borrow().clone();
+        // Failures caused by losing connectivity don't count as attempts.
+        let offline = !navigator_online(self.forced_online.get());
+        let mut items = self.items.borrow_mut();
+        let Some(item) = items.iter_mut().find(|item| item.id == id) else {
+            return;
+        };
+        item.last_error = Some(error);
+        if !offline {
+            item.attempts += 1;
+        }
+        if item.attempts >= options.max_attempts {
+            item.status = MutationStatus::Failed;
+            return;
+        }
+        item.status = MutationStatus::Pending;
+        if offline {
+            return;
+        }
+        let delay = options.retry_delay * 2u32.saturating_pow(item.attempts - 1);
+        let queue = self.clone();
+        *self.retry.borrow_mut() = Some(Timeout::new(
+            u32::try_from(delay.as_millis()).unwrap_or(u32::MAX),
+            move || {
+                queue.retry.borrow_mut().take();
+                queue.pump();
+            },
+        ));
+    }
+}
+
+/// State handle for the [`use_offline_queue`] hook.
+pub struct UseOfflineQueueHandle<T> {
+    queue: Rc<Queue<T>>,
+    version: u64,
+}
+
+impl<T> UseOfflineQueueHandle<T>
+where
+    T: Serialize + Clone + 'static,
+{
+    /// Queue a mutation; it is sent right away when online.
+    pub fn push(&self, payload: T) {
+        self.queue.push(payload);
+    }
+
+    /// Every buffered mutation, oldest first. Delivered mutations are removed.
+    pub fn items(&'_ self) -> Ref<'_, Vec<QueuedMutation<T>>> {
+        self.queue.items.borrow()
+    }
+
+    /// Number of mutations still to be delivered (not counting failed ones).
+    pub fn pending_count(&self) -> usize {
+        self.items()
+            .iter()
+            .filter(|item| item.status != MutationStatus::Failed)
+            .count()
+    }
+
+    /// Queue a failed mutation again, with a fresh attempt budget.
+    pub fn retry(&self, id: u64) {
+        if let Some(item) = self
+            .queue
+            .items
+            .borrow_mut()
+            .iter_mut()
+            .find(|item| item.id == id && item.status == MutationStatus::Failed)
+        {
+            item.status = MutationStatus::Pending;
+            item.attempts = 0;
+        }
+        self.queue.changed();
+        self.queue.pump();
+    }
+
+    /// Drop a mutation that is not currently being sent.
+    pub fn remove(&self, id: u64) {
+        self.queue
+            .items
+            .borrow_mut()
+            .retain(|item| item.id != id || item.status == MutationStatus::Sending);
+        self.queue.changed();
+    }
+}
+
+impl<T> Clone for UseOfflineQueueHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            version: self.version,
+        }
+    }
+}
+
+impl<T> PartialEq for UseOfflineQueueHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.queue, &other.queue) && self.version == other.version
+    }
+}
+
+/// A hook that buffers user mutations while offline and replays them in order
+/// once connectivity returns, retrying failures with exponential backoff.
+///
+/// The queue is persisted to `localStorage`, so mutations survive reloads;
+/// one sent when the page closed is sent again on the next visit, so `send`
+/// should be idempotent. Only one tab should mount a given queue.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// struct Like {
+///     post_id: u64,
+/// }
+///
+/// async fn send_like(like: Like) -> Result<(), String> {
+///     let _ = like.post_id;
+///     Ok(())
+/// }
+///
+/// #[function_component(LikeButton)]
+/// fn like_button() -> Html {
+///     let queue = use_offline_queue(OfflineQueueOptions::default(), send_like);
+///
+///     let onclick = {
+///         let queue = queue.clone();
+///         Callback::from(move |_| queue.push(Like { post_id: 1 }))
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "Like" }</button>
+///             if queue.pending_count() > 0 {
+///                 <p>{ format!("{} changes waiting to sync", queue.pending_count()) }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_offline_queue<T, E, F, Fut>(
+    options: OfflineQueueOptions,
+    send: F,
+) -> UseOfflineQueueHandle<T>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+    E: Display + 'static,
+    F: Fn(T) -> Fut + 'static,
+    Fut: Future<Output = Result<(), E>> + 'static,
+{
+    let update = use_update();
+    let forced_online = use_network_mock().online;
+    let online = use_online();
+    let send: Sender<T> = Rc::new(move |payload| {
+        let future = send(payload);
+        Box::pin(async move { future.await.map_err(|e| e.to_string()) })
+    });
+    let queue = use_memo((), |_| {
+        let mut items: Vec<QueuedMutation<T>> =
+            LocalStorage::get(&options.storage_key).unwrap_or_default();
+        // A mutation still marked as sending was interrupted by a reload.
+        for item in &mut items {
+            if item.status == MutationStatus::Sending {
+                item.status = MutationStatus::Pending;
+            }
+        }
+        let next_id = items.iter().map(|item| item.id + 1).max().unwrap_or(0);
+        Queue {
+            options: RefCell::new(options.clone()),
+            items: RefCell::new(items),
+            send: RefCell::new(send.clone()),
+            next_id: Cell::new(next_id),
+            sending: Cell::new(false),
+            alive: Cell::new(true),
+            forced_online: Cell::new(None),
+            retry: RefCell::new(None),
+            version: Cell::new(0),
+            update,
+        }
+    });
+    *queue.options.borrow_mut() = options;
+    *queue.send.borrow_mut() = send;
+    queue.forced_online.set(forced_online);
+
+    {
+        let queue = queue.clone();
+        use_effect_with(*online, move |_| {
+            queue.alive.set(true);
+            queue.pump();
+            move || {
+                queue.alive.set(false);
+                queue.retry.borrow_mut().take();
+            }
+        });
+    }
+
+    let version = queue.version.get();
+    UseOfflineQueueHandle { queue, version }
+}
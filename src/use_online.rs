@@ -1,24 +1,291 @@
-use gloo::utils::window;
-use yew::prelude::*;
-use yew_hooks::use_event_with_window;
+use futures::future::{Either, select};
+use gloo::{net::http::Request, timers::future::TimeoutFuture};
+use std::time::Duration;
+use web_sys::RequestCache;
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::{use_event_with_window, use_interval, use_latest};
+
+use crate::network_mock::{navigator_online, use_network_mock};
 
 /// Watch the browser navigator's online status
+///
+/// Inside a [`NetworkMockProvider`](crate::NetworkMockProvider) with `online`
+/// set, the simulated status is reported instead.
 #[hook]
 pub fn use_online() -> UseStateHandle<bool> {
-    let online = use_state(|| window().navigator().on_line());
+    let forced = use_network_mock().online;
+    let online = use_state(|| navigator_online(forced));
     {
         let online = online.clone();
         use_event_with_window("online", move |_: Event| {
-            online.set(true);
+            if forced.is_none() {
+                online.set(true);
+            }
         });
     }
 
     {
         let online = online.clone();
         use_event_with_window("offline", move |_: Event| {
-            online.set(false);
+            if forced.is_none() {
+                online.set(false);
+            }
+        });
+    }
+
+    {
+        let online = online.clone();
+        use_effect_with(forced, move |&forced| {
+            let value = navigator_online(forced);
+            if *online != value {
+                online.set(value);
+            }
         });
     }
 
     online
 }
+
+/// Online status with transition timestamps, returned by [`use_online_with_callbacks`].
+///
+/// Timestamps are milliseconds since the Unix epoch; the initial status counts
+/// as a transition at mount time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OnlineState {
+    /// `navigator.onLine`.
+    pub online: bool,
+    /// When the browser last came online; `None` while offline.
+    pub online_since: Option<f64>,
+    /// When the browser last went offline; `None` while online.
+    pub offline_since: Option<f64>,
+    /// How long the most recent offline period lasted, once back online.
+    pub last_offline_duration: Option<Duration>,
+}
+
+impl OnlineState {
+    fn new(online: bool) -> Self {
+        let now = js_sys::Date::now();
+        Self {
+            online,
+            online_since: online.then_some(now),
+            offline_since: (!online).then_some(now),
+            last_offline_duration: None,
+        }
+    }
+}
+
+/// Callbacks for [`use_online_with_callbacks`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OnlineCallbacks {
+    /// Called when the browser comes back online, with how long it was offline.
+    pub on_online: Option<Callback<Duration>>,
+    /// Called when the browser goes offline.
+    pub on_offline: Option<Callback<()>>,
+}
+
+/// Record a change of the online status in `state` and notify `callbacks`.
+fn transition(state: &UseStateHandle<OnlineState>, callbacks: &OnlineCallbacks, online: bool) {
+    if state.online == online {
+        return;
+    }
+    let now = js_sys::Date::now();
+    if online {
+        let offline_for = state
+            .offline_since
+            .map(|since| Duration::from_millis((now - since).max(0.0) as u64))
+            .unwrap_or_default();
+        state.set(OnlineState {
+            online: true,
+            online_since: Some(now),
+            offline_since: None,
+            last_offline_duration: Some(offline_for),
+        });
+        if let Some(on_online) = &callbacks.on_online {
+            on_online.emit(offline_for);
+        }
+    } else {
+        state.set(OnlineState {
+            online: false,
+            online_since: None,
+            offline_since: Some(now),
+            last_offline_duration: state.last_offline_duration,
+        });
+        if let Some(on_offline) = &callbacks.on_offline {
+            on_offline.emit(());
+        }
+    }
+}
+
+/// Like [`use_online`], but also reports when the status last changed and
+/// calls `on_online` / `on_offline` on transitions, e.g. to show a
+/// "reconnected after 2 minutes" banner or refetch data on reconnect.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(ConnectionBanner)]
+/// fn connection_banner() -> Html {
+///     let state = use_online_with_callbacks(OnlineCallbacks {
+///         on_online: Some(Callback::from(|offline_for: std::time::Duration| {
+///             log::info!("Back online after {}s, refetching", offline_for.as_secs());
+///         })),
+///         ..Default::default()
+///     });
+///
+///     html! {
+///         if let Some(duration) = state.last_offline_duration.filter(|_| state.online) {
+///             <p>{ format!("Reconnected after {}s", duration.as_secs()) }</p>
+///         } else if !state.online {
+///             <p>{ "You are offline" }</p>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_online_with_callbacks(callbacks: OnlineCallbacks) -> OnlineState {
+    let forced = use_network_mock().online;
+    let state = use_state(|| OnlineState::new(navigator_online(forced)));
+    let callbacks = use_latest(callbacks);
+
+    {
+        let state = state.clone();
+        let callbacks = callbacks.clone();
+        use_event_with_window("online", move |_: Event| {
+            if forced.is_none() {
+                transition(&state, &callbacks.current(), true);
+            }
+        });
+    }
+
+    {
+        let state = state.clone();
+        let callbacks = callbacks.clone();
+        use_event_with_window("offline", move |_: Event| {
+            if forced.is_none() {
+                transition(&state, &callbacks.current(), false);
+            }
+        });
+    }
+
+    {
+        let state = state.clone();
+        use_effect_with(forced, move |&forced| {
+            transition(&state, &callbacks.current(), navigator_online(forced));
+        });
+    }
+
+    *state
+}
+
+/// Connectivity reported by [`use_online_with_probe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnlineStatus {
+    /// The probe endpoint answered with a 2xx response.
+    Online,
+    /// `navigator.onLine` is `false`.
+    Offline,
+    /// The probe was redirected or got an unexpected status, as happens behind
+    /// captive portals (hotel or airport login pages).
+    CaptivePortal,
+    /// The browser claims to be online, but the probe failed or timed out.
+    Unreachable,
+}
+
+impl OnlineStatus {
+    /// Whether the probe endpoint is actually reachable.
+    pub fn is_online(&self) -> bool {
+        *self == Self::Online
+    }
+}
+
+async fn probe(url: &str, timeout: Duration) -> OnlineStatus {
+    let request = Request::get(url).cache(RequestCache::NoStore).send();
+    let millis = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+    match select(Box::pin(request), TimeoutFuture::new(millis)).await {
+        Either::Left((Ok(response), _)) if response.ok() && !response.redirected() => {
+            OnlineStatus::Online
+        }
+        Either::Left((Ok(_), _)) => OnlineStatus::CaptivePortal,
+        Either::Left((Err(_), _)) | Either::Right(_) => OnlineStatus::Unreachable,
+    }
+}
+
+/// Like [`use_online`], but also pings `url` every `interval` (and whenever the
+/// browser comes back online), because `navigator.onLine` is optimistic and
+/// reports `true` behind captive portals.
+///
+/// `url` should be same-origin (or allow CORS) and answer with a 2xx, ideally
+/// an empty `204`. A probe that takes longer than `interval` counts as
+/// [`OnlineStatus::Unreachable`].
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Connectivity)]
+/// fn connectivity() -> Html {
+///     let status = use_online_with_probe("/healthz".to_string(), Duration::from_secs(30));
+///
+///     html! {
+///         <p>
+///             {
+///                 match status {
+///                     OnlineStatus::Online => "Online",
+///                     OnlineStatus::Offline => "Offline",
+///                     OnlineStatus::CaptivePortal => "Sign in to the network to continue",
+///                     OnlineStatus::Unreachable => "Server unreachable",
+///                 }
+///             }
+///         </p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_online_with_probe(url: String, interval: Duration) -> OnlineStatus {
+    let forced = use_network_mock().online;
+    let online = use_online();
+    let status = use_state(|| {
+        if *online {
+            OnlineStatus::Online
+        } else {
+            OnlineStatus::Offline
+        }
+    });
+
+    let run = {
+        let status = status.clone();
+        move || {
+            if !navigator_online(forced) {
+                status.set(OnlineStatus::Offline);
+                return;
+            }
+            let status = status.clone();
+            let url = url.clone();
+            spawn_local(async move {
+                let result = probe(&url, interval).await;
+                // The browser may have gone offline while the probe was in flight.
+                if navigator_online(forced) {
+                    status.set(result);
+                } else {
+                    status.set(OnlineStatus::Offline);
+                }
+            });
+        }
+    };
+
+    {
+        let run = run.clone();
+        use_effect_with(*online, move |_| run());
+    }
+    use_interval(run, u32::try_from(interval.as_millis()).unwrap_or(u32::MAX));
+
+    *status
+}
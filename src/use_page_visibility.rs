@@ -0,0 +1,75 @@
+use gloo::{events::EventListener, utils::document};
+use web_sys::VisibilityState;
+use yew::prelude::*;
+
+/// Whether the page is visible, as reported by [`use_page_visibility`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageVisibility {
+    /// At least partly visible in a foreground tab of a non-minimized window.
+    Visible,
+    /// In a background tab, a minimized window, or behind a locked screen.
+    Hidden,
+}
+
+impl PageVisibility {
+    fn current() -> Self {
+        match document().visibility_state() {
+            VisibilityState::Hidden => Self::Hidden,
+            _ => Self::Visible,
+        }
+    }
+
+    /// Whether the page is [`Visible`](Self::Visible).
+    pub fn is_visible(self) -> bool {
+        self == Self::Visible
+    }
+
+    /// Whether the page is [`Hidden`](Self::Hidden).
+    pub fn is_hidden(self) -> bool {
+        self == Self::Hidden
+    }
+}
+
+/// A sensor hook returning the page's `document.visibilityState`, updated on
+/// `visibilitychange`.
+///
+/// Use it to pause polling, timers, animations and media while nobody can see
+/// the page; [`use_polling`](crate::use_polling) and
+/// [`use_long_poll`](crate::use_long_poll) already do.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Clock)]
+/// fn clock() -> Html {
+///     let visibility = use_page_visibility();
+///
+///     html! {
+///         <p class={classes!(visibility.is_hidden().then_some("paused"))}>
+///             { format!("{visibility:?}") }
+///         </p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_page_visibility() -> PageVisibility {
+    let visibility = use_state_eq(PageVisibility::current);
+
+    {
+        let visibility = visibility.clone();
+        use_effect_with((), move |_| {
+            // Catch a change between the first render and this effect.
+            visibility.set(PageVisibility::current());
+            let listener = EventListener::new(&document(), "visibilitychange", move |_| {
+                visibility.set(PageVisibility::current());
+            });
+            move || drop(listener)
+        });
+    }
+
+    *visibility
+}
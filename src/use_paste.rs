@@ -0,0 +1,122 @@
+use web_sys::{ClipboardEvent, File};
+use yew::prelude::*;
+use yew_hooks::{use_event, use_event_with_window};
+
+use crate::use_clipboard_files_upload::files;
+
+/// What was pasted, as delivered by [`use_paste`] and [`use_window_paste`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PasteData {
+    /// The `text/plain` representation, if any.
+    pub text: Option<String>,
+    /// The `text/html` representation, if any, e.g. when pasting from a web
+    /// page or word processor.
+    pub html: Option<String>,
+    /// Pasted files, including screenshots pasted as images.
+    pub files: Vec<File>,
+    /// The event, e.g. to `prevent_default()` the browser's own insertion.
+    pub event: ClipboardEvent,
+}
+
+impl PasteData {
+    fn from_event(event: ClipboardEvent) -> Self {
+        let data = event.clipboard_data();
+        let get = |format: &str| {
+            data.as_ref()
+                .and_then(|data| data.get_data(format).ok())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            text: get("text/plain"),
+            html: get("text/html"),
+            files: files(data.clone()),
+            event,
+        }
+    }
+
+    /// The pasted files that are images.
+    pub fn images(&self) -> impl Iterator<Item = &File> {
+        self.files
+            .iter()
+            .filter(|file| file.type_().starts_with("image/"))
+    }
+}
+
+/// A hook that calls `on_paste` with the text, HTML and files of every paste
+/// into the element behind `node`, for chat inputs and editors that accept
+/// pasted screenshots.
+///
+/// The browser still performs its default insertion; call
+/// `data.event.prevent_default()` in `on_paste` to handle the paste
+/// yourself. Use [`use_window_paste`] to capture pastes anywhere on the page.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Composer)]
+/// fn composer() -> Html {
+///     let input = use_node_ref();
+///     let images = use_state(Vec::<String>::new);
+///
+///     {
+///         let images = images.clone();
+///         use_paste(
+///             input.clone(),
+///             Callback::from(move |data: PasteData| {
+///                 let pasted: Vec<String> = data.images().map(|file| file.name()).collect();
+///                 if !pasted.is_empty() {
+///                     data.event.prevent_default();
+///                     let mut next = (*images).clone();
+///                     next.extend(pasted);
+///                     images.set(next);
+///                 }
+///             }),
+///         );
+///     }
+///
+///     html! {
+///         <>
+///             <textarea ref={input} placeholder="Message, or paste a screenshot" />
+///             <ul>{ for images.iter().map(|name| html! { <li>{ name }</li> }) }</ul>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_paste(node: NodeRef, on_paste: Callback<PasteData>) {
+    use_event(node, "paste", move |event: ClipboardEvent| {
+        on_paste.emit(PasteData::from_event(event));
+    });
+}
+
+/// [`use_paste`] for pastes anywhere on the page, wherever focus is.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(PasteAnywhere)]
+/// fn paste_anywhere() -> Html {
+///     let last = use_state(|| None::<String>);
+///
+///     {
+///         let last = last.clone();
+///         use_window_paste(Callback::from(move |data: PasteData| last.set(data.text)));
+///     }
+///
+///     html! { <p>{ last.as_deref().unwrap_or("Paste something") }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_window_paste(on_paste: Callback<PasteData>) {
+    use_event_with_window("paste", move |event: ClipboardEvent| {
+        on_paste.emit(PasteData::from_event(event));
+    });
+}
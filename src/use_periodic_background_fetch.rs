@@ -0,0 +1,271 @@
+#![cfg(feature = "storage")]
+
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+    utils::window,
+};
+use js_sys::Array;
+use log::warn;
+use std::{collections::BTreeMap, rc::Rc, time::Duration};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{MessageEvent, PermissionState, PermissionStatus, ServiceWorkerRegistration};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{UseLocalStorageDefaultHandle, js, use_local_storage_default};
+
+/// The `type` a service worker posts to its clients after handling a `periodicsync` event.
+pub const PERIODIC_SYNC_MESSAGE_TYPE: &str = "periodicsync";
+
+/// Periodic Background Sync registration state, as reported by the browser.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PeriodicBackgroundFetchState {
+    /// Whether the browser implements Periodic Background Sync.
+    pub supported: bool,
+    /// State of the `periodic-background-sync` permission, if it could be queried.
+    pub permission: Option<PermissionState>,
+    /// Tags currently registered with the active service worker.
+    pub tags: Vec<String>,
+}
+
+/// State handle for the [`use_periodic_background_fetch`] hook.
+#[derive(Clone)]
+pub struct UsePeriodicBackgroundFetchHandle {
+    state: UseStateHandle<PeriodicBackgroundFetchState>,
+    last_fired: UseLocalStorageDefaultHandle<BTreeMap<String, f64>>,
+    refresh: Rc<dyn Fn()>,
+}
+
+impl UsePeriodicBackgroundFetchHandle {
+    /// Whether the browser implements Periodic Background Sync.
+    pub fn supported(&self) -> bool {
+        self.state.supported
+    }
+
+    /// State of the `periodic-background-sync` permission.
+    pub fn permission(&self) -> Option<PermissionState> {
+        self.state.permission
+    }
+
+    /// Tags currently registered with the active service worker.
+    pub fn tags(&self) -> &[String] {
+        &self.state.tags
+    }
+
+    /// When `tag` last fired, in milliseconds since the Unix epoch.
+    pub fn last_fired(&self, tag: &str) -> Option<f64> {
+        self.last_fired.get(tag).copied()
+    }
+
+    /// Last-fired timestamps of every tag seen so far.
+    pub fn all_last_fired(&self) -> &BTreeMap<String, f64> {
+        &self.last_fired
+    }
+
+    /// Re-read the permission and registered tags.
+    pub fn refresh(&self) {
+        (self.refresh)();
+    }
+
+    /// Register `tag` to fire at most once every `min_interval`.
+    pub fn register(&self, tag: impl Into<String>, min_interval: Duration) {
+        let tag = tag.into();
+        let refresh = self.refresh.clone();
+        spawn_local(async move {
+            let Some(sync) = periodic_sync().await else {
+                return;
+            };
+            let options = js::object(&[(
+                "minInterval",
+                JsValue::from_f64(min_interval.as_millis() as f64),
+            )]);
+            if let Err(e) = js::call_async(&sync, "register", &[tag.into(), options.into()]).await {
+                warn!("periodicSync.register failed: {e:?}");
+            }
+            refresh();
+        });
+    }
+
+    /// Unregister `tag`.
+    pub fn unregister(&self, tag: impl Into<String>) {
+        let tag = tag.into();
+        let refresh = self.refresh.clone();
+        spawn_local(async move {
+            let Some(sync) = periodic_sync().await else {
+                return;
+            };
+            if let Err(e) = js::call_async(&sync, "unregister", &[tag.into()]).await {
+                warn!("periodicSync.unregister failed: {e:?}");
+            }
+            refresh();
+        });
+    }
+}
+
+impl PartialEq for UsePeriodicBackgroundFetchHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.state == *other.state && self.last_fired == other.last_fired
+    }
+}
+
+fn is_supported() -> bool {
+    js::has(&window(), "PeriodicSyncManager") && js::has(&window().navigator(), "serviceWorker")
+}
+
+async fn periodic_sync() -> Option<JsValue> {
+    if !is_supported() {
+        return None;
+    }
+    let ready = window().navigator().service_worker().ready().ok()?;
+    let registration: ServiceWorkerRegistration =
+        JsFuture::from(ready).await.ok()?.dyn_into().ok()?;
+    js::get(&registration, "periodicSync")
+}
+
+async fn query_permission() -> Option<PermissionState> {
+    let permissions = window().navigator().permissions().ok()?;
+    let descriptor = js::object(&[("name", "periodic-background-sync".into())]);
+    let status: PermissionStatus = JsFuture::from(permissions.query(&descriptor).ok()?)
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    Some(status.state())
+}
+
+async fn load() -> PeriodicBackgroundFetchState {
+    if !is_supported() {
+        return PeriodicBackgroundFetchState::default();
+    }
+    let permission = query_permission().await;
+    let mut tags = Vec::new();
+    if let Some(sync) = periodic_sync().await {
+        match js::call_async(&sync, "getTags", &[]).await {
+            Ok(list) => {
+                tags = Array::from(&list)
+                    .iter()
+                    .filter_map(|t| t.as_string())
+                    .collect()
+            }
+            Err(e) => warn!("periodicSync.getTags failed: {e:?}"),
+        }
+    }
+    PeriodicBackgroundFetchState {
+        supported: true,
+        permission,
+        tags,
+    }
+}
+
+/// A side-effect hook exposing the Periodic Background Sync registration state,
+/// so the UI can show when content was last refreshed in the background.
+///
+/// Service workers cannot write to `localStorage`, so the worker is expected to
+/// notify its clients after handling a `periodicsync` event:
+///
+/// ```js
+/// self.addEventListener("periodicsync", (event) => {
+///   event.waitUntil(refresh(event.tag).then(async () => {
+///     for (const client of await self.clients.matchAll()) {
+///       client.postMessage({ type: "periodicsync", tag: event.tag, timestamp: Date.now() });
+///     }
+///   }));
+/// });
+/// ```
+///
+/// The hook records those timestamps under `storage_key` in `localStorage`, so
+/// they survive reloads and are shared with other tabs via `storage` events.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(BackgroundRefresh)]
+/// fn background_refresh() -> Html {
+///     let sync = use_periodic_background_fetch("periodic-sync".to_string());
+///
+///     let onregister = {
+///         let sync = sync.clone();
+///         Callback::from(move |_| sync.register("news", Duration::from_secs(60 * 60 * 12)))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onregister} disabled={!sync.supported()}>{ "Refresh news in background" }</button>
+///             <p>
+///                 <b>{ "Last refreshed: " }</b>
+///                 {
+///                     match sync.last_fired("news") {
+///                         Some(ms) => html! { String::from(js_sys::Date::new(&ms.into()).to_iso_string()) },
+///                         None => html! { "never" },
+///                     }
+///                 }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_periodic_background_fetch(storage_key: String) -> UsePeriodicBackgroundFetchHandle {
+    let state = use_state(PeriodicBackgroundFetchState::default);
+    let last_fired = use_local_storage_default::<BTreeMap<String, f64>>(storage_key.clone());
+    let storage_key = use_memo(storage_key, |key| key.clone());
+
+    let refresh: Rc<dyn Fn()> = {
+        let state = state.clone();
+        Rc::new(move || {
+            let state = state.clone();
+            spawn_local(async move { state.set(load().await) });
+        })
+    };
+
+    {
+        let refresh = refresh.clone();
+        let last_fired = last_fired.clone();
+        use_effect_with(storage_key, move |key| {
+            refresh();
+            let key = key.clone();
+            let listener = is_supported().then(|| {
+                EventListener::new(
+                    &window().navigator().service_worker(),
+                    "message",
+                    move |e| {
+                        let Some(data) = e.dyn_ref::<MessageEvent>().map(MessageEvent::data) else {
+                            return;
+                        };
+                        if js::get(&data, "type")
+                            .and_then(|t| t.as_string())
+                            .as_deref()
+                            != Some(PERIODIC_SYNC_MESSAGE_TYPE)
+                        {
+                            return;
+                        }
+                        let Some(tag) = js::get(&data, "tag").and_then(|t| t.as_string()) else {
+                            return;
+                        };
+                        let timestamp = js::get(&data, "timestamp")
+                            .and_then(|t| t.as_f64())
+                            .unwrap_or_else(js_sys::Date::now);
+                        // Read from storage rather than the captured handle, which may be stale.
+                        let mut map: BTreeMap<String, f64> =
+                            LocalStorage::get(&*key).unwrap_or_default();
+                        map.insert(tag, timestamp);
+                        last_fired.set(map);
+                    },
+                )
+            });
+            move || drop(listener)
+        });
+    }
+
+    UsePeriodicBackgroundFetchHandle {
+        state,
+        last_fired,
+        refresh,
+    }
+}
@@ -0,0 +1,91 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{PermissionState, PermissionStatus};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// A sensor hook returning the state of the permission `name` (e.g.
+/// `"geolocation"`, `"notifications"`, `"camera"`, `"clipboard-read"`) from
+/// the Permissions API, updated when it changes, including from the
+/// browser's site settings.
+///
+/// Returns `None` until the query resolves, and where the browser doesn't
+/// know the permission or lacks the API. Querying never prompts the user, so
+/// the UI can explain a feature before the underlying API asks, or offer
+/// instructions once it is denied.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::PermissionState;
+///
+/// #[function_component(NearbyStores)]
+/// fn nearby_stores() -> Html {
+///     let permission = use_permission("geolocation".to_string());
+///
+///     html! {
+///         match permission {
+///             Some(PermissionState::Denied) => html! {
+///                 <p>{ "Location is blocked. Enable it in your browser's site settings." }</p>
+///             },
+///             Some(PermissionState::Granted) => html! { <p>{ "Finding stores near you..." }</p> },
+///             _ => html! { <button>{ "Use my location" }</button> },
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_permission(name: String) -> Option<PermissionState> {
+    let state = use_state_eq(|| None);
+
+    {
+        let state = state.clone();
+        use_effect_with(name, move |name| {
+            state.set(None);
+            let alive = Rc::new(Cell::new(true));
+            let listener = Rc::new(RefCell::new(None));
+            {
+                let alive = alive.clone();
+                let listener = listener.clone();
+                let descriptor = js::object(&[("name", name.into())]);
+                spawn_local(async move {
+                    let Ok(permissions) = window().navigator().permissions() else {
+                        return;
+                    };
+                    // Throws for names the browser doesn't know.
+                    let Ok(query) = permissions.query(&descriptor) else {
+                        return;
+                    };
+                    let Ok(status) = JsFuture::from(query).await else {
+                        return;
+                    };
+                    if !alive.get() {
+                        return;
+                    }
+                    let status: PermissionStatus = status.unchecked_into();
+                    state.set(Some(status.state()));
+                    let target = status.clone();
+                    *listener.borrow_mut() =
+                        Some(EventListener::new(&target, "change", move |_| {
+                            state.set(Some(status.state()));
+                        }));
+                });
+            }
+            move || {
+                alive.set(false);
+                listener.borrow_mut().take();
+            }
+        });
+    }
+
+    *state
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "storage")]
+
+use gloo::utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::{STORAGE_NAMESPACE, UseLocalStorageDefaultHandle, use_local_storage_default};
+
+/// Options for the [`use_persisted_zoom_and_font_scale`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontScaleOptions {
+    /// `localStorage` key holding the preference.
+    pub storage_key: String,
+    /// Scale without a stored preference.
+    pub default: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Change applied by `increase` and `decrease`.
+    pub step: f64,
+    /// CSS custom property set on `<html>` to the scale, e.g. for
+    /// `html { font-size: calc(100% * var(--font-scale)) }`.
+    pub css_property: Option<String>,
+    /// Class added to `<html>` as this prefix followed by the scale in
+    /// percent, e.g. `font-scale-120` for 1.2.
+    pub class_prefix: Option<String>,
+}
+
+impl Default for FontScaleOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: format!("{STORAGE_NAMESPACE}font-scale"),
+            default: 1.0,
+            min: 0.8,
+            max: 2.0,
+            step: 0.1,
+            css_property: Some("--font-scale".to_string()),
+            class_prefix: None,
+        }
+    }
+}
+
+impl FontScaleOptions {
+    fn clamp(&self, scale: f64) -> f64 {
+        // Rounded so repeated steps don't accumulate float error.
+        ((scale * 100.0).round() / 100.0).clamp(self.min, self.max)
+    }
+}
+
+fn percent(scale: f64) -> u32 {
+    (scale * 100.0).round() as u32
+}
+
+/// State handle for the [`use_persisted_zoom_and_font_scale`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFontScaleHandle {
+    stored: UseLocalStorageDefaultHandle<Option<f64>>,
+    options: FontScaleOptions,
+}
+
+impl UseFontScaleHandle {
+    /// The current scale, 1.0 being the page's own size.
+    pub fn scale(&self) -> f64 {
+        self.options
+            .clamp(self.stored.unwrap_or(self.options.default))
+    }
+
+    /// The current scale in percent, for display.
+    pub fn percent(&self) -> u32 {
+        percent(self.scale())
+    }
+
+    /// Set the scale, clamped to the allowed range.
+    pub fn set(&self, scale: f64) {
+        self.stored.set(Some(self.options.clamp(scale)));
+    }
+
+    /// Grow by one step.
+    pub fn increase(&self) {
+        self.set(self.scale() + self.options.step);
+    }
+
+    /// Shrink by one step.
+    pub fn decrease(&self) {
+        self.set(self.scale() - self.options.step);
+    }
+
+    /// Forget the preference and return to the default scale.
+    pub fn reset(&self) {
+        self.stored.delete();
+    }
+
+    /// Whether `increase` would change anything.
+    pub fn can_increase(&self) -> bool {
+        self.scale() < self.options.max
+    }
+
+    /// Whether `decrease` would change anything.
+    pub fn can_decrease(&self) -> bool {
+        self.scale() > self.options.min
+    }
+}
+
+/// A hook for built-in text-size controls: keeps a font scale preference in
+/// `localStorage` (synced across tabs) and applies it to `<html>` as a CSS
+/// custom property and/or class, which your stylesheet turns into a size.
+///
+/// The scale is clamped to [`FontScaleOptions::min`] and
+/// [`FontScaleOptions::max`]. The property and class are removed on unmount,
+/// so mount the hook once near the root of the app.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// // In your CSS: html { font-size: calc(100% * var(--font-scale, 1)); }
+/// #[function_component(TextSizeControls)]
+/// fn text_size_controls() -> Html {
+///     let font = use_persisted_zoom_and_font_scale(FontScaleOptions::default());
+///
+///     let decrease = {
+///         let font = font.clone();
+///         Callback::from(move |_| font.decrease())
+///     };
+///     let increase = {
+///         let font = font.clone();
+///         Callback::from(move |_| font.increase())
+///     };
+///     let reset = {
+///         let font = font.clone();
+///         Callback::from(move |_| font.reset())
+///     };
+///
+///     html! {
+///         <div role="group" aria-label="Text size">
+///             <button onclick={decrease} disabled={!font.can_decrease()}>{ "A-" }</button>
+///             <span>{ format!("{}%", font.percent()) }</span>
+///             <button onclick={increase} disabled={!font.can_increase()}>{ "A+" }</button>
+///             <button onclick={reset}>{ "Reset" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_zoom_and_font_scale(options: FontScaleOptions) -> UseFontScaleHandle {
+    let stored = use_local_storage_default::<Option<f64>>(options.storage_key.clone());
+    let handle = UseFontScaleHandle { stored, options };
+    let scale = handle.scale();
+
+    use_effect_with(
+        (
+            scale,
+            handle.options.css_property.clone(),
+            handle.options.class_prefix.clone(),
+        ),
+        |(scale, property, class_prefix)| {
+            let root = document()
+                .document_element()
+                .and_then(|root| root.dyn_into::<HtmlElement>().ok());
+            let class = class_prefix
+                .as_ref()
+                .map(|prefix| format!("{prefix}{}", percent(*scale)));
+            if let Some(root) = &root {
+                if let Some(property) = property {
+                    let _ = root.style().set_property(property, &scale.to_string());
+                }
+                if let Some(class) = &class {
+                    let _ = root.class_list().add_1(class);
+                }
+            }
+            let property = property.clone();
+            move || {
+                if let Some(root) = root {
+                    if let Some(property) = property {
+                        let _ = root.style().remove_property(&property);
+                    }
+                    if let Some(class) = class {
+                        let _ = root.class_list().remove_1(&class);
+                    }
+                }
+            }
+        },
+    );
+
+    handle
+}
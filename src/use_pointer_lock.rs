@@ -0,0 +1,155 @@
+use gloo::{events::EventListener, utils::document};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, MouseEvent};
+use yew::prelude::*;
+use yew_hooks::use_latest;
+
+use crate::js;
+
+/// Relative mouse movement reported by [`use_pointer_lock`], in CSS pixels
+/// since the previous `mousemove`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointerDelta {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// State handle for the [`use_pointer_lock`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UsePointerLockHandle {
+    node: NodeRef,
+    locked: UseStateHandle<bool>,
+    error: UseStateHandle<bool>,
+}
+
+impl UsePointerLockHandle {
+    /// Whether the Pointer Lock API is available (not on touch devices).
+    pub fn is_supported(&self) -> bool {
+        js::has(&document(), "exitPointerLock")
+    }
+
+    /// Whether the pointer is locked to the element.
+    pub fn is_locked(&self) -> bool {
+        *self.locked
+    }
+
+    /// Whether the last [`request`](Self::request) was refused, e.g. without
+    /// a user gesture or right after the user pressed Escape.
+    pub fn error(&self) -> bool {
+        *self.error
+    }
+
+    /// Hide the cursor and lock it to the element. Call from a user gesture,
+    /// such as a click on the element.
+    pub fn request(&self) {
+        if let Some(element) = self.node.cast::<Element>() {
+            self.error.set(false);
+            element.request_pointer_lock();
+        }
+    }
+
+    /// Release the pointer.
+    pub fn exit(&self) {
+        if self.is_locked() {
+            document().exit_pointer_lock();
+        }
+    }
+}
+
+/// A hook for the Pointer Lock API on the element behind `node`: hides the
+/// cursor and delivers relative mouse movement to `on_move` while locked,
+/// without the pointer hitting the screen edge — for canvas games and 3D
+/// viewers.
+///
+/// The user can always leave with Escape;
+/// [`is_locked`](UsePointerLockHandle::is_locked) follows
+/// `pointerlockchange`. The lock is released on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Viewer)]
+/// fn viewer() -> Html {
+///     let canvas = use_node_ref();
+///     let angle = use_state(|| (0.0, 0.0));
+///
+///     let lock = {
+///         let angle = angle.clone();
+///         use_pointer_lock(
+///             canvas.clone(),
+///             Callback::from(move |delta: PointerDelta| {
+///                 let (yaw, pitch) = *angle;
+///                 angle.set((yaw + delta.x * 0.2, (pitch + delta.y * 0.2_f64).clamp(-90.0, 90.0)));
+///             }),
+///         )
+///     };
+///
+///     let onclick = {
+///         let lock = lock.clone();
+///         Callback::from(move |_| lock.request())
+///     };
+///
+///     html! {
+///         <canvas ref={canvas} {onclick} width="800" height="600" />
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_pointer_lock(node: NodeRef, on_move: Callback<PointerDelta>) -> UsePointerLockHandle {
+    let locked = use_state_eq(|| false);
+    let error = use_state_eq(|| false);
+    let on_move = use_latest(on_move);
+
+    {
+        let locked = locked.clone();
+        let error = error.clone();
+        use_effect_with(node.clone(), move |node| {
+            let is_locked = {
+                let node = node.clone();
+                move || {
+                    let element = node.get().map(JsValue::from);
+                    element.is_some()
+                        && document().pointer_lock_element().map(JsValue::from) == element
+                }
+            };
+            let listeners = [
+                {
+                    let is_locked = is_locked.clone();
+                    EventListener::new(&document(), "pointerlockchange", move |_| {
+                        locked.set(is_locked())
+                    })
+                },
+                EventListener::new(&document(), "pointerlockerror", move |_| error.set(true)),
+                {
+                    let is_locked = is_locked.clone();
+                    EventListener::new(&document(), "mousemove", move |event| {
+                        if !is_locked() {
+                            return;
+                        }
+                        let event: &MouseEvent = event.unchecked_ref();
+                        on_move.current().emit(PointerDelta {
+                            x: event.movement_x() as f64,
+                            y: event.movement_y() as f64,
+                        });
+                    })
+                },
+            ];
+            move || {
+                drop(listeners);
+                if is_locked() {
+                    document().exit_pointer_lock();
+                }
+            }
+        });
+    }
+
+    UsePointerLockHandle {
+        node,
+        locked,
+        error,
+    }
+}
@@ -0,0 +1,205 @@
+use std::{cell::Cell, future::Future, rc::Rc, time::Duration};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::{use_interval, use_latest};
+
+use crate::{use_online, use_page_visibility};
+
+/// State handle for the [`use_polling`] hook.
+pub struct UsePollingHandle<T, E> {
+    data: UseStateHandle<Option<T>>,
+    error: UseStateHandle<Option<E>>,
+    loading: UseStateHandle<bool>,
+    paused: UseStateHandle<bool>,
+    active: bool,
+    poll: Rc<dyn Fn()>,
+}
+
+impl<T, E> UsePollingHandle<T, E> {
+    /// The result of the last successful poll. Kept when a poll fails.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// The error of the last poll, if it failed.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Whether a poll is in flight.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// Whether polling was paused with [`pause`](Self::pause).
+    pub fn is_paused(&self) -> bool {
+        *self.paused
+    }
+
+    /// Whether polling is running: not paused, the page is visible and the
+    /// browser is online.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Stop polling until [`resume`](Self::resume) is called.
+    pub fn pause(&self) {
+        self.paused.set(true);
+    }
+
+    /// Resume polling, fetching immediately if the page is visible and online.
+    pub fn resume(&self) {
+        self.paused.set(false);
+    }
+
+    /// Fetch now, without waiting for the next tick.
+    pub fn refresh(&self) {
+        (self.poll)();
+    }
+}
+
+impl<T, E> Clone for UsePollingHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            error: self.error.clone(),
+            loading: self.loading.clone(),
+            paused: self.paused.clone(),
+            active: self.active,
+            poll: self.poll.clone(),
+        }
+    }
+}
+
+impl<T, E> PartialEq for UsePollingHandle<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.error == other.error
+            && self.loading == other.loading
+            && self.paused == other.paused
+            && self.active == other.active
+    }
+}
+
+/// A hook that calls `fetcher` every `interval` while the page is visible and
+/// the browser is online.
+///
+/// Polling stops while the tab is hidden or offline, and resumes with an
+/// immediate fetch when it becomes visible and online again, so a background
+/// tab does not keep hitting the server. A tick is skipped while the previous
+/// fetch is still in flight.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// async fn fetch_build_status() -> Result<String, String> {
+///     Ok("passing".to_string())
+/// }
+///
+/// #[function_component(BuildStatus)]
+/// fn build_status() -> Html {
+///     let status = use_polling(Duration::from_secs(15), fetch_build_status);
+///
+///     let ontoggle = {
+///         let status = status.clone();
+///         Callback::from(move |_| {
+///             if status.is_paused() {
+///                 status.resume();
+///             } else {
+///                 status.pause();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <p>{ status.data().cloned().unwrap_or_default() }</p>
+///             <button onclick={ontoggle}>
+///                 { if status.is_paused() { "Resume" } else { "Pause" } }
+///             </button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_polling<T, E, F, Fut>(interval: Duration, fetcher: F) -> UsePollingHandle<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let data = use_state(|| None);
+    let error = use_state(|| None);
+    let loading = use_state(|| false);
+    let paused = use_state(|| false);
+    let visibility = use_page_visibility();
+    let online = use_online();
+    let in_flight = use_memo((), |_| Cell::new(false));
+    let fetcher = use_latest(fetcher);
+
+    let poll: Rc<dyn Fn()> = {
+        let data = data.clone();
+        let error = error.clone();
+        let loading = loading.clone();
+        Rc::new(move || {
+            if in_flight.replace(true) {
+                return;
+            }
+            let future = (*fetcher.current())();
+            let data = data.clone();
+            let error = error.clone();
+            let loading = loading.clone();
+            let in_flight = in_flight.clone();
+            loading.set(true);
+            spawn_local(async move {
+                match future.await {
+                    Ok(value) => {
+                        data.set(Some(value));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                in_flight.set(false);
+                loading.set(false);
+            });
+        })
+    };
+
+    let active = !*paused && visibility.is_visible() && *online;
+
+    {
+        let poll = poll.clone();
+        use_effect_with(active, move |&active| {
+            if active {
+                poll();
+            }
+        });
+    }
+    {
+        let poll = poll.clone();
+        let millis = if active {
+            u32::try_from(interval.as_millis()).unwrap_or(u32::MAX)
+        } else {
+            0
+        };
+        use_interval(move || poll(), millis);
+    }
+
+    UsePollingHandle {
+        data,
+        error,
+        loading,
+        paused,
+        active,
+        poll,
+    }
+}
@@ -0,0 +1,62 @@
+use gloo::{events::EventListener, utils::window};
+use yew::prelude::*;
+
+/// `navigator.languages`, falling back to `navigator.language`.
+fn navigator_languages() -> Vec<String> {
+    let navigator = window().navigator();
+    let languages: Vec<String> = navigator
+        .languages()
+        .iter()
+        .filter_map(|language| language.as_string())
+        .collect();
+    if languages.is_empty() {
+        navigator.language().into_iter().collect()
+    } else {
+        languages
+    }
+}
+
+/// A sensor hook returning the user's preferred languages from
+/// `navigator.languages`, most preferred first (e.g. `["de-CH", "de", "en"]`),
+/// updated on `languagechange` so i18n-aware components can switch locale
+/// without a reload.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// const SUPPORTED: [&str; 3] = ["en", "fr", "de"];
+///
+/// #[function_component(Greeting)]
+/// fn greeting() -> Html {
+///     let languages = use_preferred_languages();
+///     let locale = languages
+///         .iter()
+///         .filter_map(|language| language.split('-').next())
+///         .find(|language| SUPPORTED.contains(language))
+///         .unwrap_or("en");
+///
+///     html! {
+///         <p>{ match locale { "fr" => "Bonjour", "de" => "Hallo", _ => "Hello" } }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_preferred_languages() -> Vec<String> {
+    let languages = use_state_eq(navigator_languages);
+
+    {
+        let languages = languages.clone();
+        use_effect_with((), move |_| {
+            let listener = EventListener::new(&window(), "languagechange", move |_| {
+                languages.set(navigator_languages());
+            });
+            move || drop(listener)
+        });
+    }
+
+    (*languages).clone()
+}
@@ -0,0 +1,58 @@
+use yew::prelude::*;
+
+use crate::use_media_query;
+
+/// The user's contrast preference, from the `prefers-contrast` media feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContrastPreference {
+    #[default]
+    NoPreference,
+    /// Higher contrast was requested, e.g. macOS "Increase contrast".
+    More,
+    /// Lower contrast was requested.
+    Less,
+    /// A specific palette is forced that is neither more nor less contrasty,
+    /// typically by forced colors mode.
+    Custom,
+}
+
+/// A sensor hook for the `prefers-contrast` media feature: whether the user
+/// asked for more or less contrast than the page's default, updated live
+/// when they change the setting.
+///
+/// Browsers without `prefers-contrast` report
+/// [`NoPreference`](ContrastPreference::NoPreference). Windows High Contrast
+/// themes are better detected with [`use_forced_colors`](crate::use_forced_colors).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Hint)]
+/// fn hint() -> Html {
+///     let contrast = use_prefers_contrast();
+///     let class = if contrast == ContrastPreference::More {
+///         "hint hint--high-contrast"
+///     } else {
+///         "hint"
+///     };
+///
+///     html! { <p {class}>{ "Press / to search" }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_prefers_contrast() -> ContrastPreference {
+    let more = use_media_query("(prefers-contrast: more)");
+    let less = use_media_query("(prefers-contrast: less)");
+    let custom = use_media_query("(prefers-contrast: custom)");
+
+    match (more, less, custom) {
+        (true, _, _) => ContrastPreference::More,
+        (_, true, _) => ContrastPreference::Less,
+        (_, _, true) => ContrastPreference::Custom,
+        _ => ContrastPreference::NoPreference,
+    }
+}
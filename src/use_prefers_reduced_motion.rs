@@ -0,0 +1,42 @@
+use yew::prelude::*;
+
+use crate::use_media_query::{matches, use_media_query};
+
+const REDUCED_MOTION: &str = "(prefers-reduced-motion: reduce)";
+
+/// Whether the user asked for reduced motion.
+pub(crate) fn prefers_reduced_motion() -> bool {
+    matches(REDUCED_MOTION)
+}
+
+/// A sensor hook for the `prefers-reduced-motion` media feature: whether the
+/// user asked the system to minimize non-essential motion, updated live when
+/// they change the setting.
+///
+/// Use it to skip transitions, autoplay and parallax, or to swap animations
+/// for fades. For scrolling, [`use_reduced_motion_scroll`](crate::use_reduced_motion_scroll)
+/// picks the scroll behavior for you.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Drawer)]
+/// fn drawer() -> Html {
+///     let reduced_motion = use_prefers_reduced_motion();
+///     let style = if reduced_motion {
+///         "transition: none"
+///     } else {
+///         "transition: transform 300ms ease-out"
+///     };
+///
+///     html! { <aside {style}>{ "Menu" }</aside> }
+/// }
+/// ```
+#[hook]
+pub fn use_prefers_reduced_motion() -> bool {
+    use_media_query(REDUCED_MOTION)
+}
@@ -0,0 +1,193 @@
+use gloo::{timers::callback::Timeout, utils::window};
+use std::{future::Future, time::Duration};
+use wasm_bindgen::closure::Closure;
+use yew::prelude::*;
+use yew_hooks::use_latest;
+
+use crate::{js, use_query::entry};
+
+/// Options for the [`use_prefetch`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrefetchOptions {
+    /// Prefetch when the pointer rests on the element for this long; `None`
+    /// disables hover prefetching. The delay skips pointers just passing by.
+    pub hover_delay: Option<Duration>,
+    /// Prefetch when the element receives keyboard focus.
+    pub on_focus: bool,
+    /// Prefetch once the browser is idle after mount (`requestIdleCallback`).
+    pub on_idle: bool,
+    /// Don't prefetch when the cached data is younger than this.
+    pub stale_time: Duration,
+}
+
+impl Default for PrefetchOptions {
+    fn default() -> Self {
+        Self {
+            hover_delay: Some(Duration::from_millis(100)),
+            on_focus: true,
+            on_idle: false,
+            stale_time: Duration::from_secs(30),
+        }
+    }
+}
+
+/// State handle for the [`use_prefetch`] hook: event handlers to attach to
+/// the link or button whose target should be prefetched.
+#[derive(Clone, PartialEq)]
+pub struct UsePrefetchHandle {
+    /// Attach as `onmouseenter`.
+    pub onmouseenter: Callback<MouseEvent>,
+    /// Attach as `onmouseleave`.
+    pub onmouseleave: Callback<MouseEvent>,
+    /// Attach as `onfocus`.
+    pub onfocus: Callback<FocusEvent>,
+    /// Attach as `ontouchstart`; touch devices have no hover, so this
+    /// prefetches right away.
+    pub ontouchstart: Callback<TouchEvent>,
+    /// Prefetch now, e.g. from a custom event.
+    pub prefetch: Callback<()>,
+}
+
+/// A hook that prefetches the query `key` on hover, focus or when the browser
+/// is idle, so a later [`use_query`](crate::use_query) or
+/// [`use_suspense_resource`](crate::use_suspense_resource) with the same key
+/// renders the data instantly instead of waiting for the network.
+///
+/// `fetcher` is stored in the shared query cache exactly as those hooks would
+/// fetch it, so it must return the same `Result<T, E>` type. To warm up a
+/// lazily loaded route chunk instead, return `Ok(())` once it is loaded.
+/// Nothing is fetched while the key is loading or was fetched within
+/// [`PrefetchOptions::stale_time`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// async fn fetch_article(id: u32) -> Result<String, String> {
+///     Ok(format!("Article {id}"))
+/// }
+///
+/// #[function_component(ArticleLink)]
+/// fn article_link() -> Html {
+///     let prefetch = use_prefetch(
+///         "article:42".to_string(),
+///         || fetch_article(42),
+///         PrefetchOptions::default(),
+///     );
+///
+///     html! {
+///         <a
+///             href="/articles/42"
+///             onmouseenter={prefetch.onmouseenter.clone()}
+///             onmouseleave={prefetch.onmouseleave.clone()}
+///             onfocus={prefetch.onfocus.clone()}
+///             ontouchstart={prefetch.ontouchstart.clone()}
+///         >
+///             { "Read article" }
+///         </a>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_prefetch<T, E, F, Fut>(
+    key: String,
+    fetcher: F,
+    options: PrefetchOptions,
+) -> UsePrefetchHandle
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let fetcher = use_latest(fetcher);
+    let hover = use_mut_ref(|| None::<Timeout>);
+
+    let prefetch = use_callback(
+        (key.clone(), options.stale_time),
+        move |_: (), (key, stale_time)| {
+            let entry = entry::<T, E>(key);
+            if !entry.loading.get() && !entry.is_fresh(*stale_time) {
+                entry.fetch((*fetcher.current())());
+            }
+        },
+    );
+
+    let onmouseenter = {
+        let hover = hover.clone();
+        use_callback(
+            (prefetch.clone(), options.hover_delay),
+            move |_: MouseEvent, (prefetch, delay)| {
+                if let Some(delay) = delay {
+                    let prefetch = prefetch.clone();
+                    let millis = u32::try_from(delay.as_millis()).unwrap_or(u32::MAX);
+                    *hover.borrow_mut() = Some(Timeout::new(millis, move || prefetch.emit(())));
+                }
+            },
+        )
+    };
+    let onmouseleave = {
+        let hover = hover.clone();
+        use_callback((), move |_: MouseEvent, _| {
+            hover.borrow_mut().take();
+        })
+    };
+    let onfocus = use_callback(
+        (prefetch.clone(), options.on_focus),
+        |_: FocusEvent, (prefetch, on_focus)| {
+            if *on_focus {
+                prefetch.emit(());
+            }
+        },
+    );
+    let ontouchstart = use_callback(prefetch.clone(), |_: TouchEvent, prefetch| {
+        prefetch.emit(())
+    });
+
+    {
+        let prefetch = prefetch.clone();
+        use_effect_with((key, options.on_idle), move |(_, on_idle)| {
+            let mut idle = None;
+            let mut fallback = None;
+            if *on_idle {
+                let callback = {
+                    let prefetch = prefetch.clone();
+                    Closure::<dyn FnMut()>::new(move || prefetch.emit(()))
+                };
+                match js::call(
+                    &window(),
+                    "requestIdleCallback",
+                    &[callback.as_ref().clone()],
+                ) {
+                    Ok(handle) => idle = Some((callback, handle)),
+                    // Without `requestIdleCallback` (Safari), wait for the page to settle.
+                    Err(_) => fallback = Some(Timeout::new(1_000, move || prefetch.emit(()))),
+                }
+            }
+            move || {
+                drop(fallback);
+                if let Some((callback, handle)) = idle {
+                    let _ = js::call(&window(), "cancelIdleCallback", &[handle]);
+                    drop(callback);
+                }
+            }
+        });
+    }
+
+    use_effect_with((), move |_| {
+        move || {
+            hover.borrow_mut().take();
+        }
+    });
+
+    UsePrefetchHandle {
+        onmouseenter,
+        onmouseleave,
+        onfocus,
+        ontouchstart,
+        prefetch,
+    }
+}
@@ -0,0 +1,240 @@
+use gloo::utils::window;
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// A push subscription, as reported by [`use_push_subscription`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PushSubscriptionInfo {
+    /// The push service URL the backend sends messages to.
+    pub endpoint: String,
+    /// The client's public key (`p256dh`), base64url-encoded.
+    pub p256dh: String,
+    /// The authentication secret (`auth`), base64url-encoded.
+    pub auth: String,
+    /// When the subscription expires, in milliseconds since the epoch.
+    pub expiration_time: Option<f64>,
+    /// `PushSubscription.toJSON()` serialized, the format web push libraries
+    /// expect from the client.
+    pub json: String,
+}
+
+impl PushSubscriptionInfo {
+    fn read(subscription: &JsValue) -> Option<Self> {
+        let json = js::call(subscription, "toJSON", &[]).ok()?;
+        let keys = js::get(&json, "keys")?;
+        let string = |target: &JsValue, key: &str| js::get(target, key)?.as_string();
+        Some(Self {
+            endpoint: string(&json, "endpoint")?,
+            p256dh: string(&keys, "p256dh")?,
+            auth: string(&keys, "auth")?,
+            expiration_time: js::get(&json, "expirationTime").and_then(|t| t.as_f64()),
+            json: js_sys::JSON::stringify(&json).ok()?.into(),
+        })
+    }
+}
+
+fn is_supported() -> bool {
+    js::has(&window(), "PushManager") && js::has(&window().navigator(), "serviceWorker")
+}
+
+/// The `PushManager` of the active service worker registration. Waits for a
+/// service worker to be registered.
+async fn push_manager() -> Result<JsValue, JsValue> {
+    if !is_supported() {
+        return Err(JsValue::from_str("Push API is not supported"));
+    }
+    let registration = JsFuture::from(window().navigator().service_worker().ready()?).await?;
+    js::get(&registration, "pushManager").ok_or_else(|| JsValue::from_str("no PushManager"))
+}
+
+fn error_message(e: &JsValue) -> String {
+    e.as_string()
+        .or_else(|| js::get(e, "message").and_then(|m| m.as_string()))
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+/// State handle for the [`use_push_subscription`] hook.
+#[derive(Clone)]
+pub struct UsePushSubscriptionHandle {
+    subscription: UseStateHandle<Option<PushSubscriptionInfo>>,
+    loading: UseStateHandle<bool>,
+    error: UseStateHandle<Option<String>>,
+    vapid_key: Rc<str>,
+    mounted: Rc<Cell<bool>>,
+}
+
+impl UsePushSubscriptionHandle {
+    /// Whether the Push API is available. On iOS it is only available to web
+    /// apps added to the home screen.
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// The current subscription, to send to the backend.
+    pub fn subscription(&self) -> Option<&PushSubscriptionInfo> {
+        self.subscription.as_ref()
+    }
+
+    /// Whether the subscription is being read or changed, which waits for a
+    /// service worker to be registered.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// The error of the last operation, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Subscribe to push messages. Asks for the notification permission if
+    /// needed; call from a user gesture.
+    pub fn subscribe(&self) {
+        let options = js::object(&[
+            ("userVisibleOnly", true.into()),
+            ("applicationServerKey", JsValue::from_str(&self.vapid_key)),
+        ]);
+        self.run(move |push_manager| async move {
+            js::call_async(&push_manager, "subscribe", &[options.into()]).await
+        });
+    }
+
+    /// Unsubscribe from push messages. Tell the backend too, so it stops
+    /// sending to the old endpoint.
+    pub fn unsubscribe(&self) {
+        self.run(|push_manager| async move {
+            let subscription = js::call_async(&push_manager, "getSubscription", &[]).await?;
+            if !subscription.is_null() {
+                js::call_async(&subscription, "unsubscribe", &[]).await?;
+            }
+            Ok(JsValue::NULL)
+        });
+    }
+
+    /// Run `operation` against the `PushManager` and store the subscription
+    /// it resolves to (`null` for none).
+    fn run<F, Fut>(&self, operation: F)
+    where
+        F: FnOnce(JsValue) -> Fut + 'static,
+        Fut: Future<Output = Result<JsValue, JsValue>>,
+    {
+        let subscription = self.subscription.clone();
+        let loading = self.loading.clone();
+        let error = self.error.clone();
+        let mounted = self.mounted.clone();
+        loading.set(true);
+        spawn_local(async move {
+            let result = match push_manager().await {
+                Ok(push_manager) => operation(push_manager).await,
+                Err(e) => Err(e),
+            };
+            if !mounted.get() {
+                return;
+            }
+            match result {
+                Ok(value) => {
+                    subscription.set(PushSubscriptionInfo::read(&value));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(error_message(&e))),
+            }
+            loading.set(false);
+        });
+    }
+}
+
+impl PartialEq for UsePushSubscriptionHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.subscription == *other.subscription
+            && *self.loading == *other.loading
+            && *self.error == *other.error
+            && self.vapid_key == other.vapid_key
+            && Rc::ptr_eq(&self.mounted, &other.mounted)
+    }
+}
+
+/// A hook managing a Web Push subscription with the `PushManager` of the
+/// registered service worker, using the server's base64url-encoded VAPID
+/// public key.
+///
+/// The existing subscription is read on mount, once a service worker is
+/// registered; send [`subscription`](UsePushSubscriptionHandle::subscription)
+/// to the backend whenever it changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// const VAPID_KEY: &str = "BEl62iUYgUivxIkv69yViEuiBIa-Ib9-SkvMeAtA3LFgDzkrxZJjSgSnfckjBJuBkr3qBUYIHBQFLXYp5Nksh8U";
+///
+/// #[function_component(PushToggle)]
+/// fn push_toggle() -> Html {
+///     let push = use_push_subscription(VAPID_KEY.to_string());
+///
+///     {
+///         let subscription = push.subscription().cloned();
+///         use_effect_with(subscription, |subscription| {
+///             if let Some(subscription) = subscription {
+///                 // POST subscription.json to the backend.
+///                 log::info!("subscribed: {}", subscription.endpoint);
+///             }
+///         });
+///     }
+///
+///     let onclick = {
+///         let push = push.clone();
+///         Callback::from(move |_| {
+///             if push.subscription().is_some() {
+///                 push.unsubscribe();
+///             } else {
+///                 push.subscribe();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         if push.is_supported() {
+///             <button {onclick} disabled={push.loading()}>
+///                 { if push.subscription().is_some() { "Turn off alerts" } else { "Turn on alerts" } }
+///             </button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_push_subscription(vapid_key: String) -> UsePushSubscriptionHandle {
+    let subscription = use_state_eq(|| None);
+    let loading = use_state_eq(|| false);
+    let error = use_state_eq(|| None);
+    let vapid_key = use_memo(vapid_key, |key| Rc::<str>::from(key.as_str()));
+    let mounted = use_memo((), |_| Cell::new(true));
+
+    let handle = UsePushSubscriptionHandle {
+        subscription,
+        loading,
+        error,
+        vapid_key: (*vapid_key).clone(),
+        mounted: mounted.clone(),
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with((), move |_| {
+            handle.mounted.set(true);
+            if is_supported() {
+                handle.run(|push_manager| async move {
+                    js::call_async(&push_manager, "getSubscription", &[]).await
+                });
+            }
+            move || handle.mounted.set(false)
+        });
+    }
+
+    handle
+}
@@ -0,0 +1,390 @@
+use log::warn;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    rc::Rc,
+    time::Duration,
+};
+use yew::{platform::spawn_local, prelude::*, suspense::SuspensionHandle};
+use yew_hooks::{use_event_with_window, use_latest, use_update};
+
+use crate::use_online;
+
+/// Options for the [`use_query_with_options`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueryOptions {
+    /// Revalidate when the window regains focus.
+    pub revalidate_on_focus: bool,
+    /// Revalidate when the browser comes back online.
+    pub revalidate_on_reconnect: bool,
+    /// Skip automatic revalidation (on mount and focus) when the query was
+    /// fetched this recently, e.g. by another component using the same key.
+    pub dedupe_interval: Duration,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            revalidate_on_focus: true,
+            revalidate_on_reconnect: true,
+            dedupe_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// A mounted component's re-render callback, with an id to unsubscribe it by.
+type Subscriber = (u64, Rc<dyn Fn()>);
+
+pub(crate) struct QueryEntry<T, E> {
+    pub(crate) data: RefCell<Option<Rc<T>>>,
+    pub(crate) error: RefCell<Option<Rc<E>>>,
+    pub(crate) loading: Cell<bool>,
+    fetched_at: Cell<Option<f64>>,
+    /// Set by [`invalidate_query`]; mounted components refetch on their next render.
+    pub(crate) invalidated: Cell<bool>,
+    /// Suspended components waiting for the fetch in flight; dropping the
+    /// handles resumes them.
+    pub(crate) suspensions: RefCell<Vec<SuspensionHandle>>,
+    subscribers: RefCell<Vec<Subscriber>>,
+    next_id: Cell<u64>,
+}
+
+impl<T, E> QueryEntry<T, E> {
+    fn new() -> Self {
+        Self {
+            data: RefCell::new(None),
+            error: RefCell::new(None),
+            loading: Cell::new(false),
+            fetched_at: Cell::new(None),
+            invalidated: Cell::new(false),
+            suspensions: RefCell::new(Vec::new()),
+            subscribers: RefCell::new(Vec::new()),
+            next_id: Cell::new(0),
+        }
+    }
+
+    pub(crate) fn subscribe(&self, update: Rc<dyn Fn()>) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.subscribers.borrow_mut().push((id, update));
+        id
+    }
+
+    pub(crate) fn unsubscribe(&self, id: u64) {
+        self.subscribers.borrow_mut().retain(|(i, _)| *i != id);
+    }
+
+    /// Re-render every component using this query.
+    fn notify(&self) {
+        let subscribers: Vec<_> = self
+            .subscribers
+            .borrow()
+            .iter()
+            .map(|(_, update)| update.clone())
+            .collect();
+        for update in subscribers {
+            update();
+        }
+    }
+
+    /// Whether the data was fetched within `interval`.
+    pub(crate) fn is_fresh(&self, interval: Duration) -> bool {
+        let interval = interval.as_millis() as f64;
+        self.fetched_at
+            .get()
+            .is_some_and(|at| js_sys::Date::now() - at < interval)
+    }
+}
+
+impl<T: 'static, E: 'static> QueryEntry<T, E> {
+    /// Store the outcome of `future` and notify subscribers and suspended
+    /// components.
+    pub(crate) fn fetch(self: &Rc<Self>, future: impl Future<Output = Result<T, E>> + 'static) {
+        self.loading.set(true);
+        self.invalidated.set(false);
+        self.notify();
+        let entry = self.clone();
+        spawn_local(async move {
+            match future.await {
+                Ok(data) => {
+                    *entry.data.borrow_mut() = Some(Rc::new(data));
+                    *entry.error.borrow_mut() = None;
+                }
+                Err(error) => *entry.error.borrow_mut() = Some(Rc::new(error)),
+            }
+            entry.loading.set(false);
+            entry.fetched_at.set(Some(js_sys::Date::now()));
+            entry.notify();
+            entry.suspensions.borrow_mut().clear();
+        });
+    }
+}
+
+/// Type-erased access to cache entries of any data and error type.
+trait CacheEntry {
+    fn invalidate(&self);
+    fn into_any(self: Rc<Self>) -> Rc<dyn Any>;
+}
+
+impl<T: 'static, E: 'static> CacheEntry for QueryEntry<T, E> {
+    fn invalidate(&self) {
+        self.invalidated.set(true);
+        self.fetched_at.set(None);
+        self.notify();
+    }
+
+    fn into_any(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+}
+
+thread_local! {
+    static CACHE: RefCell<HashMap<String, Rc<dyn CacheEntry>>> = RefCell::new(HashMap::new());
+}
+
+/// The shared cache entry for `key`. Reusing a key with different types
+/// replaces the entry, with a warning: components using both types would keep
+/// evicting each other's data.
+pub(crate) fn entry<T: 'static, E: 'static>(key: &str) -> Rc<QueryEntry<T, E>> {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(existing) = cache.get(key) {
+            match existing.clone().into_any().downcast::<QueryEntry<T, E>>() {
+                Ok(entry) => return entry,
+                Err(_) => warn!(
+                    "Query key {key} is used with different data or error types; replacing its cache entry"
+                ),
+            }
+        }
+        let entry = Rc::new(QueryEntry::new());
+        cache.insert(key.to_string(), entry.clone());
+        entry
+    })
+}
+
+/// Mark the cached query `key` as stale: components using it refetch right
+/// away, and the next component to mount it fetches instead of deduplicating.
+/// Works for [`use_query`] and [`use_suspense_resource`](crate::use_suspense_resource).
+pub fn invalidate_query(key: &str) {
+    let entry = CACHE.with(|cache| cache.borrow().get(key).cloned());
+    if let Some(entry) = entry {
+        entry.invalidate();
+    }
+}
+
+/// State handle for the [`use_query`] hook.
+pub struct UseQueryHandle<T, E> {
+    entry: Rc<QueryEntry<T, E>>,
+    data: Option<Rc<T>>,
+    error: Option<Rc<E>>,
+    loading: bool,
+    revalidate: Rc<dyn Fn(bool)>,
+}
+
+impl<T, E> UseQueryHandle<T, E> {
+    /// The cached data, possibly stale while revalidating.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_deref()
+    }
+
+    /// The error of the last fetch, if it failed. Cached data is kept.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_deref()
+    }
+
+    /// Whether a fetch for this key is in flight.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Fetch again now, unless a fetch for this key is already in flight.
+    pub fn revalidate(&self) {
+        (self.revalidate)(true);
+    }
+
+    /// Replace the cached data for every component using this key, e.g. for
+    /// an optimistic update. Call [`revalidate`](Self::revalidate) afterwards
+    /// to confirm it with the server.
+    pub fn mutate(&self, data: T) {
+        *self.entry.data.borrow_mut() = Some(Rc::new(data));
+        self.entry.notify();
+    }
+}
+
+impl<T, E> Clone for UseQueryHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            entry: self.entry.clone(),
+            data: self.data.clone(),
+            error: self.error.clone(),
+            loading: self.loading,
+            revalidate: self.revalidate.clone(),
+        }
+    }
+}
+
+impl<T, E> PartialEq for UseQueryHandle<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<V>(a: &Option<Rc<V>>, b: &Option<Rc<V>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+        Rc::ptr_eq(&self.entry, &other.entry)
+            && same(&self.data, &other.data)
+            && same(&self.error, &other.error)
+            && self.loading == other.loading
+    }
+}
+
+/// A stale-while-revalidate query hook with an in-memory cache shared by every
+/// component using the same `key`.
+///
+/// Cached data renders immediately and is revalidated on mount, when the
+/// window regains focus and when the browser reconnects. Concurrent requests
+/// for the same key are deduplicated.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// async fn fetch_unread() -> Result<u32, String> {
+///     Ok(3)
+/// }
+///
+/// #[function_component(UnreadBadge)]
+/// fn unread_badge() -> Html {
+///     let unread = use_query("unread".to_string(), fetch_unread);
+///
+///     let onread = {
+///         let unread = unread.clone();
+///         Callback::from(move |_| unread.mutate(0))
+///     };
+///
+///     html! {
+///         <button onclick={onread}>
+///             { format!("Inbox ({})", unread.data().copied().unwrap_or_default()) }
+///         </button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_query<T, E, F, Fut>(key: String, fetcher: F) -> UseQueryHandle<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    use_query_with_options(key, fetcher, QueryOptions::default())
+}
+
+/// [`use_query`] with [`QueryOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// async fn fetch_report() -> Result<String, String> {
+///     Ok("All good".to_string())
+/// }
+///
+/// #[function_component(Report)]
+/// fn report() -> Html {
+///     let report = use_query_with_options(
+///         "report".to_string(),
+///         fetch_report,
+///         QueryOptions {
+///             revalidate_on_focus: false,
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! { <p>{ report.data().cloned().unwrap_or_default() }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_query_with_options<T, E, F, Fut>(
+    key: String,
+    fetcher: F,
+    options: QueryOptions,
+) -> UseQueryHandle<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let update = use_update();
+    let entry = use_memo(key.clone(), |key| entry::<T, E>(key));
+    let entry: Rc<QueryEntry<T, E>> = (*entry).clone();
+    let fetcher = use_latest(fetcher);
+    let online = use_online();
+    let was_online = use_mut_ref(|| *online);
+
+    let revalidate: Rc<dyn Fn(bool)> = {
+        let entry = entry.clone();
+        Rc::new(move |force| {
+            if entry.loading.get() || (!force && entry.is_fresh(options.dedupe_interval)) {
+                return;
+            }
+            entry.fetch((*fetcher.current())());
+        })
+    };
+
+    {
+        let entry = entry.clone();
+        let revalidate = revalidate.clone();
+        use_effect_with(key, move |_| {
+            let id = entry.subscribe(update);
+            revalidate(false);
+            move || entry.unsubscribe(id)
+        });
+    }
+    {
+        let entry = entry.clone();
+        let revalidate = revalidate.clone();
+        use_effect(move || {
+            if entry.invalidated.get() {
+                revalidate(true);
+            }
+        });
+    }
+    {
+        let revalidate = revalidate.clone();
+        use_event_with_window("focus", move |_: FocusEvent| {
+            if options.revalidate_on_focus {
+                revalidate(false);
+            }
+        });
+    }
+    {
+        let revalidate = revalidate.clone();
+        use_effect_with(*online, move |online| {
+            let reconnected = *online && !was_online.replace(*online);
+            if reconnected && options.revalidate_on_reconnect {
+                revalidate(true);
+            }
+        });
+    }
+
+    let data = entry.data.borrow().clone();
+    let error = entry.error.borrow().clone();
+    UseQueryHandle {
+        data,
+        error,
+        loading: entry.loading.get(),
+        entry,
+        revalidate,
+    }
+}
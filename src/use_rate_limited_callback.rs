@@ -0,0 +1,195 @@
+use gloo::timers::callback::Timeout;
+use std::{cell::RefCell, collections::VecDeque, rc::Rc, time::Duration};
+use yew::prelude::*;
+
+/// What [`use_rate_limited_callback_with_options`] does with calls over budget.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RateLimitOverflow {
+    /// Discard them.
+    #[default]
+    Drop,
+    /// Run them in order as the budget frees up.
+    Queue,
+    /// Run only the most recent one as the budget frees up. Suits
+    /// search-as-you-type, where only the latest query matters.
+    Latest,
+}
+
+struct Limiter<IN> {
+    callback: RefCell<Callback<IN>>,
+    max_calls: usize,
+    window: Duration,
+    overflow: RateLimitOverflow,
+    /// Start times of the calls within the window, oldest first.
+    calls: RefCell<VecDeque<f64>>,
+    queued: RefCell<VecDeque<IN>>,
+    drain: RefCell<Option<Timeout>>,
+}
+
+impl<IN: 'static> Limiter<IN> {
+    /// Forget calls older than the window and report whether another fits.
+    fn has_budget(&self, now: f64) -> bool {
+        let window = self.window.as_secs_f64() * 1000.0;
+        let mut calls = self.calls.borrow_mut();
+        while calls.front().is_some_and(|&start| now - start >= window) {
+            calls.pop_front();
+        }
+        calls.len() < self.max_calls
+    }
+
+    fn call(self: &Rc<Self>, input: IN) {
+        let now = js_sys::Date::now();
+        if self.queued.borrow().is_empty() && self.has_budget(now) {
+            self.run(now, input);
+            return;
+        }
+        match self.overflow {
+            RateLimitOverflow::Drop => return,
+            RateLimitOverflow::Queue => self.queued.borrow_mut().push_back(input),
+            RateLimitOverflow::Latest => {
+                let mut queued = self.queued.borrow_mut();
+                queued.clear();
+                queued.push_back(input);
+            }
+        }
+        self.schedule(now);
+    }
+
+    fn run(&self, now: f64, input: IN) {
+        self.calls.borrow_mut().push_back(now);
+        let callback = self.callback.borrow().clone();
+        callback.emit(input);
+    }
+
+    /// Run queued calls that fit the budget, then wait for the oldest call to
+    /// leave the window if any remain.
+    fn flush(self: &Rc<Self>) {
+        self.drain.borrow_mut().take();
+        let now = js_sys::Date::now();
+        while self.has_budget(now) {
+            let Some(input) = self.queued.borrow_mut().pop_front() else {
+                break;
+            };
+            self.run(now, input);
+        }
+        if !self.queued.borrow().is_empty() {
+            self.schedule(now);
+        }
+    }
+
+    fn schedule(self: &Rc<Self>, now: f64) {
+        if self.drain.borrow().is_some() {
+            return;
+        }
+        let window = self.window.as_secs_f64() * 1000.0;
+        let oldest = self.calls.borrow().front().copied().unwrap_or(now);
+        let millis = (oldest + window - now)
+            .ceil()
+            .clamp(0.0, f64::from(u32::MAX)) as u32;
+        let limiter = self.clone();
+        *self.drain.borrow_mut() = Some(Timeout::new(millis, move || limiter.flush()));
+    }
+}
+
+/// A hook that wraps `callback` so it runs at most `max_calls` times per
+/// `window`, dropping calls over budget. For search-as-you-type against a
+/// rate-limited API, see [`use_rate_limited_callback_with_options`] to run
+/// the latest call once the budget frees up instead.
+///
+/// Unlike debouncing, calls within budget run immediately. The returned
+/// callback keeps its identity across renders, while the wrapped `callback`
+/// is always the latest one passed.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// # use std::time::Duration;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///     let results = use_state(Vec::<String>::new);
+///     let search = {
+///         let results = results.clone();
+///         use_rate_limited_callback_with_options(
+///             Callback::from(move |query: String| {
+///                 results.set(vec![format!("Results for {query}")]);
+///             }),
+///             5,
+///             Duration::from_secs(1),
+///             RateLimitOverflow::Latest,
+///         )
+///     };
+///     let oninput = Callback::from(move |e: InputEvent| {
+///         let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+///         search.emit(input.value());
+///     });
+///
+///     html! {
+///         <div>
+///             <input {oninput} />
+///             <ul>{ for results.iter().map(|r| html! { <li>{ r }</li> }) }</ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_rate_limited_callback<IN>(
+    callback: Callback<IN>,
+    max_calls: usize,
+    window: Duration,
+) -> Callback<IN>
+where
+    IN: 'static,
+{
+    use_rate_limited_callback_with_options(callback, max_calls, window, RateLimitOverflow::Drop)
+}
+
+/// Like [`use_rate_limited_callback`], choosing what happens to calls over
+/// budget with `overflow`.
+///
+/// Queued calls are discarded on unmount. Changing `max_calls`, `window` or
+/// `overflow` resets the budget.
+#[hook]
+pub fn use_rate_limited_callback_with_options<IN>(
+    callback: Callback<IN>,
+    max_calls: usize,
+    window: Duration,
+    overflow: RateLimitOverflow,
+) -> Callback<IN>
+where
+    IN: 'static,
+{
+    let deps = (max_calls, window, overflow);
+    let limiter = {
+        let callback = callback.clone();
+        use_memo(deps, move |(max_calls, window, overflow)| Limiter {
+            callback: RefCell::new(callback),
+            // A zero budget would never drain the queue.
+            max_calls: (*max_calls).max(1),
+            window: *window,
+            overflow: *overflow,
+            calls: RefCell::new(VecDeque::new()),
+            queued: RefCell::new(VecDeque::new()),
+            drain: RefCell::new(None),
+        })
+    };
+    *limiter.callback.borrow_mut() = callback;
+
+    {
+        let limiter = limiter.clone();
+        use_effect_with(deps, move |_| {
+            move || {
+                limiter.drain.borrow_mut().take();
+                limiter.queued.borrow_mut().clear();
+            }
+        });
+    }
+
+    (*use_memo(deps, move |_| {
+        Callback::from(move |input: IN| limiter.call(input))
+    }))
+    .clone()
+}
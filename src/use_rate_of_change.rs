@@ -0,0 +1,141 @@
+use std::{collections::VecDeque, time::Duration};
+use yew::prelude::*;
+use yew_hooks::{use_interval, use_update};
+
+/// Options for the [`use_rate_of_change`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateOfChangeOptions {
+    /// Only samples this recent contribute to the rate.
+    pub window: Duration,
+    /// Weight of the newest rate in the exponential moving average
+    /// ([`RateOfChange::smoothed`]), between 0 (frozen) and 1 (no smoothing).
+    pub smoothing: f64,
+    /// Re-sample this often even when the value doesn't change, so the rate
+    /// decays to zero when progress stalls. `None` only samples on changes.
+    pub sample_interval: Option<Duration>,
+}
+
+impl Default for RateOfChangeOptions {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(5),
+            smoothing: 0.3,
+            sample_interval: Some(Duration::from_secs(1)),
+        }
+    }
+}
+
+/// Throughput reported by [`use_rate_of_change`], in units per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateOfChange {
+    /// Average rate over the sliding window (0 until there are two samples).
+    pub rate: f64,
+    /// Exponential moving average of [`rate`](Self::rate), steadier for display.
+    pub smoothed: f64,
+}
+
+impl RateOfChange {
+    /// Estimated time until `remaining` more units are done at the smoothed
+    /// rate, or `None` while the value isn't moving towards it.
+    pub fn eta(&self, remaining: f64) -> Option<Duration> {
+        let seconds = remaining / self.smoothed;
+        (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+    }
+}
+
+struct Samples {
+    samples: VecDeque<(f64, f64)>,
+    result: Option<RateOfChange>,
+}
+
+impl Samples {
+    fn push(&mut self, value: f64, options: &RateOfChangeOptions) {
+        let now = js_sys::Date::now();
+        self.samples.push_back((now, value));
+        // Keep the newest sample older than the window, so the window stays covered.
+        let start = now - options.window.as_secs_f64() * 1000.0;
+        while self.samples.len() > 2 && self.samples[1].0 <= start {
+            self.samples.pop_front();
+        }
+        let (Some(&(t0, v0)), Some(&(t1, v1))) = (self.samples.front(), self.samples.back()) else {
+            return;
+        };
+        if t1 <= t0 {
+            return;
+        }
+        let rate = (v1 - v0) / (t1 - t0) * 1000.0;
+        let alpha = options.smoothing.clamp(0.0, 1.0);
+        let smoothed = match self.result {
+            Some(previous) => alpha * rate + (1.0 - alpha) * previous.smoothed,
+            None => rate,
+        };
+        self.result = Some(RateOfChange { rate, smoothed });
+    }
+}
+
+/// A hook that tracks how fast `value` changes over a sliding window, e.g.
+/// items processed or bytes uploaded per second, so progress UIs can show
+/// throughput and ETAs.
+///
+/// Samples are taken when `value` changes and every
+/// [`sample_interval`](RateOfChangeOptions::sample_interval), which re-renders
+/// the component.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Progress)]
+/// fn progress(props: &ProgressProps) -> Html {
+///     let throughput = use_rate_of_change(props.uploaded, RateOfChangeOptions::default());
+///     let eta = throughput.eta(props.total - props.uploaded);
+///
+///     html! {
+///         <p>
+///             { format!("{:.1} MB/s", throughput.smoothed / 1e6) }
+///             if let Some(eta) = eta {
+///                 { format!(", {}s left", eta.as_secs()) }
+///             }
+///         </p>
+///     }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct ProgressProps {
+///     uploaded: f64,
+///     total: f64,
+/// }
+/// ```
+#[hook]
+pub fn use_rate_of_change(value: f64, options: RateOfChangeOptions) -> RateOfChange {
+    let samples = use_mut_ref(|| Samples {
+        samples: VecDeque::new(),
+        result: None,
+    });
+    let last = use_mut_ref(|| None);
+    let tick = use_mut_ref(|| false);
+    let update = use_update();
+
+    // Sample during render, so the new rate is shown without an extra render.
+    let changed = *last.borrow() != Some(value);
+    if changed || std::mem::take(&mut *tick.borrow_mut()) {
+        *last.borrow_mut() = Some(value);
+        samples.borrow_mut().push(value, &options);
+    }
+
+    let millis = options
+        .sample_interval
+        .map_or(0, |i| u32::try_from(i.as_millis()).unwrap_or(u32::MAX));
+    use_interval(
+        move || {
+            *tick.borrow_mut() = true;
+            update();
+        },
+        millis,
+    );
+
+    samples.borrow().result.unwrap_or_default()
+}
@@ -0,0 +1,136 @@
+use gloo::utils::window;
+use web_sys::{
+    Element, ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition, ScrollToOptions,
+};
+use yew::prelude::*;
+
+use crate::{use_prefers_reduced_motion, use_prefers_reduced_motion::prefers_reduced_motion};
+
+/// Smooth scrolling, unless the user prefers reduced motion.
+pub(crate) fn scroll_behavior() -> ScrollBehavior {
+    if prefers_reduced_motion() {
+        ScrollBehavior::Instant
+    } else {
+        ScrollBehavior::Smooth
+    }
+}
+
+/// Scroll `element` into view, aligned vertically at `block`.
+pub(crate) fn scroll_into_view(element: &Element, block: ScrollLogicalPosition) {
+    let options = ScrollIntoViewOptions::new();
+    options.set_block(block);
+    options.set_behavior(scroll_behavior());
+    element.scroll_into_view_with_scroll_into_view_options(&options);
+}
+
+fn scroll_to_options(left: f64, top: f64, behavior: ScrollBehavior) -> ScrollToOptions {
+    let options = ScrollToOptions::new();
+    options.set_left(left);
+    options.set_top(top);
+    options.set_behavior(behavior);
+    options
+}
+
+/// Scroll the window to `(left, top)`.
+pub(crate) fn scroll_to(left: f64, top: f64) {
+    window().scroll_to_with_scroll_to_options(&scroll_to_options(left, top, scroll_behavior()));
+}
+
+/// Jump the window to a saved `(left, top)` without animating, as browsers
+/// restore scroll positions.
+pub(crate) fn restore_scroll(left: f64, top: f64) {
+    window().scroll_to_with_scroll_to_options(&scroll_to_options(
+        left,
+        top,
+        ScrollBehavior::Instant,
+    ));
+}
+
+/// State handle for the [`use_reduced_motion_scroll`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseReducedMotionScrollHandle {
+    reduced_motion: bool,
+}
+
+impl UseReducedMotionScrollHandle {
+    /// Whether the user prefers reduced motion; updates when the setting changes.
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// `Instant` under reduced motion, otherwise `Smooth`, for building your
+    /// own scroll options.
+    pub fn behavior(&self) -> ScrollBehavior {
+        scroll_behavior()
+    }
+
+    /// Scroll `element` into view, aligned vertically at `block`.
+    pub fn scroll_into_view(&self, element: &Element, block: ScrollLogicalPosition) {
+        scroll_into_view(element, block);
+    }
+
+    /// Scroll the window to `(left, top)`.
+    pub fn scroll_to(&self, left: f64, top: f64) {
+        scroll_to(left, top);
+    }
+
+    /// Scroll `element` to `(left, top)`.
+    pub fn scroll_element_to(&self, element: &Element, left: f64, top: f64) {
+        element.scroll_to_with_scroll_to_options(&scroll_to_options(left, top, scroll_behavior()));
+    }
+
+    /// Jump the window to a saved position. Always instant, as restoring a
+    /// position should not look like navigation.
+    pub fn restore(&self, left: f64, top: f64) {
+        restore_scroll(left, top);
+    }
+}
+
+/// A hook for programmatic scrolling that respects `prefers-reduced-motion`:
+/// scrolls are smooth, except instant for users who asked for reduced motion.
+///
+/// The hooks in this crate that scroll (e.g.
+/// [`use_text_fragment_highlight`](crate::use_text_fragment_highlight)) go
+/// through the same helpers, so the whole app behaves consistently.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::{Element, ScrollLogicalPosition};
+///
+/// #[function_component(BackToTop)]
+/// fn back_to_top() -> Html {
+///     let scroll = use_reduced_motion_scroll();
+///     let heading = use_node_ref();
+///
+///     let to_top = {
+///         let scroll = scroll.clone();
+///         Callback::from(move |_| scroll.scroll_to(0.0, 0.0))
+///     };
+///     let to_heading = {
+///         let heading = heading.clone();
+///         Callback::from(move |_| {
+///             if let Some(element) = heading.cast::<Element>() {
+///                 scroll.scroll_into_view(&element, ScrollLogicalPosition::Start);
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <h2 ref={heading}>{ "Comments" }</h2>
+///             <button onclick={to_heading}>{ "Jump to comments" }</button>
+///             <button onclick={to_top}>{ "Back to top" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_reduced_motion_scroll() -> UseReducedMotionScrollHandle {
+    let reduced_motion = use_prefers_reduced_motion();
+
+    UseReducedMotionScrollHandle { reduced_motion }
+}
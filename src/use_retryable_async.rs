@@ -0,0 +1,306 @@
+use gloo::timers::future::TimeoutFuture;
+use std::{cell::Cell, future::Future, rc::Rc, time::Duration};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::{use_latest, use_unmount};
+
+/// When and how often [`use_retryable_async`] retries a failed run.
+pub struct RetryPolicy<E> {
+    /// Total attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_delay: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: f64,
+    /// Upper bound for the delay between attempts.
+    pub max_delay: Duration,
+    /// Fraction of the delay randomly shaved off, so clients don't retry in
+    /// lockstep (0 disables jitter).
+    pub jitter: f64,
+    /// Whether an error is worth retrying, e.g. only 5xx responses; other
+    /// errors are reported right away.
+    pub retry_if: Rc<dyn Fn(&E) -> bool>,
+}
+
+impl<E> RetryPolicy<E> {
+    /// Delay before attempt `attempt` (2 for the first retry).
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(2)).unwrap_or(i32::MAX);
+        let backoff = self
+            .initial_delay
+            .mul_f64(
+                self.multiplier
+                    .max(1.0)
+                    .powi(exponent)
+                    .min(f64::from(u32::MAX)),
+            )
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0) * js_sys::Math::random();
+        backoff.mul_f64(1.0 - jitter)
+    }
+}
+
+impl<E: 'static> Default for RetryPolicy<E> {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(10),
+            jitter: 0.2,
+            retry_if: Rc::new(|_| true),
+        }
+    }
+}
+
+impl<E> Clone for RetryPolicy<E> {
+    fn clone(&self) -> Self {
+        Self {
+            max_attempts: self.max_attempts,
+            initial_delay: self.initial_delay,
+            multiplier: self.multiplier,
+            max_delay: self.max_delay,
+            jitter: self.jitter,
+            retry_if: self.retry_if.clone(),
+        }
+    }
+}
+
+/// State handle for the [`use_retryable_async`] hook.
+pub struct UseRetryableAsyncHandle<T, E> {
+    data: UseStateHandle<Option<T>>,
+    error: UseStateHandle<Option<E>>,
+    loading: UseStateHandle<bool>,
+    attempt: UseStateHandle<u32>,
+    run: Rc<dyn Fn()>,
+    cancel: Rc<dyn Fn()>,
+}
+
+impl<T, E> UseRetryableAsyncHandle<T, E> {
+    /// The result of the last completed run, if it succeeded.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// The error of the last completed run, once retries are exhausted or the
+    /// error is not retryable.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Whether a run is in flight, including waits between attempts.
+    pub fn loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// The current or last attempt of the run, starting at 1; 0 before the
+    /// first run.
+    pub fn attempt(&self) -> u32 {
+        *self.attempt
+    }
+
+    /// Whether the run in flight is past its first attempt.
+    pub fn retrying(&self) -> bool {
+        *self.loading && *self.attempt > 1
+    }
+
+    /// Start a new run, abandoning the one in flight.
+    pub fn run(&self) {
+        (self.run)();
+    }
+
+    /// Abandon the run in flight and its pending retries.
+    pub fn cancel(&self) {
+        (self.cancel)();
+    }
+}
+
+impl<T, E> Clone for UseRetryableAsyncHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            error: self.error.clone(),
+            loading: self.loading.clone(),
+            attempt: self.attempt.clone(),
+            run: self.run.clone(),
+            cancel: self.cancel.clone(),
+        }
+    }
+}
+
+impl<T, E> PartialEq for UseRetryableAsyncHandle<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.error == other.error
+            && self.loading == other.loading
+            && self.attempt == other.attempt
+    }
+}
+
+/// A hook that runs an async function and retries it with exponential
+/// backoff when it fails, so transient failures don't reach the user as
+/// errors right away.
+///
+/// Only errors accepted by [`RetryPolicy::retry_if`] are retried, up to
+/// [`RetryPolicy::max_attempts`] attempts in total; the error is exposed once
+/// retrying stops. [`attempt`](UseRetryableAsyncHandle::attempt) updates
+/// reactively, for "Retrying (2/3)..." messages. Nothing runs until
+/// [`run`](UseRetryableAsyncHandle::run) is called, and results of abandoned
+/// runs are discarded.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// # use std::rc::Rc;
+/// #
+/// use gloo::net::http::Request;
+/// use more_yew_hooks::*;
+///
+/// enum LoadError {
+///     Network(String),
+///     Status(u16),
+/// }
+///
+/// async fn load_profile() -> Result<String, LoadError> {
+///     let response = Request::get("/api/profile")
+///         .send()
+///         .await
+///         .map_err(|e| LoadError::Network(e.to_string()))?;
+///     if !response.ok() {
+///         return Err(LoadError::Status(response.status()));
+///     }
+///     response.text().await.map_err(|e| LoadError::Network(e.to_string()))
+/// }
+///
+/// #[function_component(Profile)]
+/// fn profile() -> Html {
+///     let profile = use_retryable_async(
+///         load_profile,
+///         RetryPolicy {
+///             max_attempts: 4,
+///             retry_if: Rc::new(|e| match e {
+///                 LoadError::Network(_) => true,
+///                 LoadError::Status(status) => *status >= 500,
+///             }),
+///             ..Default::default()
+///         },
+///     );
+///
+///     {
+///         let profile = profile.clone();
+///         use_effect_with((), move |_| profile.run());
+///     }
+///     let retry = {
+///         let profile = profile.clone();
+///         Callback::from(move |_| profile.run())
+///     };
+///
+///     html! {
+///         <div>
+///             if profile.retrying() {
+///                 <p>{ format!("Retrying ({}/4)...", profile.attempt()) }</p>
+///             } else if profile.error().is_some() {
+///                 <button onclick={retry}>{ "Try again" }</button>
+///             } else {
+///                 <p>{ profile.data().cloned().unwrap_or_default() }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_retryable_async<T, E, F, Fut>(
+    f: F,
+    policy: RetryPolicy<E>,
+) -> UseRetryableAsyncHandle<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let data = use_state(|| None);
+    let error = use_state(|| None);
+    let loading = use_state(|| false);
+    let attempt = use_state(|| 0);
+    let generation = use_memo((), |_| Cell::new(0_u64));
+    let f = use_latest(f);
+    let policy = use_latest(policy);
+
+    let run: Rc<dyn Fn()> = {
+        let data = data.clone();
+        let error = error.clone();
+        let loading = loading.clone();
+        let attempt = attempt.clone();
+        let generation = generation.clone();
+        Rc::new(move || {
+            let id = generation.get() + 1;
+            generation.set(id);
+            loading.set(true);
+            attempt.set(1);
+
+            let f = f.clone();
+            let policy = (*policy.current()).clone();
+            let data = data.clone();
+            let error = error.clone();
+            let loading = loading.clone();
+            let attempt = attempt.clone();
+            let generation = generation.clone();
+            spawn_local(async move {
+                let mut current = 1;
+                let result = loop {
+                    let future = (*f.current())();
+                    let result = future.await;
+                    if generation.get() != id {
+                        return;
+                    }
+                    match result {
+                        Err(e) if current < policy.max_attempts && (policy.retry_if)(&e) => {
+                            let millis = u32::try_from(policy.delay(current + 1).as_millis())
+                                .unwrap_or(u32::MAX);
+                            TimeoutFuture::new(millis).await;
+                            if generation.get() != id {
+                                return;
+                            }
+                            current += 1;
+                            attempt.set(current);
+                        }
+                        result => break result,
+                    }
+                };
+                match result {
+                    Ok(value) => {
+                        data.set(Some(value));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let cancel: Rc<dyn Fn()> = {
+        let loading = loading.clone();
+        let generation = generation.clone();
+        Rc::new(move || {
+            generation.set(generation.get() + 1);
+            loading.set(false);
+        })
+    };
+
+    use_unmount(move || generation.set(generation.get() + 1));
+
+    UseRetryableAsyncHandle {
+        data,
+        error,
+        loading,
+        attempt,
+        run,
+        cancel,
+    }
+}
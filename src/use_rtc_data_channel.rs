@@ -0,0 +1,480 @@
+#![cfg(feature = "serde")]
+
+use gloo::events::EventListener;
+use log::warn;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{cell::RefCell, fmt, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    MessageEvent, RtcConfiguration, RtcDataChannel, RtcDataChannelInit, RtcDataChannelState,
+    RtcIceCandidateInit, RtcIceServer, RtcPeerConnection, RtcPeerConnectionIceEvent,
+    RtcPeerConnectionState, RtcSdpType, RtcSessionDescriptionInit,
+};
+use yew::prelude::*;
+
+use crate::js;
+
+/// Connection state of [`use_rtc_data_channel`] (`RTCPeerConnection.connectionState`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RtcConnectionState {
+    /// Not negotiated yet.
+    #[default]
+    New,
+    /// ICE/DTLS negotiation is in progress.
+    Connecting,
+    /// The peers are connected.
+    Connected,
+    /// Connectivity was lost; the browser may recover on its own.
+    Disconnected,
+    /// The connection failed; call [`reset`](UseRtcDataChannelHandle::reset)
+    /// and negotiate again.
+    Failed,
+    /// The connection was closed.
+    Closed,
+}
+
+impl From<RtcPeerConnectionState> for RtcConnectionState {
+    fn from(state: RtcPeerConnectionState) -> Self {
+        match state {
+            RtcPeerConnectionState::Connecting => Self::Connecting,
+            RtcPeerConnectionState::Connected => Self::Connected,
+            RtcPeerConnectionState::Disconnected => Self::Disconnected,
+            RtcPeerConnectionState::Failed => Self::Failed,
+            RtcPeerConnectionState::Closed => Self::Closed,
+            _ => Self::New,
+        }
+    }
+}
+
+/// An ICE candidate to relay to the other peer over your signaling channel.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IceCandidate {
+    pub candidate: String,
+    #[serde(rename = "sdpMid")]
+    pub sdp_mid: Option<String>,
+    #[serde(rename = "sdpMLineIndex")]
+    pub sdp_m_line_index: Option<u16>,
+}
+
+/// Options for the [`use_rtc_data_channel`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RtcDataChannelOptions {
+    /// Label of the data channel; both peers must use the same one.
+    pub label: String,
+    /// STUN/TURN server URLs, e.g. `stun:stun.example.com:3478`. Without any,
+    /// peers can only connect on the same network.
+    pub ice_servers: Vec<String>,
+    /// Deliver messages in order. Unordered channels suit frequent updates
+    /// where only the latest matters, like cursor positions.
+    pub ordered: bool,
+    /// Called with every local ICE candidate, to be relayed to the other
+    /// peer's [`add_ice_candidate`](UseRtcDataChannelHandle::add_ice_candidate).
+    pub on_ice_candidate: Option<Callback<IceCandidate>>,
+}
+
+impl Default for RtcDataChannelOptions {
+    fn default() -> Self {
+        Self {
+            label: "data".to_string(),
+            ice_servers: Vec::new(),
+            ordered: true,
+            on_ice_candidate: None,
+        }
+    }
+}
+
+/// Errors returned by [`UseRtcDataChannelHandle`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RtcDataChannelError {
+    /// The data channel is not open.
+    NotOpen,
+    /// Creating or applying an offer, answer or ICE candidate failed.
+    Signaling(String),
+    /// The message could not be serialized or sent.
+    Send(String),
+}
+
+impl fmt::Display for RtcDataChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotOpen => write!(f, "data channel is not open"),
+            Self::Signaling(e) => write!(f, "signaling failed: {e}"),
+            Self::Send(e) => write!(f, "failed to send message: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RtcDataChannelError {}
+
+fn js_error(e: JsValue) -> String {
+    e.as_string()
+        .or_else(|| js::get(&e, "message").and_then(|m| m.as_string()))
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+struct Peer {
+    connection: RtcPeerConnection,
+    channel: RtcDataChannel,
+    _listeners: Vec<EventListener>,
+}
+
+impl Drop for Peer {
+    fn drop(&mut self) {
+        self.channel.close();
+        self.connection.close();
+    }
+}
+
+struct Session<T> {
+    options: RefCell<RtcDataChannelOptions>,
+    peer: RefCell<Option<Peer>>,
+    state: UseStateHandle<RtcConnectionState>,
+    open: UseStateHandle<bool>,
+    message: UseStateHandle<Option<Rc<T>>>,
+    on_ice_candidate: Rc<RefCell<Option<Callback<IceCandidate>>>>,
+}
+
+impl<T> Session<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    fn connect(&self) {
+        self.peer.borrow_mut().take();
+        self.state.set(RtcConnectionState::New);
+        self.open.set(false);
+        let options = self.options.borrow().clone();
+
+        let configuration = RtcConfiguration::new();
+        if !options.ice_servers.is_empty() {
+            let server = RtcIceServer::new();
+            let urls: js_sys::Array = options
+                .ice_servers
+                .iter()
+                .map(|url| JsValue::from_str(url))
+                .collect();
+            server.set_urls(&urls);
+            configuration.set_ice_servers(&js_sys::Array::of1(&server));
+        }
+        let connection = match RtcPeerConnection::new_with_configuration(&configuration) {
+            Ok(connection) => connection,
+            Err(e) => {
+                warn!("RTCPeerConnection is not available: {}", js_error(e));
+                self.state.set(RtcConnectionState::Failed);
+                return;
+            }
+        };
+
+        // A pre-negotiated channel exists on both peers as soon as they
+        // connect, so neither side has to wait for `datachannel`.
+        let init = RtcDataChannelInit::new();
+        init.set_negotiated(true);
+        init.set_id(0);
+        init.set_ordered(options.ordered);
+        let channel = connection.create_data_channel_with_data_channel_dict(&options.label, &init);
+
+        let listeners = vec![
+            {
+                let state = self.state.clone();
+                let target = connection.clone();
+                EventListener::new(&connection, "connectionstatechange", move |_| {
+                    state.set(target.connection_state().into());
+                })
+            },
+            {
+                let on_ice_candidate = self.on_ice_candidate.clone();
+                EventListener::new(&connection, "icecandidate", move |e| {
+                    let Some(candidate) = e
+                        .dyn_ref::<RtcPeerConnectionIceEvent>()
+                        .and_then(|e| e.candidate())
+                    else {
+                        return;
+                    };
+                    if let Some(callback) = &*on_ice_candidate.borrow() {
+                        callback.emit(IceCandidate {
+                            candidate: candidate.candidate(),
+                            sdp_mid: candidate.sdp_mid(),
+                            sdp_m_line_index: candidate.sdp_m_line_index(),
+                        });
+                    }
+                })
+            },
+            {
+                let open = self.open.clone();
+                EventListener::new(&channel, "open", move |_| open.set(true))
+            },
+            {
+                let open = self.open.clone();
+                EventListener::new(&channel, "close", move |_| open.set(false))
+            },
+            {
+                let message = self.message.clone();
+                EventListener::new(&channel, "message", move |e| {
+                    let Some(text) = e
+                        .dyn_ref::<MessageEvent>()
+                        .and_then(|e| e.data().as_string())
+                    else {
+                        return;
+                    };
+                    match serde_json::from_str::<T>(&text) {
+                        Ok(value) => message.set(Some(Rc::new(value))),
+                        Err(e) => warn!("Ignoring malformed data channel message: {e}"),
+                    }
+                })
+            },
+        ];
+
+        *self.peer.borrow_mut() = Some(Peer {
+            connection,
+            channel,
+            _listeners: listeners,
+        });
+    }
+
+    fn connection(&self) -> Result<RtcPeerConnection, RtcDataChannelError> {
+        self.peer
+            .borrow()
+            .as_ref()
+            .map(|peer| peer.connection.clone())
+            .ok_or_else(|| RtcDataChannelError::Signaling("no peer connection".to_string()))
+    }
+}
+
+async fn description(promise: js_sys::Promise) -> Result<String, RtcDataChannelError> {
+    let description = JsFuture::from(promise)
+        .await
+        .map_err(|e| RtcDataChannelError::Signaling(js_error(e)))?;
+    js::get(&description, "sdp")
+        .and_then(|sdp| sdp.as_string())
+        .ok_or_else(|| RtcDataChannelError::Signaling("description has no SDP".to_string()))
+}
+
+async fn apply(promise: js_sys::Promise) -> Result<(), RtcDataChannelError> {
+    JsFuture::from(promise)
+        .await
+        .map(drop)
+        .map_err(|e| RtcDataChannelError::Signaling(js_error(e)))
+}
+
+fn session_description(kind: RtcSdpType, sdp: &str) -> RtcSessionDescriptionInit {
+    let init = RtcSessionDescriptionInit::new(kind);
+    init.set_sdp(sdp);
+    init
+}
+
+/// State handle for the [`use_rtc_data_channel`] hook.
+pub struct UseRtcDataChannelHandle<T> {
+    session: Rc<Session<T>>,
+    state: UseStateHandle<RtcConnectionState>,
+    open: UseStateHandle<bool>,
+    message: UseStateHandle<Option<Rc<T>>>,
+}
+
+impl<T> UseRtcDataChannelHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    /// The peer connection state.
+    pub fn state(&self) -> RtcConnectionState {
+        *self.state
+    }
+
+    /// Whether the data channel is open for sending.
+    pub fn is_open(&self) -> bool {
+        *self.open
+    }
+
+    /// The latest message received from the peer.
+    pub fn message(&self) -> Option<Rc<T>> {
+        (*self.message).clone()
+    }
+
+    /// Start negotiating as the calling peer: returns the offer SDP to send to
+    /// the other peer's [`accept_offer`](Self::accept_offer).
+    pub async fn create_offer(&self) -> Result<String, RtcDataChannelError> {
+        let connection = self.session.connection()?;
+        let sdp = description(connection.create_offer()).await?;
+        apply(connection.set_local_description(&session_description(RtcSdpType::Offer, &sdp)))
+            .await?;
+        Ok(sdp)
+    }
+
+    /// Accept the other peer's offer: returns the answer SDP to send back to
+    /// its [`accept_answer`](Self::accept_answer).
+    pub async fn accept_offer(&self, offer: &str) -> Result<String, RtcDataChannelError> {
+        let connection = self.session.connection()?;
+        apply(connection.set_remote_description(&session_description(RtcSdpType::Offer, offer)))
+            .await?;
+        let sdp = description(connection.create_answer()).await?;
+        apply(connection.set_local_description(&session_description(RtcSdpType::Answer, &sdp)))
+            .await?;
+        Ok(sdp)
+    }
+
+    /// Complete negotiation with the other peer's answer.
+    pub async fn accept_answer(&self, answer: &str) -> Result<(), RtcDataChannelError> {
+        let connection = self.session.connection()?;
+        apply(connection.set_remote_description(&session_description(RtcSdpType::Answer, answer)))
+            .await
+    }
+
+    /// Add an ICE candidate relayed from the other peer. Call this after the
+    /// remote description was applied.
+    pub async fn add_ice_candidate(
+        &self,
+        candidate: &IceCandidate,
+    ) -> Result<(), RtcDataChannelError> {
+        let connection = self.session.connection()?;
+        let init = RtcIceCandidateInit::new(&candidate.candidate);
+        init.set_sdp_mid(candidate.sdp_mid.as_deref());
+        init.set_sdp_m_line_index(candidate.sdp_m_line_index);
+        apply(connection.add_ice_candidate_with_opt_rtc_ice_candidate_init(Some(&init))).await
+    }
+
+    /// Send `message` to the peer as JSON.
+    pub fn send(&self, message: &T) -> Result<(), RtcDataChannelError> {
+        let peer = self.session.peer.borrow();
+        let channel = peer
+            .as_ref()
+            .map(|peer| &peer.channel)
+            .filter(|channel| channel.ready_state() == RtcDataChannelState::Open)
+            .ok_or(RtcDataChannelError::NotOpen)?;
+        let json =
+            serde_json::to_string(message).map_err(|e| RtcDataChannelError::Send(e.to_string()))?;
+        channel
+            .send_with_str(&json)
+            .map_err(|e| RtcDataChannelError::Send(js_error(e)))
+    }
+
+    /// Close the connection.
+    pub fn close(&self) {
+        self.session.peer.borrow_mut().take();
+        self.state.set(RtcConnectionState::Closed);
+        self.open.set(false);
+    }
+
+    /// Replace the connection with a fresh one, e.g. after it failed or was
+    /// closed. Negotiation starts over.
+    pub fn reset(&self) {
+        self.session.connect();
+    }
+}
+
+impl<T> Clone for UseRtcDataChannelHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            session: self.session.clone(),
+            state: self.state.clone(),
+            open: self.open.clone(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseRtcDataChannelHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.session, &other.session)
+            && *self.state == *other.state
+            && *self.open == *other.open
+            && match (&*self.message, &*other.message) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+/// A hook that manages an `RTCPeerConnection` with a single data channel for
+/// peer-to-peer features like collaborative cursors, sending and receiving
+/// `T` as JSON.
+///
+/// Signaling is left to the app: the calling peer sends the SDP from
+/// [`create_offer`](UseRtcDataChannelHandle::create_offer) to the other peer,
+/// which replies with the SDP from
+/// [`accept_offer`](UseRtcDataChannelHandle::accept_offer), and both relay
+/// the candidates from [`RtcDataChannelOptions::on_ice_candidate`]. Both
+/// peers must use the same [`label`](RtcDataChannelOptions::label), since the
+/// channel is pre-negotiated. The connection is created on mount, recreated
+/// when the label, ICE servers or ordering change, and closed on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Cursor {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[function_component(SharedCanvas)]
+/// fn shared_canvas() -> Html {
+///     let peer = use_rtc_data_channel::<Cursor>(RtcDataChannelOptions {
+///         ordered: false,
+///         on_ice_candidate: Some(Callback::from(|candidate: IceCandidate| {
+///             // Relay `candidate` to the other peer over your signaling server.
+///         })),
+///         ..Default::default()
+///     });
+///
+///     let onmousemove = {
+///         let peer = peer.clone();
+///         Callback::from(move |e: MouseEvent| {
+///             let _ = peer.send(&Cursor { x: e.offset_x(), y: e.offset_y() });
+///         })
+///     };
+///
+///     html! {
+///         <div {onmousemove}>
+///             if let Some(cursor) = peer.message() {
+///                 <p>{ format!("Peer cursor at {}, {}", cursor.x, cursor.y) }</p>
+///             }
+///             <p>{ format!("{:?}", peer.state()) }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_rtc_data_channel<T>(options: RtcDataChannelOptions) -> UseRtcDataChannelHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let state = use_state(RtcConnectionState::default);
+    let open = use_state(|| false);
+    let message = use_state(|| None);
+    let session = use_memo((), |_| Session {
+        options: RefCell::new(options.clone()),
+        peer: RefCell::new(None),
+        state: state.clone(),
+        open: open.clone(),
+        message: message.clone(),
+        on_ice_candidate: Rc::new(RefCell::new(None)),
+    });
+    *session.on_ice_candidate.borrow_mut() = options.on_ice_candidate.clone();
+
+    {
+        let session = session.clone();
+        let key = (
+            options.label.clone(),
+            options.ice_servers.clone(),
+            options.ordered,
+        );
+        use_effect_with(key, move |_| {
+            *session.options.borrow_mut() = options;
+            session.connect();
+            move || {
+                session.peer.borrow_mut().take();
+            }
+        });
+    }
+
+    UseRtcDataChannelHandle {
+        session,
+        state,
+        open,
+        message,
+    }
+}
@@ -0,0 +1,185 @@
+use gloo::{events::EventListener, utils::window};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{OrientationLockType, OrientationType, ScreenOrientation};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// The screen's orientation, as reported by [`use_screen_orientation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Orientation {
+    /// E.g. `LandscapePrimary`.
+    pub kind: OrientationType,
+    /// Degrees of rotation from the device's natural orientation: 0, 90, 180
+    /// or 270.
+    pub angle: u16,
+}
+
+impl Orientation {
+    fn current() -> Option<Self> {
+        let orientation = screen_orientation()?;
+        Some(Self {
+            kind: orientation.type_().ok()?,
+            angle: orientation.angle().ok()?,
+        })
+    }
+
+    /// Whether the screen is wider than it is tall.
+    pub fn is_landscape(&self) -> bool {
+        matches!(
+            self.kind,
+            OrientationType::LandscapePrimary | OrientationType::LandscapeSecondary
+        )
+    }
+
+    /// Whether the screen is taller than it is wide.
+    pub fn is_portrait(&self) -> bool {
+        !self.is_landscape()
+    }
+}
+
+/// `screen.orientation`, missing before Safari 16.4.
+fn screen_orientation() -> Option<ScreenOrientation> {
+    let screen = window().screen().ok()?;
+    js::has(&screen, "orientation").then(|| screen.orientation())
+}
+
+/// Release the lock if we hold it.
+fn unlock(locked: &Cell<bool>) {
+    if locked.replace(false)
+        && let Some(orientation) = screen_orientation()
+    {
+        let _ = orientation.unlock();
+    }
+}
+
+/// State handle for the [`use_screen_orientation`] hook.
+#[derive(Clone)]
+pub struct UseScreenOrientationHandle {
+    orientation: Option<Orientation>,
+    error: UseStateHandle<Option<String>>,
+    locked: Rc<Cell<bool>>,
+}
+
+impl UseScreenOrientationHandle {
+    /// Whether the Screen Orientation API is available.
+    pub fn is_supported(&self) -> bool {
+        screen_orientation().is_some()
+    }
+
+    /// The current orientation; `None` where unsupported.
+    pub fn orientation(&self) -> Option<Orientation> {
+        self.orientation
+    }
+
+    /// The error of the last [`lock`](Self::lock), if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Lock the screen to `orientation` until [`unlock`](Self::unlock) or
+    /// unmount. Mostly limited to Android, and usually only while
+    /// fullscreen; the failure is reported in [`error`](Self::error).
+    pub fn lock(&self, orientation: OrientationLockType) {
+        let promise = screen_orientation().map(|o| o.lock(orientation));
+        let Some(Ok(promise)) = promise else {
+            self.error
+                .set(Some("Screen orientation lock is not supported".to_string()));
+            return;
+        };
+        let error = self.error.clone();
+        let locked = self.locked.clone();
+        spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(_) => {
+                    locked.set(true);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(
+                    js::get(&e, "message")
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                )),
+            }
+        });
+    }
+
+    /// Release a [`lock`](Self::lock).
+    pub fn unlock(&self) {
+        unlock(&self.locked);
+    }
+}
+
+impl PartialEq for UseScreenOrientationHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.orientation == other.orientation
+            && self.error == other.error
+            && Rc::ptr_eq(&self.locked, &other.locked)
+    }
+}
+
+/// A sensor hook for the Screen Orientation API: the screen's orientation
+/// type and angle, updated on rotation, plus [`lock`](UseScreenOrientationHandle::lock)
+/// for games and media that need landscape.
+///
+/// A lock taken through the handle is released on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::OrientationLockType;
+///
+/// #[function_component(Game)]
+/// fn game() -> Html {
+///     let screen = use_screen_orientation();
+///
+///     let onclick = {
+///         let screen = screen.clone();
+///         Callback::from(move |_| screen.lock(OrientationLockType::Landscape))
+///     };
+///
+///     html! {
+///         if screen.orientation().is_some_and(|o| o.is_portrait()) {
+///             <div class="rotate-hint">
+///                 <p>{ "Rotate your device to play" }</p>
+///                 <button {onclick}>{ "Switch to landscape" }</button>
+///             </div>
+///         } else {
+///             <canvas id="game" />
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_screen_orientation() -> UseScreenOrientationHandle {
+    let orientation = use_state_eq(Orientation::current);
+    let error = use_state_eq(|| None);
+    let locked = use_memo((), |_| Cell::new(false));
+
+    {
+        let orientation = orientation.clone();
+        let locked = locked.clone();
+        use_effect_with((), move |_| {
+            let listener = screen_orientation().map(|target| {
+                orientation.set(Orientation::current());
+                EventListener::new(&target, "change", move |_| {
+                    orientation.set(Orientation::current());
+                })
+            });
+            move || {
+                drop(listener);
+                unlock(&locked);
+            }
+        });
+    }
+
+    UseScreenOrientationHandle {
+        orientation: *orientation,
+        error,
+        locked,
+    }
+}
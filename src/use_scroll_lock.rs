@@ -0,0 +1,160 @@
+use gloo::{
+    events::{EventListener, EventListenerOptions},
+    utils::{body, document, window},
+};
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Elements matching this selector, and their descendants, stay scrollable by
+/// touch while scrolling is locked.
+const SCROLLABLE: &str = "[data-scroll-lock-scrollable]";
+
+/// The active lock, shared by every component holding one.
+struct Lock {
+    count: usize,
+    /// `<body>`'s inline `overflow` and `padding-right` before locking.
+    overflow: String,
+    padding_right: String,
+    /// Blocks iOS Safari's touch scrolling, which ignores `overflow: hidden`.
+    _touchmove: EventListener,
+}
+
+thread_local! {
+    static LOCK: RefCell<Option<Lock>> = const { RefCell::new(None) };
+}
+
+fn acquire() {
+    LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        if let Some(lock) = lock.as_mut() {
+            lock.count += 1;
+            return;
+        }
+        let style = body().style();
+        let overflow = style.get_property_value("overflow").unwrap_or_default();
+        let padding_right = style
+            .get_property_value("padding-right")
+            .unwrap_or_default();
+        // Pad by the scrollbar's width so the content doesn't shift when it
+        // disappears.
+        let scrollbar = window()
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .zip(document().document_element())
+            .map_or(0.0, |(width, root)| width - root.client_width() as f64);
+        if scrollbar > 0.0 {
+            let current = window()
+                .get_computed_style(&body())
+                .ok()
+                .flatten()
+                .and_then(|computed| computed.get_property_value("padding-right").ok())
+                .and_then(|padding| padding.trim_end_matches("px").parse::<f64>().ok())
+                .unwrap_or(0.0);
+            let _ = style.set_property("padding-right", &format!("{}px", current + scrollbar));
+        }
+        let _ = style.set_property("overflow", "hidden");
+        let touchmove = EventListener::new_with_options(
+            &document(),
+            "touchmove",
+            EventListenerOptions::enable_prevent_default(),
+            |event| {
+                let scrollable = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| target.closest(SCROLLABLE).ok().flatten())
+                    .is_some();
+                if !scrollable {
+                    event.prevent_default();
+                }
+            },
+        );
+        *lock = Some(Lock {
+            count: 1,
+            overflow,
+            padding_right,
+            _touchmove: touchmove,
+        });
+    });
+}
+
+fn release() {
+    LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        let Some(active) = lock.as_mut() else {
+            return;
+        };
+        active.count -= 1;
+        if active.count > 0 {
+            return;
+        }
+        let style = body().style();
+        for (property, value) in [
+            ("overflow", &active.overflow),
+            ("padding-right", &active.padding_right),
+        ] {
+            if value.is_empty() {
+                let _ = style.remove_property(property);
+            } else {
+                let _ = style.set_property(property, value);
+            }
+        }
+        *lock = None;
+    });
+}
+
+/// A hook that prevents the page from scrolling while `active`, e.g. while a
+/// modal or drawer is open, and restores it when `active` turns `false` or on
+/// unmount.
+///
+/// Sets `overflow: hidden` on `<body>`, padded by the scrollbar's width so
+/// the layout doesn't shift, and blocks touch scrolling on iOS, which
+/// ignores `overflow` on `<body>`. Mark the dialog's own scrolling container
+/// with `data-scroll-lock-scrollable` to keep it touch-scrollable. Locks are
+/// counted, so nested dialogs only unlock when the last one closes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Drawer)]
+/// fn drawer() -> Html {
+///     let open = use_state(|| false);
+///     use_scroll_lock(*open);
+///
+///     let ontoggle = {
+///         let open = open.clone();
+///         Callback::from(move |_| open.set(!*open))
+///     };
+///
+///     html! {
+///         <>
+///             <button onclick={ontoggle.clone()}>{ "Menu" }</button>
+///             if *open {
+///                 <nav class="drawer" data-scroll-lock-scrollable="">
+///                     <button onclick={ontoggle}>{ "Close" }</button>
+///                 </nav>
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_lock(active: bool) {
+    use_effect_with(active, |active| {
+        let locked = *active;
+        if locked {
+            acquire();
+        }
+        move || {
+            if locked {
+                release();
+            }
+        }
+    });
+}
@@ -0,0 +1,243 @@
+use gloo::{events::EventListener, utils::window};
+use std::{cell::Cell, rc::Rc, time::Duration};
+use web_sys::{Element, EventTarget};
+use yew::prelude::*;
+
+use crate::use_window_size::{ResizeLimiter, ResizeRate};
+
+/// Direction of the last scroll movement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Scroll offsets and direction returned by [`use_window_scroll`] and
+/// [`use_element_scroll`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollPosition {
+    /// Horizontal offset in CSS pixels.
+    pub x: f64,
+    /// Vertical offset in CSS pixels.
+    pub y: f64,
+    /// Direction of the last movement, vertical first; `None` before any.
+    pub direction: Option<ScrollDirection>,
+}
+
+impl ScrollPosition {
+    /// The position at `(x, y)`, moving from `self`.
+    fn next(self, x: f64, y: f64) -> Self {
+        let direction = if y > self.y {
+            Some(ScrollDirection::Down)
+        } else if y < self.y {
+            Some(ScrollDirection::Up)
+        } else if x > self.x {
+            Some(ScrollDirection::Right)
+        } else if x < self.x {
+            Some(ScrollDirection::Left)
+        } else {
+            self.direction
+        };
+        Self { x, y, direction }
+    }
+}
+
+/// Options for [`use_window_scroll_with_options`] and
+/// [`use_element_scroll_with_options`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScrollOptions {
+    /// Update at most this often while scrolling, including the final
+    /// position. [`Duration::ZERO`] updates on every `scroll` event.
+    pub throttle: Duration,
+}
+
+/// Track the scroll offsets read by `read` on `target`'s passive `scroll`
+/// events into `position`.
+fn listen<R>(
+    target: &EventTarget,
+    read: R,
+    position: UseStateHandle<ScrollPosition>,
+    options: &ScrollOptions,
+) -> impl FnOnce() + use<R>
+where
+    R: Fn() -> Option<(f64, f64)> + 'static,
+{
+    // Starting from the current offsets, the initial position has no direction.
+    let start = read().map_or_else(ScrollPosition::default, |(x, y)| ScrollPosition {
+        x,
+        y,
+        direction: None,
+    });
+    let last = Rc::new(Cell::new(start));
+    let update = Rc::new(move || {
+        if let Some((x, y)) = read() {
+            last.set(last.get().next(x, y));
+            position.set(last.get());
+        }
+    });
+    update();
+    let limiter = ResizeLimiter::new(ResizeRate::Throttle(options.throttle));
+    let listener = {
+        let limiter = limiter.clone();
+        EventListener::new(target, "scroll", move |_| {
+            let update = update.clone();
+            limiter.run(move || update());
+        })
+    };
+    move || {
+        drop(listener);
+        limiter.cancel();
+    }
+}
+
+/// A sensor hook returning the window's scroll offsets and the direction of
+/// the last scroll, for sticky headers that hide while scrolling down,
+/// reading-progress bars and scroll-linked effects.
+///
+/// The `scroll` listener is passive and every event re-renders; use
+/// [`use_window_scroll_with_options`] to throttle updates.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Header)]
+/// fn header() -> Html {
+///     let scroll = use_window_scroll();
+///     let hidden = scroll.y > 64.0 && scroll.direction == Some(ScrollDirection::Down);
+///
+///     html! {
+///         <header class={classes!("sticky", hidden.then_some("sticky--hidden"))}>
+///             { "My site" }
+///         </header>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_window_scroll() -> ScrollPosition {
+    use_window_scroll_with_options(ScrollOptions::default())
+}
+
+/// [`use_window_scroll`] with [`ScrollOptions`], e.g. to throttle updates.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(BackToTop)]
+/// fn back_to_top() -> Html {
+///     let scroll = use_window_scroll_with_options(ScrollOptions {
+///         throttle: Duration::from_millis(100),
+///     });
+///
+///     html! {
+///         if scroll.y > 800.0 {
+///             <a href="#top">{ "Back to top" }</a>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_window_scroll_with_options(options: ScrollOptions) -> ScrollPosition {
+    let position = use_state_eq(ScrollPosition::default);
+
+    {
+        let position = position.clone();
+        use_effect_with(options, move |options| {
+            let read = || {
+                let window = window();
+                Some((window.scroll_x().ok()?, window.scroll_y().ok()?))
+            };
+            listen(&window(), read, position, options)
+        });
+    }
+
+    *position
+}
+
+/// A sensor hook returning the scroll offsets of the element behind `node`
+/// and the direction of its last scroll. See [`use_window_scroll`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Log)]
+/// fn log() -> Html {
+///     let node = use_node_ref();
+///     let scroll = use_element_scroll(node.clone());
+///
+///     html! {
+///         <div ref={node} style="overflow: auto; height: 300px">
+///             <div class={classes!("shadow", (scroll.y > 0.0).then_some("shadow--visible"))} />
+///             <pre>{ "..." }</pre>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_scroll(node: NodeRef) -> ScrollPosition {
+    use_element_scroll_with_options(node, ScrollOptions::default())
+}
+
+/// [`use_element_scroll`] with [`ScrollOptions`], e.g. to throttle updates.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Transcript)]
+/// fn transcript() -> Html {
+///     let node = use_node_ref();
+///     let scroll = use_element_scroll_with_options(
+///         node.clone(),
+///         ScrollOptions {
+///             throttle: Duration::from_millis(100),
+///         },
+///     );
+///
+///     html! {
+///         <div ref={node} style="overflow: auto; height: 300px">
+///             <p>{ format!("Scrolled {:.0}px", scroll.y) }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_scroll_with_options(node: NodeRef, options: ScrollOptions) -> ScrollPosition {
+    let position = use_state_eq(ScrollPosition::default);
+
+    {
+        let position = position.clone();
+        use_effect_with((node, options), move |(node, options)| {
+            let cleanup = node.cast::<Element>().map(|element| {
+                let target = element.clone();
+                let read = move || Some((target.scroll_left() as f64, target.scroll_top() as f64));
+                listen(&element, read, position, options)
+            });
+            move || {
+                if let Some(cleanup) = cleanup {
+                    cleanup();
+                }
+            }
+        });
+    }
+
+    *position
+}
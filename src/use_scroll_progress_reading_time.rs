@@ -0,0 +1,193 @@
+use gloo::{events::EventListener, utils::window};
+use std::{rc::Rc, time::Duration};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Element, ResizeObserver};
+use yew::prelude::*;
+
+/// Reading speeds measured from scrolling outside this range (words per
+/// minute) are skimming or jumping, and are ignored.
+const PLAUSIBLE_WPM: (f64, f64) = (50.0, 1500.0);
+
+/// Options for the [`use_scroll_progress_reading_time`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReadingTimeOptions {
+    /// Reading speed assumed until one has been measured.
+    pub words_per_minute: f64,
+    /// Scroll progress is sampled over windows of at least this long to
+    /// measure the reader's speed.
+    pub sample_window: Duration,
+    /// Weight of the newest measurement in the smoothed reading speed.
+    pub smoothing: f64,
+}
+
+impl Default for ReadingTimeOptions {
+    fn default() -> Self {
+        Self {
+            words_per_minute: 230.0,
+            sample_window: Duration::from_secs(5),
+            smoothing: 0.3,
+        }
+    }
+}
+
+/// Reading progress reported by [`use_scroll_progress_reading_time`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReadingProgress {
+    /// How far the content has been scrolled through, from 0 to 1.
+    pub progress: f64,
+    /// Number of words in the content.
+    pub word_count: usize,
+    /// Measured reading speed, or the configured one until measured.
+    pub words_per_minute: f64,
+    /// Estimated time to read the rest of the content.
+    pub remaining: Duration,
+}
+
+struct Tracker {
+    options: ReadingTimeOptions,
+    word_count: usize,
+    measured_wpm: Option<f64>,
+    /// Time and progress at the start of the current sample window.
+    anchor: Option<(f64, f64)>,
+}
+
+impl Tracker {
+    fn measure(&mut self, element: &Element) -> ReadingProgress {
+        let rect = element.get_bounding_client_rect();
+        let viewport = window()
+            .inner_height()
+            .ok()
+            .and_then(|h| h.as_f64())
+            .unwrap_or_default();
+        let scrollable = rect.height() - viewport;
+        let progress = if scrollable > 0.0 {
+            (-rect.top() / scrollable).clamp(0.0, 1.0)
+        } else if rect.bottom() <= viewport {
+            1.0
+        } else {
+            0.0
+        };
+
+        let now = js_sys::Date::now();
+        match self.anchor {
+            Some((_, start)) if progress < start => self.anchor = Some((now, progress)),
+            Some((time, start)) => {
+                let minutes = (now - time) / 60_000.0;
+                if minutes * 60.0 >= self.options.sample_window.as_secs_f64() {
+                    let wpm = (progress - start) * self.word_count as f64 / minutes;
+                    if (PLAUSIBLE_WPM.0..=PLAUSIBLE_WPM.1).contains(&wpm) {
+                        self.measured_wpm = Some(match self.measured_wpm {
+                            Some(m) => m + self.options.smoothing * (wpm - m),
+                            None => wpm,
+                        });
+                    }
+                    self.anchor = Some((now, progress));
+                }
+            }
+            None => self.anchor = Some((now, progress)),
+        }
+
+        let words_per_minute = self.measured_wpm.unwrap_or(self.options.words_per_minute);
+        let remaining_words = (1.0 - progress) * self.word_count as f64;
+        let remaining = if words_per_minute > 0.0 {
+            Duration::from_secs_f64(remaining_words / words_per_minute * 60.0)
+        } else {
+            Duration::ZERO
+        };
+        ReadingProgress {
+            progress,
+            word_count: self.word_count,
+            words_per_minute,
+            remaining,
+        }
+    }
+}
+
+/// A hook for article pages that reports how far `node` has been scrolled
+/// through and estimates the remaining reading time, e.g. for a progress bar.
+///
+/// Progress is 0 while the top of the content is below the top of the
+/// viewport and 1 once its bottom reaches the bottom of the viewport. The
+/// estimate starts from [`ReadingTimeOptions::words_per_minute`] and adapts to
+/// the reader's measured scroll speed; fast jumps and scrolling back are
+/// ignored. The word count follows content changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Article)]
+/// fn article() -> Html {
+///     let content = use_node_ref();
+///     let reading = use_scroll_progress_reading_time(content.clone(), Default::default());
+///
+///     html! {
+///         <>
+///             <progress value={reading.progress.to_string()} />
+///             <p>{ format!("{} min left", reading.remaining.as_secs().div_ceil(60)) }</p>
+///             <article ref={content}>{ "Lorem ipsum dolor sit amet..." }</article>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_progress_reading_time(
+    node: NodeRef,
+    options: ReadingTimeOptions,
+) -> ReadingProgress {
+    let state = use_state_eq(ReadingProgress::default);
+    let tracker = use_mut_ref(|| Tracker {
+        options,
+        word_count: 0,
+        measured_wpm: None,
+        anchor: None,
+    });
+    tracker.borrow_mut().options = options;
+
+    {
+        let state = state.clone();
+        use_effect_with(node, move |node| {
+            let element = node.cast::<Element>();
+            let measure = {
+                let element = element.clone();
+                let tracker = tracker.clone();
+                Rc::new(move || {
+                    if let Some(element) = &element {
+                        state.set(tracker.borrow_mut().measure(element));
+                    }
+                })
+            };
+            let recount = {
+                let element = element.clone();
+                let measure = measure.clone();
+                Closure::<dyn FnMut()>::new(move || {
+                    if let Some(element) = &element {
+                        let text = element.text_content().unwrap_or_default();
+                        tracker.borrow_mut().word_count = text.split_whitespace().count();
+                    }
+                    measure();
+                })
+            };
+            let observer = ResizeObserver::new(recount.as_ref().unchecked_ref()).ok();
+            if let (Some(observer), Some(element)) = (&observer, &element) {
+                observer.observe(element);
+            }
+            let listeners = ["scroll", "resize"].map(|event| {
+                let measure = measure.clone();
+                EventListener::new(&window(), event, move |_| measure())
+            });
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                drop(recount);
+                drop(listeners);
+            }
+        });
+    }
+
+    *state
+}
@@ -0,0 +1,135 @@
+use std::time::Duration;
+use yew::prelude::*;
+use yew_hooks::{use_interval, use_update};
+
+use crate::{TimeSample, TimeSyncOptions, use_time_sync};
+
+/// Options for the [`use_server_time_with_options`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerTimeOptions {
+    /// Sample the endpoint this often.
+    pub sample_interval: Duration,
+    /// Requests per sample; the one with the lowest round-trip time wins.
+    pub samples: u32,
+    /// Weight of each new sample in the smoothed offset (0–1); lower values
+    /// follow the server more slowly but ignore network jitter better.
+    pub smoothing: f64,
+    /// Re-render this often so [`now`](UseServerTimeHandle::now) ticks, e.g.
+    /// for countdowns; `None` only re-renders when the offset changes.
+    pub tick: Option<Duration>,
+}
+
+impl Default for ServerTimeOptions {
+    fn default() -> Self {
+        Self {
+            sample_interval: Duration::from_secs(60),
+            samples: 3,
+            smoothing: 0.3,
+            tick: Some(Duration::from_secs(1)),
+        }
+    }
+}
+
+/// State handle for the [`use_server_time`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseServerTimeHandle {
+    offset: UseStateHandle<Option<f64>>,
+    sample: Option<TimeSample>,
+}
+
+impl UseServerTimeHandle {
+    /// The smoothed server clock minus client clock, in milliseconds (0 until
+    /// the first sample).
+    pub fn offset(&self) -> f64 {
+        self.offset.unwrap_or(0.0)
+    }
+
+    /// The latest raw sample.
+    pub fn last_sample(&self) -> Option<TimeSample> {
+        self.sample
+    }
+
+    /// Whether at least one sample succeeded.
+    pub fn is_synced(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    /// The server's current time, in milliseconds since the Unix epoch.
+    pub fn now(&self) -> f64 {
+        js_sys::Date::now() + self.offset()
+    }
+}
+
+/// A hook that keeps a smoothed estimate of the server's clock by sampling
+/// `endpoint` every minute, and re-renders every second so
+/// [`now`](UseServerTimeHandle::now) can drive countdowns to server-side
+/// deadlines even when the user's clock is off.
+///
+/// `endpoint` may return the server time in milliseconds since the Unix epoch
+/// as its body; otherwise its `Date` header is used. Samples are taken as by
+/// [`use_time_sync`] and combined with an exponential moving average, so a
+/// single slow response doesn't make the clock jump.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(SaleCountdown)]
+/// fn sale_countdown(props: &SaleCountdownProps) -> Html {
+///     let server = use_server_time("/api/time".to_string());
+///     let left = ((props.ends_at - server.now()) / 1000.0).max(0.0) as u64;
+///
+///     html! {
+///         <p>{ format!("Sale ends in {}:{:02}", left / 60, left % 60) }</p>
+///     }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct SaleCountdownProps {
+///     ends_at: f64,
+/// }
+/// ```
+#[hook]
+pub fn use_server_time(endpoint: String) -> UseServerTimeHandle {
+    use_server_time_with_options(endpoint, ServerTimeOptions::default())
+}
+
+/// Like [`use_server_time`], with options for the sampling interval,
+/// smoothing and render tick.
+#[hook]
+pub fn use_server_time_with_options(
+    endpoint: String,
+    options: ServerTimeOptions,
+) -> UseServerTimeHandle {
+    let sync = use_time_sync(TimeSyncOptions {
+        url: endpoint,
+        samples: options.samples,
+        resync_interval: Some(options.sample_interval),
+    });
+    let offset = use_state(|| None);
+    let sample = sync.sample();
+
+    {
+        let offset = offset.clone();
+        let smoothing = options.smoothing.clamp(0.0, 1.0);
+        use_effect_with(sample, move |sample| {
+            if let Some(sample) = sample {
+                offset.set(Some(match *offset {
+                    Some(previous) => previous + (sample.offset - previous) * smoothing,
+                    None => sample.offset,
+                }));
+            }
+        });
+    }
+
+    let update = use_update();
+    let millis = options.tick.map_or(0, |tick| {
+        u32::try_from(tick.as_millis()).unwrap_or(u32::MAX)
+    });
+    use_interval(move || update(), millis);
+
+    UseServerTimeHandle { offset, sample }
+}
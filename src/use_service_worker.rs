@@ -0,0 +1,254 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ServiceWorkerContainer, ServiceWorkerRegistration};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// The message [`UseServiceWorkerHandle::skip_waiting_and_reload`] posts to
+/// the waiting worker, as `{ type: "SKIP_WAITING" }` (the Workbox convention).
+pub const SKIP_WAITING_MESSAGE_TYPE: &str = "SKIP_WAITING";
+
+/// Which workers a registration has, as reported by [`use_service_worker`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ServiceWorkerState {
+    /// A new worker is installing.
+    pub installing: bool,
+    /// A new worker is installed and waiting for the old one to let go.
+    pub waiting: bool,
+    /// A worker is active.
+    pub active: bool,
+    /// The page is controlled by a worker. `false` on the first visit.
+    pub controlled: bool,
+}
+
+impl ServiceWorkerState {
+    fn read(registration: &ServiceWorkerRegistration) -> Self {
+        Self {
+            installing: registration.installing().is_some(),
+            waiting: registration.waiting().is_some(),
+            active: registration.active().is_some(),
+            controlled: container().and_then(|c| c.controller()).is_some(),
+        }
+    }
+}
+
+fn container() -> Option<ServiceWorkerContainer> {
+    let navigator = window().navigator();
+    js::has(&navigator, "serviceWorker").then(|| navigator.service_worker())
+}
+
+/// State handle for the [`use_service_worker`] hook.
+#[derive(Clone)]
+pub struct UseServiceWorkerHandle {
+    registration: UseStateHandle<Option<ServiceWorkerRegistration>>,
+    state: UseStateHandle<ServiceWorkerState>,
+    error: UseStateHandle<Option<String>>,
+    reload: Rc<RefCell<Option<EventListener>>>,
+}
+
+impl UseServiceWorkerHandle {
+    /// Whether service workers are available; they require a secure context.
+    pub fn is_supported(&self) -> bool {
+        container().is_some()
+    }
+
+    /// The registration, once registered.
+    pub fn registration(&self) -> Option<&ServiceWorkerRegistration> {
+        self.registration.as_ref()
+    }
+
+    /// The registration's lifecycle state.
+    pub fn state(&self) -> ServiceWorkerState {
+        *self.state
+    }
+
+    /// Whether a new version is installed and waiting to replace the one
+    /// controlling the page, e.g. to show a "New version available" toast.
+    pub fn update_available(&self) -> bool {
+        self.state.waiting && self.state.controlled
+    }
+
+    /// The error of the registration or the last update check, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Check the server for a new version of the script.
+    pub fn update(&self) {
+        let Some(Ok(promise)) = self.registration.as_ref().map(|r| r.update()) else {
+            return;
+        };
+        let error = self.error.clone();
+        spawn_local(async move {
+            if let Err(e) = JsFuture::from(promise).await {
+                error.set(Some(
+                    js::get(&e, "message")
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                ));
+            }
+        });
+    }
+
+    /// Activate the waiting worker and reload the page once it takes control.
+    /// The worker must call `self.skipWaiting()` on receiving
+    /// [`SKIP_WAITING_MESSAGE_TYPE`].
+    pub fn skip_waiting_and_reload(&self) {
+        let (Some(container), Some(waiting)) = (
+            container(),
+            self.registration.as_ref().and_then(|r| r.waiting()),
+        ) else {
+            return;
+        };
+        *self.reload.borrow_mut() =
+            Some(EventListener::once(&container, "controllerchange", |_| {
+                let _ = window().location().reload();
+            }));
+        let message = js::object(&[("type", SKIP_WAITING_MESSAGE_TYPE.into())]);
+        let _ = waiting.post_message(&message);
+    }
+}
+
+impl PartialEq for UseServiceWorkerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.registration == *other.registration
+            && *self.state == *other.state
+            && *self.error == *other.error
+            && Rc::ptr_eq(&self.reload, &other.reload)
+    }
+}
+
+/// A hook that registers the service worker at `script_url` and follows its
+/// lifecycle, so apps can show a "New version available" toast when
+/// [`update_available`](UseServiceWorkerHandle::update_available) and apply
+/// it with [`skip_waiting_and_reload`](UseServiceWorkerHandle::skip_waiting_and_reload).
+///
+/// The worker has to cooperate:
+///
+/// ```js
+/// self.addEventListener("message", (event) => {
+///   if (event.data?.type === "SKIP_WAITING") self.skipWaiting();
+/// });
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(UpdateToast)]
+/// fn update_toast() -> Html {
+///     let sw = use_service_worker("/sw.js".to_string());
+///
+///     let onclick = {
+///         let sw = sw.clone();
+///         Callback::from(move |_| sw.skip_waiting_and_reload())
+///     };
+///
+///     html! {
+///         if sw.update_available() {
+///             <div role="status" class="toast">
+///                 { "A new version is available. " }
+///                 <button {onclick}>{ "Reload" }</button>
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_service_worker(script_url: String) -> UseServiceWorkerHandle {
+    let registration = use_state_eq(|| None);
+    let state = use_state_eq(ServiceWorkerState::default);
+    let error = use_state_eq(|| None);
+    let reload = use_mut_ref(|| None);
+
+    {
+        let registration = registration.clone();
+        let state = state.clone();
+        let error = error.clone();
+        use_effect_with(script_url, move |script_url| {
+            let alive = Rc::new(Cell::new(true));
+            let listeners = Rc::new(RefCell::new(Vec::new()));
+            if let Some(container) = container() {
+                let promise = container.register(script_url);
+                let alive = alive.clone();
+                let listeners = listeners.clone();
+                spawn_local(async move {
+                    let result = JsFuture::from(promise).await;
+                    if !alive.get() {
+                        return;
+                    }
+                    let reg: ServiceWorkerRegistration = match result {
+                        Ok(reg) => reg.unchecked_into(),
+                        Err(e) => {
+                            error.set(Some(
+                                js::get(&e, "message")
+                                    .and_then(|m| m.as_string())
+                                    .unwrap_or_else(|| format!("{e:?}")),
+                            ));
+                            return;
+                        }
+                    };
+                    let refresh = {
+                        let reg = reg.clone();
+                        Rc::new(move || state.set(ServiceWorkerState::read(&reg)))
+                    };
+                    refresh();
+
+                    let watch_installing = {
+                        let reg = reg.clone();
+                        let refresh = refresh.clone();
+                        let listeners = Rc::downgrade(&listeners);
+                        move || {
+                            let (Some(worker), Some(listeners)) =
+                                (reg.installing(), listeners.upgrade())
+                            else {
+                                return;
+                            };
+                            let refresh = refresh.clone();
+                            listeners.borrow_mut().push(EventListener::new(
+                                &worker,
+                                "statechange",
+                                move |_| refresh(),
+                            ));
+                        }
+                    };
+                    watch_installing();
+                    {
+                        let mut listeners = listeners.borrow_mut();
+                        let refresh = refresh.clone();
+                        listeners.push(EventListener::new(&reg, "updatefound", move |_| {
+                            watch_installing();
+                            refresh();
+                        }));
+                    }
+                    listeners.borrow_mut().push(EventListener::new(
+                        &container,
+                        "controllerchange",
+                        move |_| refresh(),
+                    ));
+                    registration.set(Some(reg));
+                });
+            }
+            move || {
+                alive.set(false);
+                listeners.borrow_mut().clear();
+            }
+        });
+    }
+
+    UseServiceWorkerHandle {
+        registration,
+        state,
+        error,
+        reload,
+    }
+}
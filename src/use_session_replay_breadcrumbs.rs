@@ -0,0 +1,369 @@
+#![cfg(feature = "storage")]
+
+use gloo::{
+    events::{EventListener, EventListenerOptions, EventListenerPhase},
+    storage::{SessionStorage, Storage},
+    timers::callback::Timeout,
+    utils::{document, window},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::{Cell, Ref, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{Element, ErrorEvent, PromiseRejectionEvent};
+use yew::prelude::*;
+
+use crate::js;
+
+/// What a [`Breadcrumb`] records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreadcrumbKind {
+    /// The location changed; the message is the URL.
+    Navigation,
+    /// A marked element was clicked; the message is its marker value.
+    Click,
+    /// An uncaught error, unhandled promise rejection or `console.error` call.
+    Error,
+    /// Added by the app through [`UseSessionReplayBreadcrumbsHandle::add`].
+    Custom,
+}
+
+/// A single entry of the breadcrumb trail.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Breadcrumb {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: f64,
+    pub kind: BreadcrumbKind,
+    pub message: String,
+}
+
+/// Options for the [`use_session_replay_breadcrumbs`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreadcrumbOptions {
+    /// Maximum number of breadcrumbs kept; the oldest are dropped first.
+    pub capacity: usize,
+    /// Also keep the trail in `sessionStorage` under this key, so it survives reloads.
+    pub storage_key: Option<String>,
+    /// Clicks are only recorded on elements (or descendants of elements) carrying
+    /// this attribute, and only the attribute's value is recorded.
+    pub click_attribute: String,
+    /// Keep query strings and fragments of navigation URLs (they often contain
+    /// tokens or personal data, so they are stripped by default).
+    pub keep_url_query: bool,
+    /// Called on every breadcrumb before it is recorded; return `None` to drop
+    /// it or a modified copy to redact it.
+    pub redact: Option<Callback<Breadcrumb, Option<Breadcrumb>>>,
+}
+
+impl Default for BreadcrumbOptions {
+    fn default() -> Self {
+        Self {
+            capacity: 50,
+            storage_key: None,
+            click_attribute: "data-breadcrumb".to_string(),
+            keep_url_query: false,
+            redact: None,
+        }
+    }
+}
+
+struct Trail {
+    options: RefCell<BreadcrumbOptions>,
+    crumbs: RefCell<VecDeque<Breadcrumb>>,
+    last_url: RefCell<String>,
+}
+
+impl Trail {
+    fn record(&self, kind: BreadcrumbKind, message: String) {
+        let crumb = Breadcrumb {
+            timestamp: js_sys::Date::now(),
+            kind,
+            message,
+        };
+        let options = self.options.borrow();
+        let crumb = match &options.redact {
+            Some(redact) => redact.emit(crumb),
+            None => Some(crumb),
+        };
+        let Some(crumb) = crumb else {
+            return;
+        };
+        let mut crumbs = self.crumbs.borrow_mut();
+        crumbs.push_back(crumb);
+        while crumbs.len() > options.capacity {
+            crumbs.pop_front();
+        }
+        if let Some(key) = &options.storage_key {
+            let _ = SessionStorage::set(key, &*crumbs);
+        }
+    }
+
+    /// `url` without its query string and fragment, unless
+    /// [`BreadcrumbOptions::keep_url_query`] is set.
+    fn redact_url(&self, url: &str) -> String {
+        if self.options.borrow().keep_url_query {
+            return url.to_string();
+        }
+        url.split(['?', '#']).next().unwrap_or_default().to_string()
+    }
+
+    fn current_url(&self) -> String {
+        self.redact_url(&window().location().href().unwrap_or_default())
+    }
+
+    fn check_navigation(&self) {
+        let url = self.current_url();
+        if *self.last_url.borrow() != url {
+            *self.last_url.borrow_mut() = url.clone();
+            self.record(BreadcrumbKind::Navigation, url);
+        }
+    }
+}
+
+/// A thrown value or console argument as text.
+fn describe(value: &JsValue) -> String {
+    value
+        .as_string()
+        .or_else(|| value.dyn_ref::<js_sys::Error>().map(|e| e.message().into()))
+        .unwrap_or_else(|| format!("{value:?}"))
+}
+
+type ApplyTrap = Closure<dyn FnMut(JsValue, JsValue, js_sys::Array) -> Result<JsValue, JsValue>>;
+
+/// `console.error` replaced by a `Proxy` that records a breadcrumb and then
+/// calls the original with the same arguments.
+struct ConsoleError {
+    console: JsValue,
+    original: JsValue,
+    proxy: js_sys::Proxy,
+    trap: ApplyTrap,
+    enabled: Rc<Cell<bool>>,
+}
+
+impl ConsoleError {
+    fn wrap(trail: Rc<Trail>) -> Option<Self> {
+        let console = js::get(&window(), "console")?;
+        let original = js::get(&console, "error")?;
+        let target: js_sys::Function = original.clone().dyn_into().ok()?;
+        let enabled = Rc::new(Cell::new(true));
+        let trap: ApplyTrap = {
+            let enabled = enabled.clone();
+            // Set while recording, so a `redact` callback that logs an error
+            // doesn't record itself.
+            let recording = Cell::new(false);
+            Closure::new(move |target: JsValue, this: JsValue, args: js_sys::Array| {
+                if enabled.get() && !recording.replace(true) {
+                    let message = args.iter().map(|arg| describe(&arg)).collect::<Vec<_>>();
+                    trail.record(
+                        BreadcrumbKind::Error,
+                        format!("console.error: {}", message.join(" ")),
+                    );
+                    recording.set(false);
+                }
+                js_sys::Reflect::apply(target.unchecked_ref(), &this, &args)
+            })
+        };
+        let handler = js::object(&[("apply", trap.as_ref().clone())]);
+        let proxy = js_sys::Proxy::new(&target, &handler);
+        js_sys::Reflect::set(&console, &"error".into(), &proxy).ok()?;
+        Some(Self {
+            console,
+            original,
+            proxy,
+            trap,
+            enabled,
+        })
+    }
+
+    /// Put the original `console.error` back. If something else wrapped it in
+    /// the meantime, the proxy stays in place as a plain pass-through.
+    fn restore(self) {
+        self.enabled.set(false);
+        if js::get(&self.console, "error").is_some_and(|current| current == *self.proxy) {
+            let _ = js_sys::Reflect::set(&self.console, &"error".into(), &self.original);
+        } else {
+            self.trap.forget();
+        }
+    }
+}
+
+/// State handle for the [`use_session_replay_breadcrumbs`] hook.
+#[derive(Clone)]
+pub struct UseSessionReplayBreadcrumbsHandle {
+    trail: Rc<Trail>,
+}
+
+impl UseSessionReplayBreadcrumbsHandle {
+    /// The recorded trail, oldest first.
+    pub fn breadcrumbs(&'_ self) -> Ref<'_, VecDeque<Breadcrumb>> {
+        self.trail.crumbs.borrow()
+    }
+
+    /// Record an app-specific breadcrumb (subject to redaction).
+    pub fn add(&self, message: impl Into<String>) {
+        self.trail.record(BreadcrumbKind::Custom, message.into());
+    }
+
+    /// Serialize the trail to JSON, e.g. to attach it to a bug report.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&*self.trail.crumbs.borrow()).unwrap_or_default()
+    }
+
+    /// Forget the trail (and its `sessionStorage` copy).
+    pub fn clear(&self) {
+        self.trail.crumbs.borrow_mut().clear();
+        if let Some(key) = &self.trail.options.borrow().storage_key {
+            SessionStorage::delete(key);
+        }
+    }
+}
+
+impl PartialEq for UseSessionReplayBreadcrumbsHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.trail, &other.trail)
+    }
+}
+
+/// A hook that records a bounded, privacy-filtered breadcrumb trail for bug
+/// reports: route changes, clicks on elements marked with
+/// [`BreadcrumbOptions::click_attribute`], uncaught errors / unhandled
+/// promise rejections, and `console.error` calls.
+///
+/// `console.error` is wrapped while the component is mounted: every call is
+/// recorded and then forwarded to the original, which is restored on unmount.
+///
+/// Nothing is captured from the page besides marker values, URLs (including
+/// error locations, without query strings by default) and error messages, and every breadcrumb passes
+/// through [`BreadcrumbOptions::redact`] first. Recording does not re-render;
+/// read the trail when building a report.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let breadcrumbs = use_session_replay_breadcrumbs(BreadcrumbOptions {
+///         storage_key: Some("breadcrumbs".to_string()),
+///         redact: Some(Callback::from(|mut crumb: Breadcrumb| {
+///             crumb.message = crumb.message.replace("secret", "[redacted]");
+///             Some(crumb)
+///         })),
+///         ..Default::default()
+///     });
+///
+///     let onreport = {
+///         let breadcrumbs = breadcrumbs.clone();
+///         Callback::from(move |_| log::info!("Bug report trail: {}", breadcrumbs.to_json()))
+///     };
+///
+///     html! {
+///         <button data-breadcrumb="report-bug" onclick={onreport}>{ "Report a bug" }</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_session_replay_breadcrumbs(
+    options: BreadcrumbOptions,
+) -> UseSessionReplayBreadcrumbsHandle {
+    let trail = use_memo((), |_| {
+        let crumbs = options
+            .storage_key
+            .as_ref()
+            .and_then(|key| SessionStorage::get(key).ok())
+            .unwrap_or_default();
+        Trail {
+            options: RefCell::new(options.clone()),
+            crumbs: RefCell::new(crumbs),
+            last_url: RefCell::new(String::new()),
+        }
+    });
+    *trail.options.borrow_mut() = options;
+
+    {
+        let trail = trail.clone();
+        use_effect_with((), move |_| {
+            trail.check_navigation();
+            let mut listeners = Vec::new();
+
+            for event in ["popstate", "hashchange"] {
+                let trail = trail.clone();
+                listeners.push(EventListener::new(&window(), event, move |_| {
+                    trail.check_navigation()
+                }));
+            }
+            {
+                // Client-side routers use `history.pushState`, which fires no
+                // event, so re-check the location right after every click.
+                let trail = trail.clone();
+                let pending = Rc::new(RefCell::new(None));
+                let options = EventListenerOptions {
+                    phase: EventListenerPhase::Capture,
+                    passive: true,
+                };
+                listeners.push(EventListener::new_with_options(
+                    &document(),
+                    "click",
+                    options,
+                    move |e| {
+                        let attribute = trail.options.borrow().click_attribute.clone();
+                        let marked = e
+                            .target()
+                            .and_then(|t| t.dyn_into::<Element>().ok())
+                            .and_then(|el| el.closest(&format!("[{attribute}]")).ok().flatten())
+                            .and_then(|el| el.get_attribute(&attribute));
+                        if let Some(marker) = marked {
+                            trail.record(BreadcrumbKind::Click, marker);
+                        }
+                        let trail = trail.clone();
+                        *pending.borrow_mut() =
+                            Some(Timeout::new(0, move || trail.check_navigation()));
+                    },
+                ));
+            }
+            {
+                let trail = trail.clone();
+                listeners.push(EventListener::new(&window(), "error", move |e| {
+                    if let Some(e) = e.dyn_ref::<ErrorEvent>() {
+                        let filename = trail.redact_url(&e.filename());
+                        let message = format!("{} ({filename}:{})", e.message(), e.lineno());
+                        trail.record(BreadcrumbKind::Error, message);
+                    }
+                }));
+            }
+            {
+                let trail = trail.clone();
+                listeners.push(EventListener::new(
+                    &window(),
+                    "unhandledrejection",
+                    move |e| {
+                        if let Some(e) = e.dyn_ref::<PromiseRejectionEvent>() {
+                            let message = describe(&e.reason());
+                            trail.record(
+                                BreadcrumbKind::Error,
+                                format!("Unhandled rejection: {message}"),
+                            );
+                        }
+                    },
+                ));
+            }
+
+            let console_error = ConsoleError::wrap(trail.clone());
+
+            move || {
+                drop(listeners);
+                if let Some(console_error) = console_error {
+                    console_error.restore();
+                }
+            }
+        });
+    }
+
+    UseSessionReplayBreadcrumbsHandle { trail }
+}
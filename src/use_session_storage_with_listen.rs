@@ -8,6 +8,8 @@ use web_sys::StorageEvent;
 use yew::prelude::*;
 use yew_hooks::use_event_with_window;
 
+use crate::storage::{StorageOptions, debounce};
+
 /// State handle for the [`use_session_storage_with_listen`] hook.
 pub struct UseSessionStorageWithListenHandle<T> {
     inner: UseStateHandle<Option<T>>,
@@ -67,7 +69,7 @@ where
 /// ```rust
 /// # use yew::prelude::*;
 /// #
-/// use yew_hooks::prelude::*;
+/// use more_yew_hooks::*;
 ///
 /// #[function_component(SessionStorage)]
 /// fn session_storage() -> Html {
@@ -102,12 +104,47 @@ where
 /// ```
 #[hook]
 pub fn use_session_storage_with_listen<T>(key: String) -> UseSessionStorageWithListenHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    use_session_storage_with_listen_with_options(key, StorageOptions::default())
+}
+
+/// [`use_session_storage_with_listen`] with [`StorageOptions`], e.g. to debounce
+/// `storage` events when another tab writes the key rapidly.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(WizardStep)]
+/// fn wizard_step() -> Html {
+///     let step = use_session_storage_with_listen_with_options::<u32>(
+///         "wizard-step".to_string(),
+///         StorageOptions {
+///             listen_debounce: Duration::from_millis(300),
+///         },
+///     );
+///
+///     html! { <p>{ format!("Step {}", step.unwrap_or(1)) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_session_storage_with_listen_with_options<T>(
+    key: String,
+    options: StorageOptions,
+) -> UseSessionStorageWithListenHandle<T>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
     let inner: UseStateHandle<Option<T>> =
         use_state(|| SessionStorage::get(&key).unwrap_or_default());
     let key = use_memo((), |_| key);
+    let pending = use_mut_ref(|| None);
 
     {
         let key = key.clone();
@@ -122,7 +159,11 @@ where
             }
             if k == *key {
                 info!("SessionStorage event for key: {k}");
-                inner.set(SessionStorage::get(&*key).unwrap_or_default());
+                let key = key.clone();
+                let inner = inner.clone();
+                debounce(&pending, options.listen_debounce, move || {
+                    inner.set(SessionStorage::get(&*key).unwrap_or_default());
+                });
             }
         });
     }
@@ -0,0 +1,146 @@
+use gloo::utils::{document, window};
+use wasm_bindgen::JsValue;
+use yew::prelude::*;
+
+use crate::{js, use_network_information};
+
+/// What the browser does with the speculated URLs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpeculationAction {
+    /// Fetch the document only.
+    #[default]
+    Prefetch,
+    /// Load and render the whole page in the background.
+    Prerender,
+}
+
+/// When the browser acts on the speculated URLs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpeculationEagerness {
+    /// As soon as the rules are added.
+    Immediate,
+    /// As soon as possible, with slightly less priority than `Immediate`.
+    Eager,
+    /// When the user hovers a link to the URL.
+    #[default]
+    Moderate,
+    /// When the user starts clicking a link to the URL.
+    Conservative,
+}
+
+impl SpeculationEagerness {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Immediate => "immediate",
+            Self::Eager => "eager",
+            Self::Moderate => "moderate",
+            Self::Conservative => "conservative",
+        }
+    }
+}
+
+/// Options for the [`use_speculation_rules`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeculationRulesOptions {
+    pub action: SpeculationAction,
+    pub eagerness: SpeculationEagerness,
+    /// Skip speculation when data saving is on or the connection is slow
+    /// (see [`NetworkInformation::is_constrained`](crate::NetworkInformation::is_constrained)).
+    pub respect_data_saver: bool,
+}
+
+impl Default for SpeculationRulesOptions {
+    fn default() -> Self {
+        Self {
+            action: SpeculationAction::default(),
+            eagerness: SpeculationEagerness::default(),
+            respect_data_saver: true,
+        }
+    }
+}
+
+/// Whether the browser supports `<script type="speculationrules">`.
+fn is_supported() -> bool {
+    js::get(&window(), "HTMLScriptElement")
+        .and_then(|script| js::call(&script, "supports", &["speculationrules".into()]).ok())
+        .and_then(|supported| supported.as_bool())
+        .unwrap_or(false)
+}
+
+fn rules(urls: &[String], options: &SpeculationRulesOptions) -> Option<String> {
+    let urls: js_sys::Array = urls.iter().map(|url| JsValue::from_str(url)).collect();
+    let rule = js::object(&[
+        ("source", "list".into()),
+        ("urls", urls.into()),
+        ("eagerness", options.eagerness.as_str().into()),
+    ]);
+    let action = match options.action {
+        SpeculationAction::Prefetch => "prefetch",
+        SpeculationAction::Prerender => "prerender",
+    };
+    let rules = js::object(&[(action, js_sys::Array::of1(&rule).into())]);
+    js_sys::JSON::stringify(&rules).ok().map(String::from)
+}
+
+/// A hook that injects Speculation Rules for the likely next URLs, so the
+/// browser can prefetch or prerender them and multi-page navigations become
+/// instant.
+///
+/// The rules follow `urls` and are removed on unmount. Returns whether rules
+/// are currently active: `false` where unsupported (everywhere but Chromium),
+/// with no URLs, or on constrained connections when
+/// [`respect_data_saver`](SpeculationRulesOptions::respect_data_saver) is set.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Pagination)]
+/// fn pagination(props: &PaginationProps) -> Html {
+///     let next = format!("/articles?page={}", props.page + 1);
+///     use_speculation_rules(
+///         vec![next.clone()],
+///         SpeculationRulesOptions {
+///             action: SpeculationAction::Prerender,
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! { <a href={next}>{ "Next page" }</a> }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct PaginationProps {
+///     page: u32,
+/// }
+/// ```
+#[hook]
+pub fn use_speculation_rules(urls: Vec<String>, options: SpeculationRulesOptions) -> bool {
+    let network = use_network_information();
+    let active = !urls.is_empty()
+        && (!options.respect_data_saver || !network.is_constrained())
+        && is_supported();
+
+    use_effect_with((urls, options, active), |(urls, options, active)| {
+        let script = active
+            .then(|| rules(urls, options))
+            .flatten()
+            .and_then(|rules| {
+                let script = document().create_element("script").ok()?;
+                script.set_attribute("type", "speculationrules").ok()?;
+                script.set_text_content(Some(&rules));
+                document().head()?.append_child(&script).ok()?;
+                Some(script)
+            });
+        move || {
+            if let Some(script) = script {
+                script.remove();
+            }
+        }
+    });
+
+    active
+}
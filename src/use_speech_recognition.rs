@@ -0,0 +1,285 @@
+use gloo::{events::EventListener, utils::window};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::EventTarget;
+use yew::prelude::*;
+
+use crate::js;
+
+/// Options for the [`use_speech_recognition`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpeechRecognitionOptions {
+    /// BCP 47 language to recognize, e.g. `"de-DE"`; the page's language
+    /// otherwise.
+    pub lang: Option<String>,
+    /// Keep listening through pauses until [`stop`](UseSpeechRecognitionHandle::stop),
+    /// instead of stopping after the first phrase.
+    pub continuous: bool,
+    /// Report guesses in [`interim`](UseSpeechRecognitionHandle::interim)
+    /// while the user is still speaking.
+    pub interim_results: bool,
+}
+
+impl Default for SpeechRecognitionOptions {
+    fn default() -> Self {
+        Self {
+            lang: None,
+            continuous: false,
+            interim_results: true,
+        }
+    }
+}
+
+/// `SpeechRecognition`, or the `webkitSpeechRecognition` of Chrome and Safari.
+fn constructor() -> Option<js_sys::Function> {
+    ["SpeechRecognition", "webkitSpeechRecognition"]
+        .iter()
+        .find_map(|name| js::get(&window(), name))
+        .and_then(|constructor| constructor.dyn_into().ok())
+}
+
+/// The running recognition and its listeners, dropped when it ends.
+#[derive(Default)]
+struct Session {
+    recognition: RefCell<Option<(JsValue, Vec<EventListener>)>>,
+    /// Final text of the session so far.
+    transcript: RefCell<String>,
+}
+
+impl Session {
+    fn call(&self, method: &str) {
+        // Not borrowed during the call, in case it fires `end` synchronously.
+        let recognition = self
+            .recognition
+            .borrow()
+            .as_ref()
+            .map(|(recognition, _)| recognition.clone());
+        if let Some(recognition) = recognition {
+            let _ = js::call(&recognition, method, &[]);
+        }
+    }
+}
+
+/// State handle for the [`use_speech_recognition`] hook.
+#[derive(Clone)]
+pub struct UseSpeechRecognitionHandle {
+    listening: UseStateHandle<bool>,
+    transcript: UseStateHandle<String>,
+    interim: UseStateHandle<String>,
+    error: UseStateHandle<Option<String>>,
+    session: Rc<Session>,
+    options: SpeechRecognitionOptions,
+}
+
+impl UseSpeechRecognitionHandle {
+    /// Whether the browser can recognize speech (Chrome, Edge and Safari;
+    /// Chrome sends audio to a server).
+    pub fn is_supported(&self) -> bool {
+        constructor().is_some()
+    }
+
+    /// Whether the microphone is listening.
+    pub fn listening(&self) -> bool {
+        *self.listening
+    }
+
+    /// The recognized text of the current or last session.
+    pub fn transcript(&self) -> &str {
+        &self.transcript
+    }
+
+    /// The guess for what is being said, not yet final.
+    pub fn interim(&self) -> &str {
+        &self.interim
+    }
+
+    /// The error of the current or last session, e.g. `"not-allowed"` when
+    /// the microphone permission is denied, or `"no-speech"`.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Start a new session, clearing the transcript. Asks for the microphone
+    /// permission the first time; call from a user gesture.
+    pub fn start(&self) {
+        let Some(constructor) = constructor() else {
+            self.error.set(Some("not-supported".to_string()));
+            return;
+        };
+        self.session.call("abort");
+        let Ok(recognition) = js_sys::Reflect::construct(&constructor, &js_sys::Array::new())
+        else {
+            return;
+        };
+        let set = |key: &str, value: JsValue| {
+            let _ = js_sys::Reflect::set(&recognition, &key.into(), &value);
+        };
+        if let Some(lang) = &self.options.lang {
+            set("lang", lang.into());
+        }
+        set("continuous", self.options.continuous.into());
+        set("interimResults", self.options.interim_results.into());
+
+        let target: &EventTarget = recognition.unchecked_ref();
+        let listeners = vec![
+            {
+                let listening = self.listening.clone();
+                EventListener::new(target, "start", move |_| listening.set(true))
+            },
+            {
+                let listening = self.listening.clone();
+                let interim = self.interim.clone();
+                let session = self.session.clone();
+                EventListener::new(target, "end", move |_| {
+                    session.recognition.take();
+                    listening.set(false);
+                    interim.set(String::new());
+                })
+            },
+            {
+                let error = self.error.clone();
+                EventListener::new(target, "error", move |event| {
+                    error.set(js::get(event, "error").and_then(|e| e.as_string()));
+                })
+            },
+            {
+                let transcript = self.transcript.clone();
+                let interim = self.interim.clone();
+                let session = self.session.clone();
+                EventListener::new(target, "result", move |event| {
+                    let Some(results) = js::get(event, "results") else {
+                        return;
+                    };
+                    let from = js::get(event, "resultIndex")
+                        .and_then(|i| i.as_f64())
+                        .unwrap_or(0.0) as u32;
+                    let length = js::get(&results, "length")
+                        .and_then(|l| l.as_f64())
+                        .unwrap_or(0.0) as u32;
+                    let mut guess = String::new();
+                    for index in from..length {
+                        let result = js_sys::Reflect::get_u32(&results, index).unwrap_or_default();
+                        let text = js_sys::Reflect::get_u32(&result, 0)
+                            .ok()
+                            .and_then(|alternative| js::get(&alternative, "transcript"))
+                            .and_then(|text| text.as_string())
+                            .unwrap_or_default();
+                        if js::get(&result, "isFinal").and_then(|f| f.as_bool()) == Some(true) {
+                            session.transcript.borrow_mut().push_str(&text);
+                        } else {
+                            guess.push_str(&text);
+                        }
+                    }
+                    transcript.set(session.transcript.borrow().clone());
+                    interim.set(guess);
+                })
+            },
+        ];
+
+        self.session.transcript.borrow_mut().clear();
+        self.transcript.set(String::new());
+        self.interim.set(String::new());
+        self.error.set(None);
+        let started = js::call(&recognition, "start", &[]);
+        *self.session.recognition.borrow_mut() = Some((recognition, listeners));
+        if let Err(e) = started {
+            self.error.set(Some(
+                js::get(&e, "message")
+                    .and_then(|m| m.as_string())
+                    .unwrap_or_else(|| format!("{e:?}")),
+            ));
+        }
+    }
+
+    /// Stop listening and finalize what was heard so far.
+    pub fn stop(&self) {
+        self.session.call("stop");
+    }
+
+    /// Stop listening and discard what is still being recognized.
+    pub fn abort(&self) {
+        self.session.call("abort");
+    }
+}
+
+impl PartialEq for UseSpeechRecognitionHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.listening == *other.listening
+            && *self.transcript == *other.transcript
+            && *self.interim == *other.interim
+            && *self.error == *other.error
+            && Rc::ptr_eq(&self.session, &other.session)
+            && self.options == other.options
+    }
+}
+
+/// A hook for speech recognition from the Web Speech API (`SpeechRecognition`,
+/// or `webkitSpeechRecognition` in Chrome and Safari): dictation and voice
+/// commands with the final transcript and interim guesses as state.
+///
+/// Check [`is_supported`](UseSpeechRecognitionHandle::is_supported): Firefox
+/// has no speech recognition. A running session is aborted on unmount, so the
+/// microphone doesn't stay on.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Dictation)]
+/// fn dictation() -> Html {
+///     let speech = use_speech_recognition(SpeechRecognitionOptions {
+///         lang: Some("en-US".to_string()),
+///         ..Default::default()
+///     });
+///
+///     let onclick = {
+///         let speech = speech.clone();
+///         Callback::from(move |_| {
+///             if speech.listening() {
+///                 speech.stop();
+///             } else {
+///                 speech.start();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         if speech.is_supported() {
+///             <div>
+///                 <button {onclick}>{ if speech.listening() { "Stop" } else { "Dictate" } }</button>
+///                 <p>{ speech.transcript() }<i>{ speech.interim() }</i></p>
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_speech_recognition(options: SpeechRecognitionOptions) -> UseSpeechRecognitionHandle {
+    let listening = use_state_eq(|| false);
+    let transcript = use_state_eq(String::new);
+    let interim = use_state_eq(String::new);
+    let error = use_state_eq(|| None);
+    let session = use_memo((), |_| Session::default());
+
+    {
+        let session = session.clone();
+        use_effect_with((), move |_| {
+            move || {
+                session.call("abort");
+                session.recognition.take();
+            }
+        });
+    }
+
+    UseSpeechRecognitionHandle {
+        listening,
+        transcript,
+        interim,
+        error,
+        session,
+        options,
+    }
+}
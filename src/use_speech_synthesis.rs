@@ -0,0 +1,299 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{SpeechSynthesis, SpeechSynthesisUtterance, SpeechSynthesisVoice};
+use yew::prelude::*;
+
+use crate::js;
+
+/// A voice offered by the browser, as listed by [`use_speech_synthesis`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Voice {
+    /// Identifies the voice; pass it as [`SpeakOptions::voice`].
+    pub uri: String,
+    /// Human-readable, e.g. `"Google UK English Female"`.
+    pub name: String,
+    /// BCP 47 language tag, e.g. `"en-GB"`.
+    pub lang: String,
+    /// Whether it is the browser's default voice.
+    pub default: bool,
+    /// Whether it works offline rather than through a remote service.
+    pub local: bool,
+}
+
+impl From<SpeechSynthesisVoice> for Voice {
+    fn from(voice: SpeechSynthesisVoice) -> Self {
+        Self {
+            uri: voice.voice_uri(),
+            name: voice.name(),
+            lang: voice.lang(),
+            default: voice.default(),
+            local: voice.local_service(),
+        }
+    }
+}
+
+/// Options for [`UseSpeechSynthesisHandle::speak`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpeakOptions {
+    /// [`Voice::uri`] of the voice to use; the default voice for `lang`
+    /// otherwise.
+    pub voice: Option<String>,
+    /// Language of the text, e.g. `"fr-FR"`; the page's language otherwise.
+    pub lang: Option<String>,
+    /// 0.1 to 10.
+    pub rate: f32,
+    /// 0 to 2.
+    pub pitch: f32,
+    /// 0 to 1.
+    pub volume: f32,
+}
+
+impl Default for SpeakOptions {
+    fn default() -> Self {
+        Self {
+            voice: None,
+            lang: None,
+            rate: 1.0,
+            pitch: 1.0,
+            volume: 1.0,
+        }
+    }
+}
+
+fn synthesis() -> Option<SpeechSynthesis> {
+    js::has(&window(), "speechSynthesis")
+        .then(|| window().speech_synthesis().ok())
+        .flatten()
+}
+
+fn voices(synthesis: &SpeechSynthesis) -> Vec<SpeechSynthesisVoice> {
+    synthesis
+        .get_voices()
+        .iter()
+        .map(|voice| voice.unchecked_into())
+        .collect()
+}
+
+fn voice_list(synthesis: &SpeechSynthesis) -> Vec<Voice> {
+    voices(synthesis).into_iter().map(Voice::from).collect()
+}
+
+/// Utterances spoken through the handle.
+#[derive(Default)]
+struct Queue {
+    /// Queued or speaking.
+    pending: Cell<usize>,
+    /// Listeners of the queued utterances, dropped once the queue drains.
+    listeners: RefCell<Vec<EventListener>>,
+}
+
+/// State handle for the [`use_speech_synthesis`] hook.
+#[derive(Clone)]
+pub struct UseSpeechSynthesisHandle {
+    voices: UseStateHandle<Vec<Voice>>,
+    speaking: UseStateHandle<bool>,
+    paused: UseStateHandle<bool>,
+    queue: Rc<Queue>,
+}
+
+impl UseSpeechSynthesisHandle {
+    /// Whether the Web Speech API can speak.
+    pub fn is_supported(&self) -> bool {
+        synthesis().is_some()
+    }
+
+    /// The available voices. Often empty on the first render: browsers load
+    /// them asynchronously.
+    pub fn voices(&self) -> &[Voice] {
+        &self.voices
+    }
+
+    /// Whether an utterance spoken through this handle is queued or speaking.
+    pub fn speaking(&self) -> bool {
+        *self.speaking
+    }
+
+    /// Whether speech is paused.
+    pub fn paused(&self) -> bool {
+        *self.paused
+    }
+
+    /// Queue `text` to be spoken after anything already queued.
+    pub fn speak(&self, text: &str, options: SpeakOptions) {
+        let Some(synthesis) = synthesis() else {
+            return;
+        };
+        let Ok(utterance) = SpeechSynthesisUtterance::new_with_text(text) else {
+            return;
+        };
+        if let Some(lang) = &options.lang {
+            utterance.set_lang(lang);
+        }
+        if let Some(uri) = &options.voice {
+            let voice = voices(&synthesis)
+                .into_iter()
+                .find(|voice| &voice.voice_uri() == uri);
+            utterance.set_voice(voice.as_ref());
+        }
+        utterance.set_rate(options.rate);
+        utterance.set_pitch(options.pitch);
+        utterance.set_volume(options.volume);
+
+        let queue = &self.queue;
+        if queue.pending.get() == 0 {
+            queue.listeners.borrow_mut().clear();
+        }
+        let done = {
+            let queue = queue.clone();
+            let speaking = self.speaking.clone();
+            let paused = self.paused.clone();
+            move |_: &web_sys::Event| {
+                let pending = queue.pending.get().saturating_sub(1);
+                queue.pending.set(pending);
+                if pending == 0 {
+                    queue.listeners.borrow_mut().clear();
+                    speaking.set(false);
+                    paused.set(false);
+                }
+            }
+        };
+        let mut listeners = queue.listeners.borrow_mut();
+        listeners.push(EventListener::new(&utterance, "end", done.clone()));
+        // Also fired for utterances dropped by `cancel()`.
+        listeners.push(EventListener::new(&utterance, "error", done));
+        for (event, value) in [("pause", true), ("resume", false)] {
+            let paused = self.paused.clone();
+            listeners.push(EventListener::new(&utterance, event, move |_| {
+                paused.set(value)
+            }));
+        }
+        drop(listeners);
+
+        queue.pending.set(queue.pending.get() + 1);
+        self.speaking.set(true);
+        synthesis.speak(&utterance);
+    }
+
+    /// Pause speaking.
+    pub fn pause(&self) {
+        if let Some(synthesis) = synthesis() {
+            synthesis.pause();
+        }
+    }
+
+    /// Continue after [`pause`](Self::pause).
+    pub fn resume(&self) {
+        if let Some(synthesis) = synthesis() {
+            synthesis.resume();
+        }
+    }
+
+    /// Stop speaking and clear the queue.
+    pub fn cancel(&self) {
+        if let Some(synthesis) = synthesis() {
+            synthesis.cancel();
+        }
+        self.queue.pending.set(0);
+        self.speaking.set(false);
+        self.paused.set(false);
+    }
+}
+
+impl PartialEq for UseSpeechSynthesisHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.voices == *other.voices
+            && *self.speaking == *other.speaking
+            && *self.paused == *other.paused
+            && Rc::ptr_eq(&self.queue, &other.queue)
+    }
+}
+
+/// A hook for the speech synthesis part of the Web Speech API, e.g. for
+/// read-aloud accessibility features: lists the available voices, speaks text
+/// with a chosen voice, rate and pitch, and tracks whether it is speaking.
+///
+/// Voices load asynchronously; the list updates on `voiceschanged`. Speech
+/// queued through the handle is cancelled on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     article: AttrValue,
+/// }
+///
+/// #[function_component(ReadAloud)]
+/// fn read_aloud(props: &Props) -> Html {
+///     let speech = use_speech_synthesis();
+///
+///     let onclick = {
+///         let speech = speech.clone();
+///         let article = props.article.clone();
+///         Callback::from(move |_| {
+///             if speech.speaking() {
+///                 speech.cancel();
+///             } else {
+///                 let voice = speech.voices().iter().find(|v| v.lang.starts_with("en")).map(|v| v.uri.clone());
+///                 speech.speak(&article, SpeakOptions { voice, rate: 1.1, ..Default::default() });
+///             }
+///         })
+///     };
+///
+///     html! {
+///         if speech.is_supported() {
+///             <button {onclick}>{ if speech.speaking() { "Stop" } else { "Read aloud" } }</button>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_speech_synthesis() -> UseSpeechSynthesisHandle {
+    let voices = use_state_eq(|| {
+        synthesis()
+            .map(|synthesis| voice_list(&synthesis))
+            .unwrap_or_default()
+    });
+    let speaking = use_state_eq(|| false);
+    let paused = use_state_eq(|| false);
+    let queue = use_memo((), |_| Queue::default());
+
+    {
+        let voices = voices.clone();
+        let queue = queue.clone();
+        use_effect_with((), move |_| {
+            let listener = synthesis().map(|target| {
+                let synthesis = target.clone();
+                // Catch voices loaded since the first render.
+                voices.set(voice_list(&synthesis));
+                EventListener::new(&target, "voiceschanged", move |_| {
+                    voices.set(voice_list(&synthesis));
+                })
+            });
+            move || {
+                drop(listener);
+                queue.listeners.borrow_mut().clear();
+                if queue.pending.get() > 0
+                    && let Some(synthesis) = synthesis()
+                {
+                    synthesis.cancel();
+                }
+            }
+        });
+    }
+
+    UseSpeechSynthesisHandle {
+        voices,
+        speaking,
+        paused,
+        queue,
+    }
+}
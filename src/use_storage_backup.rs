@@ -0,0 +1,125 @@
+#![cfg(feature = "storage")]
+
+use gloo::storage::{LocalStorage, SessionStorage, Storage};
+use serde_json::{Value, json};
+use wasm_bindgen::JsValue;
+use web_sys::{Blob, BlobPropertyBag};
+use yew::prelude::*;
+
+use crate::{
+    STORAGE_NAMESPACE, StorageSnapshotError, UseStorageSnapshotExportHandle,
+    use_download::save_blob, use_storage_snapshot_export,
+};
+
+/// Format version written to backups by [`UseStorageBackupHandle::backup`].
+pub const STORAGE_BACKUP_VERSION: u64 = 1;
+
+/// State handle for the [`use_storage_backup`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseStorageBackupHandle {
+    snapshot: UseStorageSnapshotExportHandle,
+}
+
+impl UseStorageBackupHandle {
+    /// Serialize every `localStorage` and `sessionStorage` key under
+    /// [`STORAGE_NAMESPACE`] into a single JSON blob.
+    pub fn backup(&self) -> String {
+        let backup = json!({
+            "version": STORAGE_BACKUP_VERSION,
+            "namespace": STORAGE_NAMESPACE,
+            "local": self.snapshot.export_from(&LocalStorage::raw()),
+            "session": self.snapshot.export_from(&SessionStorage::raw()),
+        });
+        serde_json::to_string_pretty(&backup).unwrap_or_default()
+    }
+
+    /// Restore a blob produced by [`backup`](Self::backup), replacing the keys
+    /// under the namespace and updating every live storage hook for them.
+    /// A storage area missing from the blob is left untouched.
+    /// Returns the number of keys written or removed.
+    pub fn restore(&self, json: &str) -> Result<usize, StorageSnapshotError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| StorageSnapshotError::Parse(e.to_string()))?;
+        let Value::Object(backup) = value else {
+            return Err(StorageSnapshotError::NotAnObject);
+        };
+        let area = |name: &str| match backup.get(name) {
+            Some(Value::Object(entries)) => Ok(Some(entries)),
+            None => Ok(None),
+            Some(_) => Err(StorageSnapshotError::NotAnObject),
+        };
+        let (local, session) = (area("local")?, area("session")?);
+
+        let mut count = 0;
+        for (storage, entries) in [
+            (LocalStorage::raw(), local),
+            (SessionStorage::raw(), session),
+        ] {
+            if let Some(entries) = entries {
+                count += self.snapshot.import_into(&storage, entries)?;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Offer the [`backup`](Self::backup) to the user as a file download.
+    pub fn download(&self, filename: &str) -> Result<(), JsValue> {
+        let parts: js_sys::Array = [JsValue::from_str(&self.backup())].iter().collect();
+        let options = BlobPropertyBag::new();
+        options.set_type("application/json");
+        let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+        save_blob(&blob, filename)
+    }
+}
+
+/// A hook to export all storage under the crate's namespace,
+/// [`STORAGE_NAMESPACE`], to a single JSON blob (for "export my settings" and
+/// support workflows) and restore it.
+///
+/// Both `localStorage` and `sessionStorage` are included: the default keys of
+/// this crate's storage hooks, and your own keys prefixed with
+/// [`STORAGE_NAMESPACE`]. Like [`use_storage_snapshot_export`], which it builds
+/// on, [`restore`](UseStorageBackupHandle::restore) also updates the live
+/// storage hooks of the current tab.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Settings)]
+/// fn settings() -> Html {
+///     let theme = use_local_storage_default::<String>(format!("{STORAGE_NAMESPACE}theme"));
+///     let backup = use_storage_backup();
+///
+///     let ondownload = {
+///         let backup = backup.clone();
+///         Callback::from(move |_| {
+///             let _ = backup.download("myapp-settings.json");
+///         })
+///     };
+///     let onrestore = {
+///         let backup = backup.clone();
+///         Callback::from(move |e: Event| {
+///             let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+///             let _ = backup.restore(&input.value());
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <p>{ format!("Theme: {}", *theme) }</p>
+///             <button onclick={ondownload}>{ "Export my settings" }</button>
+///             <textarea placeholder="Paste a backup to restore" onchange={onrestore} />
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_backup() -> UseStorageBackupHandle {
+    let snapshot = use_storage_snapshot_export(STORAGE_NAMESPACE.to_string());
+
+    UseStorageBackupHandle { snapshot }
+}
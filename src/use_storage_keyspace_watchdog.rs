@@ -0,0 +1,213 @@
+#![cfg(feature = "storage")]
+
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+    timers::callback::Interval,
+    utils::window,
+};
+use log::warn;
+use std::{cell::Cell, rc::Rc, time::Duration};
+use yew::prelude::*;
+
+/// Options for the [`use_storage_keyspace_watchdog`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyspaceWatchdogOptions {
+    /// Key prefixes to total separately, e.g. one per feature
+    /// (`"drafts:"`, `"cache:"`). Keys matching several count toward the
+    /// longest prefix; the rest are reported as unprefixed.
+    pub prefixes: Vec<String>,
+    /// Assumed `localStorage` quota. Browsers allow about 5 MiB per origin,
+    /// counted in UTF-16 code units, so the default is 10 MiB.
+    pub quota_bytes: usize,
+    /// Log a warning once usage exceeds this fraction of the quota.
+    pub warn_ratio: f64,
+    /// Number of largest keys to list.
+    pub top_keys: usize,
+    /// Rescan this often to catch writes that fire no `storage` event
+    /// (writes from this tab).
+    pub interval: Duration,
+    /// Whether to scan at all; defaults to debug builds only, so the hook can
+    /// stay mounted in production at no cost.
+    pub enabled: bool,
+}
+
+impl Default for KeyspaceWatchdogOptions {
+    fn default() -> Self {
+        Self {
+            prefixes: Vec::new(),
+            quota_bytes: 10 * 1024 * 1024,
+            warn_ratio: 0.8,
+            top_keys: 10,
+            interval: Duration::from_secs(5),
+            enabled: cfg!(debug_assertions),
+        }
+    }
+}
+
+/// Usage of one key prefix in a [`KeyspaceReport`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamespaceUsage {
+    pub prefix: String,
+    pub bytes: usize,
+    pub keys: usize,
+}
+
+/// Usage of a single key in a [`KeyspaceReport`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyUsage {
+    pub key: String,
+    pub bytes: usize,
+}
+
+/// `localStorage` usage reported by [`use_storage_keyspace_watchdog`]. Sizes
+/// are bytes of UTF-16 keys and values, as browsers count them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyspaceReport {
+    pub total_bytes: usize,
+    pub quota_bytes: usize,
+    /// Usage per [`KeyspaceWatchdogOptions::prefixes`] entry, in that order.
+    pub namespaces: Vec<NamespaceUsage>,
+    /// Usage of keys matching none of the prefixes.
+    pub unprefixed: NamespaceUsage,
+    /// The largest keys, largest first.
+    pub largest: Vec<KeyUsage>,
+}
+
+impl KeyspaceReport {
+    /// Fraction of the quota in use.
+    pub fn usage_ratio(&self) -> f64 {
+        if self.quota_bytes == 0 {
+            return 0.0;
+        }
+        self.total_bytes as f64 / self.quota_bytes as f64
+    }
+}
+
+fn utf16_bytes(text: &str) -> usize {
+    text.encode_utf16().count() * 2
+}
+
+fn scan(options: &KeyspaceWatchdogOptions) -> KeyspaceReport {
+    let storage = LocalStorage::raw();
+    let mut namespaces: Vec<NamespaceUsage> = options
+        .prefixes
+        .iter()
+        .map(|prefix| NamespaceUsage {
+            prefix: prefix.clone(),
+            ..Default::default()
+        })
+        .collect();
+    let mut unprefixed = NamespaceUsage::default();
+    let mut keys = Vec::new();
+    for i in 0..storage.length().unwrap_or(0) {
+        let Some(key) = storage.key(i).ok().flatten() else {
+            continue;
+        };
+        let value = storage.get_item(&key).ok().flatten().unwrap_or_default();
+        let bytes = utf16_bytes(&key) + utf16_bytes(&value);
+        let namespace = namespaces
+            .iter_mut()
+            .filter(|namespace| key.starts_with(&namespace.prefix))
+            .max_by_key(|namespace| namespace.prefix.len())
+            .unwrap_or(&mut unprefixed);
+        namespace.bytes += bytes;
+        namespace.keys += 1;
+        keys.push(KeyUsage { key, bytes });
+    }
+    keys.sort_by_key(|k| std::cmp::Reverse(k.bytes));
+    keys.truncate(options.top_keys);
+    KeyspaceReport {
+        total_bytes: namespaces.iter().map(|n| n.bytes).sum::<usize>() + unprefixed.bytes,
+        quota_bytes: options.quota_bytes,
+        namespaces,
+        unprefixed,
+        largest: keys,
+    }
+}
+
+/// A development hook that reports how much of `localStorage` each feature's
+/// key prefix uses and lists the largest keys, to find what is bloating
+/// storage before users hit `QuotaExceededError`.
+///
+/// The report is refreshed on `storage` events and every
+/// [`KeyspaceWatchdogOptions::interval`]. A warning is logged when usage
+/// crosses [`KeyspaceWatchdogOptions::warn_ratio`] of the quota. By default
+/// the hook only scans in debug builds and otherwise returns an empty report.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(StorageDebugPanel)]
+/// fn storage_debug_panel() -> Html {
+///     let report = use_storage_keyspace_watchdog(KeyspaceWatchdogOptions {
+///         prefixes: vec!["drafts:".to_string(), "cache:".to_string()],
+///         ..Default::default()
+///     });
+///
+///     html! {
+///         <details>
+///             <summary>{ format!("localStorage: {:.0}% used", report.usage_ratio() * 100.0) }</summary>
+///             <ul>
+///                 { for report.namespaces.iter().map(|n| html! {
+///                     <li>{ format!("{}: {} KiB in {} keys", n.prefix, n.bytes / 1024, n.keys) }</li>
+///                 }) }
+///             </ul>
+///             <ol>
+///                 { for report.largest.iter().map(|k| html! {
+///                     <li>{ format!("{} ({} KiB)", k.key, k.bytes / 1024) }</li>
+///                 }) }
+///             </ol>
+///         </details>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_keyspace_watchdog(options: KeyspaceWatchdogOptions) -> KeyspaceReport {
+    let report = use_state_eq(KeyspaceReport::default);
+
+    {
+        let report = report.clone();
+        use_effect_with(options, move |options| {
+            if !options.enabled {
+                report.set(KeyspaceReport::default());
+            }
+            let refresh: Option<Rc<dyn Fn()>> = options.enabled.then(|| {
+                let options = options.clone();
+                let warned = Cell::new(false);
+                Rc::new(move || {
+                    let scanned = scan(&options);
+                    let over = scanned.usage_ratio() >= options.warn_ratio;
+                    if over && !warned.get() {
+                        warn!(
+                            "localStorage is {:.0}% full ({} of {} bytes); largest key: {:?}",
+                            scanned.usage_ratio() * 100.0,
+                            scanned.total_bytes,
+                            scanned.quota_bytes,
+                            scanned.largest.first().map(|k| &k.key),
+                        );
+                    }
+                    warned.set(over);
+                    report.set(scanned);
+                }) as Rc<dyn Fn()>
+            });
+            let watchers = refresh.map(|refresh| {
+                refresh();
+                let millis = u32::try_from(options.interval.as_millis()).unwrap_or(u32::MAX);
+                let interval = {
+                    let refresh = refresh.clone();
+                    Interval::new(millis, move || refresh())
+                };
+                let listener = EventListener::new(&window(), "storage", move |_| refresh());
+                (interval, listener)
+            });
+            move || drop(watchers)
+        });
+    }
+
+    (*report).clone()
+}
@@ -0,0 +1,177 @@
+#![cfg(feature = "storage")]
+
+use gloo::storage::{LocalStorage, Storage};
+use log::info;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    rc::Rc,
+};
+use web_sys::StorageEvent;
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+use crate::storage::{StorageOptions, debounce, notify};
+
+fn load<T: DeserializeOwned>(keys: &[String]) -> BTreeMap<String, Option<T>> {
+    keys.iter()
+        .map(|key| (key.clone(), LocalStorage::get(key).ok()))
+        .collect()
+}
+
+/// State handle for the [`use_storage_listen_many`] hook.
+pub struct UseStorageListenManyHandle<T> {
+    inner: UseStateHandle<BTreeMap<String, Option<T>>>,
+    keys: Rc<Vec<String>>,
+}
+
+impl<T> UseStorageListenManyHandle<T> {
+    /// The stored value of `key`, if it is watched, present and valid.
+    pub fn get(&self, key: &str) -> Option<&T> {
+        self.inner.get(key)?.as_ref()
+    }
+
+    /// Store `value` under `key` and notify every listening hook in this tab.
+    pub fn set(&self, key: &str, value: T)
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        if LocalStorage::set(key, value).is_ok() {
+            notify(&LocalStorage::raw(), &[key.to_string()]);
+            self.reload();
+        }
+    }
+
+    /// Delete `key` and notify every listening hook in this tab.
+    pub fn delete(&self, key: &str)
+    where
+        T: DeserializeOwned,
+    {
+        LocalStorage::delete(key);
+        notify(&LocalStorage::raw(), &[key.to_string()]);
+        self.reload();
+    }
+
+    fn reload(&self)
+    where
+        T: DeserializeOwned,
+    {
+        self.inner.set(load(&self.keys));
+    }
+}
+
+impl<T> Deref for UseStorageListenManyHandle<T> {
+    type Target = BTreeMap<String, Option<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseStorageListenManyHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseStorageListenManyHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+/// A hook that mirrors several `localStorage` keys with a single `storage`
+/// event listener.
+///
+/// Returns a map of every key to its deserialized value (`None` when absent or
+/// invalid), updated when any of the keys changes in another tab or through a
+/// listening hook in this one. Use `serde_json::Value` for `T` to mirror keys
+/// of different types.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Settings)]
+/// fn settings() -> Html {
+///     let settings = use_storage_listen_many::<serde_json::Value>(vec![
+///         "theme".to_string(),
+///         "font-size".to_string(),
+///         "locale".to_string(),
+///     ]);
+///
+///     html! {
+///         <dl>
+///             { for settings.iter().map(|(key, value)| html! {
+///                 <>
+///                     <dt>{ key }</dt>
+///                     <dd>{ value.as_ref().map(|v| v.to_string()).unwrap_or_default() }</dd>
+///                 </>
+///             }) }
+///         </dl>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_listen_many<T>(keys: Vec<String>) -> UseStorageListenManyHandle<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    use_storage_listen_many_with_options(keys, StorageOptions::default())
+}
+
+/// [`use_storage_listen_many`] with [`StorageOptions`], e.g. to debounce
+/// `storage` events when another tab writes the keys rapidly.
+#[hook]
+pub fn use_storage_listen_many_with_options<T>(
+    keys: Vec<String>,
+    options: StorageOptions,
+) -> UseStorageListenManyHandle<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    let inner = use_state(|| load::<T>(&keys));
+    let keys = use_memo(keys, |keys| keys.clone());
+    let pending = use_mut_ref(|| None);
+
+    {
+        let inner = inner.clone();
+        use_effect_with(keys.clone(), move |keys| {
+            let watched: BTreeSet<&String> = keys.iter().collect();
+            if !watched.into_iter().eq(inner.keys()) {
+                inner.set(load(keys));
+            }
+        });
+    }
+    {
+        let inner = inner.clone();
+        let keys = keys.clone();
+        use_event_with_window("storage", move |e: StorageEvent| {
+            if Some(LocalStorage::raw()) != e.storage_area() {
+                return;
+            }
+            // A `None` key means the storage was cleared.
+            let watched = e.key().is_none_or(|k| keys.contains(&k));
+            if watched {
+                info!("Storage event for keys: {:?}", e.key());
+                let keys = keys.clone();
+                let inner = inner.clone();
+                debounce(&pending, options.listen_debounce, move || {
+                    inner.set(load(&keys));
+                });
+            }
+        });
+    }
+
+    UseStorageListenManyHandle { inner, keys }
+}
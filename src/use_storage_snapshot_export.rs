@@ -0,0 +1,125 @@
+#![cfg(feature = "storage")]
+
+use gloo::storage::{LocalStorage, Storage};
+use std::{fmt, rc::Rc};
+use yew::prelude::*;
+
+use crate::storage::{notify, restore, snapshot};
+
+/// Errors returned by [`UseStorageSnapshotExportHandle::import`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum StorageSnapshotError {
+    /// The snapshot is not valid JSON.
+    Parse(String),
+    /// The snapshot is valid JSON but not an object of `key: value` pairs.
+    NotAnObject,
+    /// Writing to `localStorage` failed (e.g. quota exceeded).
+    Write(String),
+}
+
+impl fmt::Display for StorageSnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "invalid snapshot: {e}"),
+            Self::NotAnObject => write!(f, "snapshot must be a JSON object"),
+            Self::Write(e) => write!(f, "failed to write snapshot: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageSnapshotError {}
+
+/// State handle for the [`use_storage_snapshot_export`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseStorageSnapshotExportHandle {
+    namespace: Rc<String>,
+}
+
+impl UseStorageSnapshotExportHandle {
+    /// Serialize every `localStorage` key under the namespace to a JSON object.
+    pub fn export(&self) -> String {
+        let snapshot = self.export_from(&LocalStorage::raw());
+        serde_json::to_string_pretty(&snapshot).unwrap_or_default()
+    }
+
+    /// Replace the keys under the namespace with those in `json`, as produced by
+    /// [`export`](Self::export). Keys outside the namespace are ignored, and
+    /// live storage hooks for the imported keys are updated.
+    /// Returns the number of keys written or removed.
+    pub fn import(&self, json: &str) -> Result<usize, StorageSnapshotError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| StorageSnapshotError::Parse(e.to_string()))?;
+        let serde_json::Value::Object(entries) = value else {
+            return Err(StorageSnapshotError::NotAnObject);
+        };
+        self.import_into(&LocalStorage::raw(), &entries)
+    }
+
+    /// The keys under the namespace in `storage`, e.g. `sessionStorage`.
+    pub(crate) fn export_from(
+        &self,
+        storage: &web_sys::Storage,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        snapshot(storage, &self.namespace)
+    }
+
+    /// Replace the keys under the namespace in `storage` with `entries` and
+    /// update the live storage hooks for them.
+    pub(crate) fn import_into(
+        &self,
+        storage: &web_sys::Storage,
+        entries: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<usize, StorageSnapshotError> {
+        let changed =
+            restore(storage, &self.namespace, entries).map_err(StorageSnapshotError::Write)?;
+        notify(storage, &changed);
+        Ok(changed.len())
+    }
+}
+
+/// A utility hook to back up and restore app data kept in `localStorage`.
+///
+/// `namespace` is a key prefix: give the keys of your storage hooks a common
+/// prefix (e.g. `"myapp:"`) and they are exported and imported together.
+/// Values are exported as the JSON the storage hooks write.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Backup)]
+/// fn backup() -> Html {
+///     let snapshot = use_storage_snapshot_export("myapp:".to_string());
+///     let exported = use_state(String::new);
+///
+///     let onexport = {
+///         let snapshot = snapshot.clone();
+///         let exported = exported.clone();
+///         Callback::from(move |_| exported.set(snapshot.export()))
+///     };
+///     let onimport = {
+///         let snapshot = snapshot.clone();
+///         let exported = exported.clone();
+///         Callback::from(move |_| {
+///             let _ = snapshot.import(&exported);
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onexport}>{ "Export" }</button>
+///             <button onclick={onimport}>{ "Import" }</button>
+///             <pre>{ &*exported }</pre>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_snapshot_export(namespace: String) -> UseStorageSnapshotExportHandle {
+    let namespace = use_memo(namespace, |namespace| namespace.clone());
+
+    UseStorageSnapshotExportHandle { namespace }
+}
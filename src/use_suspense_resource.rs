@@ -0,0 +1,95 @@
+use std::{future::Future, rc::Rc};
+use yew::{
+    prelude::*,
+    suspense::{Suspension, SuspensionResult},
+};
+use yew_hooks::{use_latest, use_update};
+
+use crate::use_query::{QueryEntry, entry};
+
+/// A hook that suspends the component until the resource `key` is loaded,
+/// so it can be used with `?` inside a `<Suspense>` boundary instead of
+/// rendering a loading branch.
+///
+/// Resources share the [`use_query`](crate::use_query) cache: a key loaded by
+/// either hook is not fetched again, [`UseQueryHandle::mutate`](crate::UseQueryHandle::mutate)
+/// updates both, and [`invalidate_query`](crate::invalidate_query) refetches
+/// it. A refetch keeps rendering the cached data instead of suspending again.
+/// A failed load is returned as the inner `Err`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// async fn fetch_greeting() -> Result<String, String> {
+///     Ok("Hello".to_string())
+/// }
+///
+/// #[function_component(Greeting)]
+/// fn greeting() -> HtmlResult {
+///     let greeting = use_suspense_resource("greeting".to_string(), fetch_greeting)?;
+///
+///     Ok(match greeting {
+///         Ok(greeting) => html! { <p>{ &*greeting }</p> },
+///         Err(error) => html! { <p>{ format!("Failed: {error}") }</p> },
+///     })
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onrefresh = Callback::from(|_| invalidate_query("greeting"));
+///
+///     html! {
+///         <>
+///             <button onclick={onrefresh}>{ "Refresh" }</button>
+///             <Suspense fallback={html! { <p>{ "Loading..." }</p> }}>
+///                 <Greeting />
+///             </Suspense>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_suspense_resource<T, E, F, Fut>(
+    key: String,
+    fetcher: F,
+) -> SuspensionResult<Result<Rc<T>, Rc<E>>>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let update = use_update();
+    let entry = use_memo(key.clone(), |key| entry::<T, E>(key));
+    let entry: Rc<QueryEntry<T, E>> = (*entry).clone();
+    let fetcher = use_latest(fetcher);
+
+    {
+        let entry = entry.clone();
+        use_effect_with(key, move |_| {
+            let id = entry.subscribe(update);
+            move || entry.unsubscribe(id)
+        });
+    }
+
+    let data = entry.data.borrow().clone();
+    let error = entry.error.borrow().clone();
+    let needs_fetch = entry.invalidated.get() || (data.is_none() && error.is_none());
+    if needs_fetch && !entry.loading.get() {
+        entry.fetch((*fetcher.current())());
+    }
+
+    match (data, error) {
+        (Some(data), _) => Ok(Ok(data)),
+        (None, Some(error)) if !entry.loading.get() => Ok(Err(error)),
+        _ => {
+            let (suspension, handle) = Suspension::new();
+            entry.suspensions.borrow_mut().push(handle);
+            Err(suspension)
+        }
+    }
+}
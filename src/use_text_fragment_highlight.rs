@@ -0,0 +1,450 @@
+use gloo::utils::{document, window};
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, Node, PerformanceEntry, Range, ScrollLogicalPosition};
+use yew::prelude::*;
+
+use crate::{js, use_reduced_motion_scroll::scroll_into_view};
+
+/// `NodeFilter.SHOW_TEXT`.
+const SHOW_TEXT: u32 = 0x4;
+
+/// Selections longer than this many words are linked by their first and last
+/// words (`text=start,end`) instead of in full.
+const LINK_CONTEXT_WORDS: usize = 5;
+
+/// Options for the [`use_text_fragment_highlight`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextFragmentHighlightOptions {
+    /// Name of the CSS custom highlight, styled with `::highlight(<name>)`.
+    pub highlight_name: String,
+    /// Scroll the first match into view.
+    pub scroll: bool,
+}
+
+impl Default for TextFragmentHighlightOptions {
+    fn default() -> Self {
+        Self {
+            highlight_name: "text-fragment".to_string(),
+            scroll: true,
+        }
+    }
+}
+
+/// A text directive, `#:~:text=[prefix-,]start[,end][,-suffix]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextFragment {
+    /// Text that must immediately precede the match.
+    pub prefix: Option<String>,
+    /// The matched text, or where it starts when `end` is set.
+    pub start: String,
+    /// Where the matched text ends.
+    pub end: Option<String>,
+    /// Text that must immediately follow the match.
+    pub suffix: Option<String>,
+}
+
+impl TextFragment {
+    /// A fragment matching `start` exactly.
+    pub fn new(start: impl Into<String>) -> Self {
+        Self {
+            start: start.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parse the value of a `text=` directive.
+    pub fn parse(directive: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = directive.split(',').collect();
+        let prefix = match parts.first() {
+            Some(part) if part.ends_with('-') => {
+                let prefix = parts.remove(0);
+                Some(decode(&prefix[..prefix.len() - 1])?)
+            }
+            _ => None,
+        };
+        let suffix = match parts.last() {
+            Some(part) if part.starts_with('-') => Some(decode(&parts.pop()?[1..])?),
+            _ => None,
+        };
+        let (start, end) = match parts.as_slice() {
+            [start] => (decode(start)?, None),
+            [start, end] => (decode(start)?, Some(decode(end)?)),
+            _ => return None,
+        };
+        (!start.is_empty()).then_some(Self {
+            prefix,
+            start,
+            end,
+            suffix,
+        })
+    }
+
+    /// Every text directive in the fragment directive (`:~:`) of `url`.
+    pub fn from_url(url: &str) -> Vec<Self> {
+        let Some((_, directives)) = url.split_once(":~:") else {
+            return Vec::new();
+        };
+        directives
+            .split('&')
+            .filter_map(|directive| directive.strip_prefix("text="))
+            .filter_map(Self::parse)
+            .collect()
+    }
+
+    /// Format as the value of a `text=` directive.
+    pub fn to_directive(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(prefix) = &self.prefix {
+            parts.push(format!("{}-", encode(prefix)));
+        }
+        parts.push(encode(&self.start));
+        if let Some(end) = &self.end {
+            parts.push(encode(end));
+        }
+        if let Some(suffix) = &self.suffix {
+            parts.push(format!("-{}", encode(suffix)));
+        }
+        parts.join(",")
+    }
+}
+
+fn decode(text: &str) -> Option<String> {
+    js_sys::decode_uri_component(text).ok().map(String::from)
+}
+
+/// Percent-encode `text`, including the `-` that delimits prefixes and suffixes.
+fn encode(text: &str) -> String {
+    String::from(js_sys::encode_uri_component(text)).replace('-', "%2D")
+}
+
+/// Collapse whitespace runs to single spaces and fold ASCII case.
+fn normalize(text: &str) -> Vec<char> {
+    let mut chars = Vec::new();
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if chars.last().is_some_and(|c| *c != ' ') {
+                chars.push(' ');
+            }
+        } else {
+            chars.push(c.to_ascii_lowercase());
+        }
+    }
+    if chars.last() == Some(&' ') {
+        chars.pop();
+    }
+    chars
+}
+
+/// The normalized text content of a subtree, mapped back to DOM positions.
+struct TextIndex {
+    chars: Vec<char>,
+    /// For every char: its text node and UTF-16 start/end offsets in it.
+    positions: Vec<(usize, u32, u32)>,
+    nodes: Vec<Node>,
+}
+
+impl TextIndex {
+    fn new(root: &Node) -> Self {
+        let mut index = Self {
+            chars: Vec::new(),
+            positions: Vec::new(),
+            nodes: Vec::new(),
+        };
+        let Ok(walker) = document().create_tree_walker_with_what_to_show(root, SHOW_TEXT) else {
+            return index;
+        };
+        while let Ok(Some(node)) = walker.next_node() {
+            let text = node.node_value().unwrap_or_default();
+            let n = index.nodes.len();
+            let mut offset = 0;
+            for c in text.chars() {
+                let len = c.len_utf16() as u32;
+                if c.is_whitespace() {
+                    if index.chars.last().is_some_and(|c| *c != ' ') {
+                        index.chars.push(' ');
+                        index.positions.push((n, offset, offset + len));
+                    }
+                } else {
+                    index.chars.push(c.to_ascii_lowercase());
+                    index.positions.push((n, offset, offset + len));
+                }
+                offset += len;
+            }
+            index.nodes.push(node);
+        }
+        index
+    }
+
+    fn find(&self, needle: &[char], from: usize) -> Option<usize> {
+        if needle.is_empty() || from > self.chars.len() {
+            return None;
+        }
+        self.chars[from..]
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .map(|i| i + from)
+    }
+
+    /// The char range matching `fragment`, honoring its prefix and suffix.
+    fn find_fragment(&self, fragment: &TextFragment) -> Option<(usize, usize)> {
+        let start = normalize(&fragment.start);
+        let end = fragment.end.as_deref().map(normalize);
+        let prefix = fragment.prefix.as_deref().map(normalize);
+        let suffix = fragment.suffix.as_deref().map(normalize);
+        let mut from = 0;
+        while let Some(i) = self.find(&start, from) {
+            from = i + 1;
+            let before = self.chars[..i]
+                .strip_suffix(&[' '])
+                .unwrap_or(&self.chars[..i]);
+            if prefix.as_ref().is_some_and(|p| !before.ends_with(p)) {
+                continue;
+            }
+            let j = match &end {
+                Some(end) => match self.find(end, i + start.len()) {
+                    Some(j) => j + end.len(),
+                    None => return None,
+                },
+                None => i + start.len(),
+            };
+            let after = self.chars[j..]
+                .strip_prefix(&[' '])
+                .unwrap_or(&self.chars[j..]);
+            if suffix.as_ref().is_some_and(|s| !after.starts_with(s)) {
+                continue;
+            }
+            return Some((i, j));
+        }
+        None
+    }
+
+    fn range(&self, (start, end): (usize, usize)) -> Option<Range> {
+        let (start_node, start_offset, _) = *self.positions.get(start)?;
+        let (end_node, _, end_offset) = *self.positions.get(end.checked_sub(1)?)?;
+        let range = document().create_range().ok()?;
+        range
+            .set_start(&self.nodes[start_node], start_offset)
+            .ok()?;
+        range.set_end(&self.nodes[end_node], end_offset).ok()?;
+        Some(range)
+    }
+}
+
+/// The URL the page was navigated to. Browsers strip the fragment directive
+/// from `location`, but keep it in the navigation timing entry.
+fn navigation_url() -> String {
+    window()
+        .performance()
+        .and_then(|performance| {
+            performance
+                .get_entries_by_type("navigation")
+                .get(0)
+                .dyn_into()
+                .ok()
+        })
+        .map(|entry: PerformanceEntry| entry.name())
+        .or_else(|| window().location().href().ok())
+        .unwrap_or_default()
+}
+
+/// `CSS.highlights`, where the CSS Custom Highlight API is supported.
+fn highlight_registry() -> Option<JsValue> {
+    js::get(&js::get(&window(), "CSS")?, "highlights")
+}
+
+fn clear(name: &str) {
+    if let Some(registry) = highlight_registry() {
+        let _ = js::call(&registry, "delete", &[name.into()]);
+    }
+}
+
+/// Highlight every matching fragment inside `root`. Returns the number of matches.
+fn apply(root: &Node, fragments: &[TextFragment], options: &TextFragmentHighlightOptions) -> usize {
+    clear(&options.highlight_name);
+    let index = TextIndex::new(root);
+    let ranges: Vec<Range> = fragments
+        .iter()
+        .filter_map(|fragment| index.range(index.find_fragment(fragment)?))
+        .collect();
+    let Some(first) = ranges.first() else {
+        return 0;
+    };
+
+    let highlight = highlight_registry().and_then(|registry| {
+        let constructor: js_sys::Function = js::get(&window(), "Highlight")?.dyn_into().ok()?;
+        let args: js_sys::Array = ranges.iter().collect();
+        let highlight = js_sys::Reflect::construct(&constructor, &args).ok()?;
+        js::call(
+            &registry,
+            "set",
+            &[options.highlight_name.as_str().into(), highlight],
+        )
+        .ok()
+    });
+    if highlight.is_none()
+        && let Ok(Some(selection)) = window().get_selection()
+    {
+        let _ = selection.remove_all_ranges();
+        let _ = selection.add_range(first);
+    }
+
+    if options.scroll {
+        let container = first.start_container().ok();
+        let element = container.and_then(|node| match node.dyn_into::<Element>() {
+            Ok(element) => Some(element),
+            Err(node) => node.parent_element(),
+        });
+        if let Some(element) = element {
+            scroll_into_view(&element, ScrollLogicalPosition::Center);
+        }
+    }
+    ranges.len()
+}
+
+/// State handle for the [`use_text_fragment_highlight`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseTextFragmentHighlightHandle {
+    node: NodeRef,
+    options: Rc<TextFragmentHighlightOptions>,
+    fragments: Rc<Vec<TextFragment>>,
+    matches: UseStateHandle<usize>,
+}
+
+impl UseTextFragmentHighlightHandle {
+    /// The text directives the page was navigated with.
+    pub fn fragments(&self) -> &[TextFragment] {
+        &self.fragments
+    }
+
+    /// How many of the fragments were found and highlighted.
+    pub fn matches(&self) -> usize {
+        *self.matches
+    }
+
+    /// Search and highlight the fragments again, e.g. after content loaded.
+    pub fn highlight(&self) {
+        self.highlight_fragments(&self.fragments);
+    }
+
+    /// Highlight `fragments` instead of the ones from the URL.
+    pub fn highlight_fragments(&self, fragments: &[TextFragment]) {
+        if let Some(root) = self.node.get() {
+            self.matches.set(apply(&root, fragments, &self.options));
+        }
+    }
+
+    /// Remove the highlight.
+    pub fn clear(&self) {
+        clear(&self.options.highlight_name);
+        self.matches.set(0);
+    }
+
+    /// A text fragment for the current selection, if it lies inside the node.
+    pub fn selection_fragment(&self) -> Option<TextFragment> {
+        let root = self.node.get()?;
+        let selection = window().get_selection().ok()??;
+        let anchor = selection.anchor_node()?;
+        if !root.contains(Some(&anchor)) {
+            return None;
+        }
+        let text = String::from(selection.to_string());
+        let words: Vec<&str> = text.split_whitespace().collect();
+        if words.is_empty() {
+            return None;
+        }
+        if words.len() <= LINK_CONTEXT_WORDS * 2 {
+            return Some(TextFragment::new(words.join(" ")));
+        }
+        Some(TextFragment {
+            start: words[..LINK_CONTEXT_WORDS].join(" "),
+            end: Some(words[words.len() - LINK_CONTEXT_WORDS..].join(" ")),
+            ..Default::default()
+        })
+    }
+
+    /// A shareable link to the current page that scrolls to and highlights the
+    /// current selection.
+    pub fn selection_link(&self) -> Option<String> {
+        let fragment = self.selection_fragment()?;
+        let href = window().location().href().ok()?;
+        let base = href
+            .split_once(":~:")
+            .map_or(href.as_str(), |(base, _)| base);
+        let separator = if base.contains('#') { "" } else { "#" };
+        Some(format!(
+            "{base}{separator}:~:text={}",
+            fragment.to_directive()
+        ))
+    }
+}
+
+/// A hook that highlights the text fragments (`#:~:text=`) the page was
+/// navigated with inside `node`, and creates text-fragment links from the
+/// user's selection.
+///
+/// Browsers only match text fragments against the initial document, so they
+/// miss content rendered by the app. This hook searches the node's text on
+/// mount (ASCII case-insensitively, collapsing whitespace), highlights matches
+/// with the CSS Custom Highlight API (falling back to the selection) and
+/// scrolls the first one into view, smoothly unless the user prefers reduced
+/// motion. Style the highlight with `::highlight(text-fragment)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Article)]
+/// fn article() -> Html {
+///     let node = use_node_ref();
+///     let fragments = use_text_fragment_highlight(node.clone(), Default::default());
+///
+///     let onshare = {
+///         let fragments = fragments.clone();
+///         Callback::from(move |_| {
+///             if let Some(link) = fragments.selection_link() {
+///                 log::info!("Share {link}");
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <article ref={node}>{ "Lorem ipsum dolor sit amet." }</article>
+///             <button onclick={onshare}>{ "Link to selection" }</button>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_text_fragment_highlight(
+    node: NodeRef,
+    options: TextFragmentHighlightOptions,
+) -> UseTextFragmentHighlightHandle {
+    let fragments = use_memo((), |_| TextFragment::from_url(&navigation_url()));
+    let options = use_memo(options, |options| options.clone());
+    let matches = use_state(|| 0);
+
+    let handle = UseTextFragmentHighlightHandle {
+        node,
+        options,
+        fragments,
+        matches,
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with(handle.node.clone(), move |_| {
+            if !handle.fragments.is_empty() {
+                handle.highlight();
+            }
+            let name = handle.options.highlight_name.clone();
+            move || clear(&name)
+        });
+    }
+
+    handle
+}
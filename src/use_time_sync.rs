@@ -0,0 +1,171 @@
+use gloo::net::http::Request;
+use std::time::Duration;
+use web_sys::RequestCache;
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_interval;
+
+/// Options for the [`use_time_sync`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSyncOptions {
+    /// Endpoint to sample. Its body may be the server time in milliseconds since
+    /// the Unix epoch; otherwise the `Date` response header is used.
+    pub url: String,
+    /// Requests per sync; the one with the lowest round-trip time wins.
+    pub samples: u32,
+    /// Re-sync this often, to follow client clock drift and adjustments.
+    pub resync_interval: Option<Duration>,
+}
+
+impl Default for TimeSyncOptions {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            samples: 3,
+            resync_interval: Some(Duration::from_secs(10 * 60)),
+        }
+    }
+}
+
+/// A measured client↔server clock offset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeSample {
+    /// Server clock minus client clock, in milliseconds.
+    pub offset: f64,
+    /// Round-trip time of the winning request, in milliseconds; the offset is
+    /// accurate to about half of it.
+    pub rtt: f64,
+}
+
+/// Sample `url` once, compensating for latency by assuming the server read its
+/// clock halfway through the round trip.
+async fn sample(url: &str) -> Option<TimeSample> {
+    let sent = js_sys::Date::now();
+    let response = Request::get(url)
+        .cache(RequestCache::NoStore)
+        .send()
+        .await
+        .ok()?;
+    let received = js_sys::Date::now();
+    let date = response.headers().get("date");
+    let body = response.text().await.unwrap_or_default();
+    let server = match body.trim().parse::<f64>() {
+        Ok(millis) => millis,
+        Err(_) => {
+            let millis = js_sys::Date::parse(&date?);
+            if millis.is_nan() {
+                return None;
+            }
+            // `Date` has one-second resolution; assume the middle of that second.
+            millis + 500.0
+        }
+    };
+    Some(TimeSample {
+        offset: server - (sent + received) / 2.0,
+        rtt: received - sent,
+    })
+}
+
+/// State handle for the [`use_time_sync`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseTimeSyncHandle {
+    sample: UseStateHandle<Option<TimeSample>>,
+    resync: Callback<()>,
+}
+
+impl UseTimeSyncHandle {
+    /// The latest measurement, or `None` until the first sync succeeds.
+    pub fn sample(&self) -> Option<TimeSample> {
+        *self.sample
+    }
+
+    /// Server clock minus client clock in milliseconds (0 until synced).
+    pub fn offset(&self) -> f64 {
+        self.sample.map_or(0.0, |s| s.offset)
+    }
+
+    /// Whether at least one sync succeeded.
+    pub fn is_synced(&self) -> bool {
+        self.sample.is_some()
+    }
+
+    /// The server's current time, in milliseconds since the Unix epoch.
+    pub fn now(&self) -> f64 {
+        js_sys::Date::now() + self.offset()
+    }
+
+    /// Sync again now.
+    pub fn resync(&self) {
+        self.resync.emit(());
+    }
+}
+
+/// A hook that estimates the offset between the client's and the server's
+/// clocks by sampling a time endpoint, so deadlines and countdowns can be shown
+/// in server time even when the user's clock is wrong.
+///
+/// Re-renders when a new offset is measured. A failed sync keeps the previous
+/// measurement.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(AuctionEnd)]
+/// fn auction_end(props: &AuctionEndProps) -> Html {
+///     let time = use_time_sync(TimeSyncOptions {
+///         url: "/api/time".to_string(),
+///         ..Default::default()
+///     });
+///     let seconds_left = ((props.ends_at - time.now()) / 1000.0).max(0.0);
+///
+///     html! { <p>{ format!("Ends in {seconds_left:.0}s") }</p> }
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct AuctionEndProps {
+///     ends_at: f64,
+/// }
+/// ```
+#[hook]
+pub fn use_time_sync(options: TimeSyncOptions) -> UseTimeSyncHandle {
+    let sample = use_state(|| None);
+    let options = use_memo(options, |options| options.clone());
+
+    let resync = {
+        let sample = sample.clone();
+        use_callback(options.clone(), move |_, options| {
+            let sample = sample.clone();
+            let options = options.clone();
+            spawn_local(async move {
+                let mut best: Option<TimeSample> = None;
+                for _ in 0..options.samples.max(1) {
+                    if let Some(s) = self::sample(&options.url).await
+                        && best.is_none_or(|b| s.rtt < b.rtt)
+                    {
+                        best = Some(s);
+                    }
+                }
+                if best.is_some() {
+                    sample.set(best);
+                }
+            });
+        })
+    };
+
+    {
+        let resync = resync.clone();
+        use_effect_with(resync, move |resync| resync.emit(()));
+    }
+    {
+        let resync = resync.clone();
+        let millis = options
+            .resync_interval
+            .map_or(0, |i| u32::try_from(i.as_millis()).unwrap_or(u32::MAX));
+        use_interval(move || resync.emit(()), millis);
+    }
+
+    UseTimeSyncHandle { sample, resync }
+}
@@ -0,0 +1,49 @@
+use yew::prelude::*;
+
+use crate::{js, use_page_visibility};
+
+/// The IANA time zone of the browser, e.g. `"Europe/Berlin"`.
+fn time_zone() -> String {
+    let format = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new());
+    js::get(&format.resolved_options(), "timeZone")
+        .and_then(|zone| zone.as_string())
+        .unwrap_or_else(|| "UTC".to_string())
+}
+
+/// A sensor hook returning the user's IANA time zone (e.g.
+/// `"America/New_York"`) from `Intl.DateTimeFormat`, so displayed times can
+/// follow a laptop that travels.
+///
+/// Browsers fire no event when the system time zone changes, so it is
+/// re-checked whenever the page becomes visible again.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(MeetingTime)]
+/// fn meeting_time() -> Html {
+///     let time_zone = use_timezone();
+///
+///     html! { <p>{ format!("Times are shown in {time_zone}") }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_timezone() -> String {
+    let zone = use_state_eq(time_zone);
+    let visibility = use_page_visibility();
+
+    {
+        let zone = zone.clone();
+        use_effect_with(visibility, move |visibility| {
+            if visibility.is_visible() {
+                zone.set(time_zone());
+            }
+        });
+    }
+
+    (*zone).clone()
+}
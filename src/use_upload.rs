@@ -0,0 +1,208 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use web_sys::Blob;
+use yew::prelude::*;
+use yew_hooks::use_update;
+
+use crate::upload::{self, Upload, UploadOptions, UploadStatus};
+
+/// Weight of the latest sample in the smoothed transfer rate.
+const RATE_SMOOTHING: f64 = 0.3;
+
+struct Transfer {
+    options: RefCell<UploadOptions>,
+    status: RefCell<UploadStatus>,
+    active: RefCell<Option<Upload>>,
+    /// Time and bytes of the previous progress event.
+    last_sample: Cell<Option<(f64, f64)>>,
+    /// Smoothed bytes per second.
+    rate: Cell<Option<f64>>,
+    /// Bumped on every change, so handles from different renders compare unequal.
+    version: Cell<u64>,
+    update: Rc<dyn Fn()>,
+}
+
+impl Transfer {
+    fn set_status(&self, status: UploadStatus) {
+        *self.status.borrow_mut() = status;
+        self.version.set(self.version.get() + 1);
+        (self.update)();
+    }
+
+    fn progress(&self, loaded: f64, total: f64) {
+        let now = js_sys::Date::now();
+        if let Some((time, bytes)) = self.last_sample.get()
+            && now > time
+        {
+            let sample = (loaded - bytes) / (now - time) * 1000.0;
+            let rate = match self.rate.get() {
+                Some(rate) => rate + RATE_SMOOTHING * (sample - rate),
+                None => sample,
+            };
+            self.rate.set(Some(rate));
+        }
+        self.last_sample.set(Some((now, loaded)));
+        self.set_status(UploadStatus::Uploading { loaded, total });
+    }
+
+    fn start(self: &Rc<Self>, file: &Blob, filename: &str) {
+        self.abort();
+        self.last_sample.set(Some((js_sys::Date::now(), 0.0)));
+        self.rate.set(None);
+        self.set_status(UploadStatus::Uploading {
+            loaded: 0.0,
+            total: file.size(),
+        });
+
+        let on_progress = {
+            let transfer = self.clone();
+            move |loaded, total| transfer.progress(loaded, total)
+        };
+        let on_done = {
+            let transfer = self.clone();
+            move |status| {
+                transfer.active.take();
+                transfer.set_status(status);
+            }
+        };
+        let options = self.options.borrow().clone();
+        match upload::start(&options, file, filename, on_progress, on_done) {
+            Ok(upload) => *self.active.borrow_mut() = Some(upload),
+            Err(e) => self.set_status(UploadStatus::Failed(e)),
+        }
+    }
+
+    fn abort(&self) {
+        let active = self.active.borrow_mut().take();
+        if let Some(upload) = active {
+            upload.abort();
+            self.set_status(UploadStatus::Cancelled);
+        }
+    }
+}
+
+/// State handle for the [`use_upload`] hook.
+#[derive(Clone)]
+pub struct UseUploadHandle {
+    transfer: Rc<Transfer>,
+    version: u64,
+}
+
+impl UseUploadHandle {
+    /// Upload `file` (a `File` or any `Blob`) as `filename`, aborting the
+    /// upload in progress.
+    pub fn upload(&self, file: &Blob, filename: &str) {
+        self.transfer.start(file, filename);
+    }
+
+    /// Abort the upload in progress.
+    pub fn abort(&self) {
+        self.transfer.abort();
+    }
+
+    /// Status of the latest upload; [`UploadStatus::Queued`] before the first.
+    pub fn status(&self) -> UploadStatus {
+        self.transfer.status.borrow().clone()
+    }
+
+    /// Progress of the latest upload between 0 and 1, when known.
+    pub fn progress(&self) -> Option<f64> {
+        self.transfer.status.borrow().progress()
+    }
+
+    /// Smoothed transfer rate in bytes per second while uploading.
+    pub fn bytes_per_second(&self) -> Option<f64> {
+        match *self.transfer.status.borrow() {
+            UploadStatus::Uploading { .. } => self.transfer.rate.get(),
+            _ => None,
+        }
+    }
+
+    /// Whether an upload is in progress.
+    pub fn is_uploading(&self) -> bool {
+        self.transfer.active.borrow().is_some()
+    }
+}
+
+impl PartialEq for UseUploadHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.transfer, &other.transfer) && self.version == other.version
+    }
+}
+
+/// A hook that uploads a single `File`/`Blob` as `multipart/form-data` with
+/// reactive progress, transfer rate and abort, which `fetch` cannot report.
+///
+/// Starting a new upload aborts the one in progress, and the upload is
+/// aborted when the component unmounts.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::HtmlInputElement;
+///
+/// #[function_component(AvatarUpload)]
+/// fn avatar_upload() -> Html {
+///     let upload = use_upload(UploadOptions {
+///         url: "/api/avatar".to_string(),
+///         ..Default::default()
+///     });
+///
+///     let onchange = {
+///         let upload = upload.clone();
+///         Callback::from(move |e: Event| {
+///             let input: HtmlInputElement = e.target_unchecked_into();
+///             if let Some(file) = input.files().and_then(|files| files.get(0)) {
+///                 upload.upload(&file, &file.name());
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <input type="file" {onchange} />
+///             if let Some(progress) = upload.progress() {
+///                 <progress value={progress.to_string()} />
+///             }
+///             if let Some(rate) = upload.bytes_per_second() {
+///                 <span>{ format!("{:.0} KB/s", rate / 1024.0) }</span>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_upload(options: UploadOptions) -> UseUploadHandle {
+    let update = use_update();
+    let transfer = use_memo((), |_| Transfer {
+        options: RefCell::new(options.clone()),
+        status: RefCell::new(UploadStatus::Queued),
+        active: RefCell::new(None),
+        last_sample: Cell::new(None),
+        rate: Cell::new(None),
+        version: Cell::new(0),
+        update,
+    });
+    *transfer.options.borrow_mut() = options;
+
+    {
+        let transfer = transfer.clone();
+        use_effect_with((), move |_| {
+            move || {
+                // Aborting runs `on_done` synchronously, which borrows `active`.
+                let active = transfer.active.borrow_mut().take();
+                if let Some(upload) = active {
+                    upload.abort();
+                }
+            }
+        });
+    }
+
+    let version = transfer.version.get();
+    UseUploadHandle { transfer, version }
+}
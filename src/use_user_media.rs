@@ -0,0 +1,290 @@
+use gloo::utils::window;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{MediaDevices, MediaStream, MediaStreamConstraints, MediaStreamTrack};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// Options for the [`use_user_media`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserMediaOptions {
+    /// Capture the microphone.
+    pub audio: bool,
+    /// Capture the camera.
+    pub video: bool,
+    /// The microphone to use, a [`MediaDevice::device_id`](crate::MediaDevice)
+    /// from [`use_media_devices`](crate::use_media_devices); the default one
+    /// otherwise.
+    pub audio_device_id: Option<String>,
+    /// The camera to use; the default one otherwise.
+    pub video_device_id: Option<String>,
+    /// Start capturing on mount. Otherwise capture waits for
+    /// [`start`](UseUserMediaHandle::start).
+    pub immediate: bool,
+}
+
+impl Default for UserMediaOptions {
+    fn default() -> Self {
+        Self {
+            audio: true,
+            video: true,
+            audio_device_id: None,
+            video_device_id: None,
+            immediate: false,
+        }
+    }
+}
+
+impl UserMediaOptions {
+    fn constraints(&self) -> MediaStreamConstraints {
+        let track = |wanted: bool, device_id: &Option<String>| -> JsValue {
+            match device_id {
+                Some(id) if wanted => {
+                    js::object(&[("deviceId", js::object(&[("exact", id.into())]).into())]).into()
+                }
+                _ => wanted.into(),
+            }
+        };
+        let constraints = MediaStreamConstraints::new();
+        constraints.set_audio(&track(self.audio, &self.audio_device_id));
+        constraints.set_video(&track(self.video, &self.video_device_id));
+        constraints
+    }
+}
+
+/// `navigator.mediaDevices`, missing outside secure contexts.
+fn media_devices() -> Option<MediaDevices> {
+    let navigator = window().navigator();
+    js::has(&navigator, "mediaDevices")
+        .then(|| navigator.media_devices().ok())
+        .flatten()
+}
+
+fn stop_tracks(stream: &MediaStream) {
+    for track in stream.get_tracks() {
+        track.unchecked_into::<MediaStreamTrack>().stop();
+    }
+}
+
+/// The captured stream, kept outside of state so it can be stopped on
+/// unmount.
+#[derive(Default)]
+struct Capture {
+    stream: RefCell<Option<MediaStream>>,
+    /// Bumped by every start and stop, so a `getUserMedia` that resolves
+    /// after being superseded stops its stream right away.
+    generation: Cell<u32>,
+    mounted: Cell<bool>,
+}
+
+impl Capture {
+    fn stop(&self) {
+        self.generation.set(self.generation.get().wrapping_add(1));
+        if let Some(stream) = self.stream.take() {
+            stop_tracks(&stream);
+        }
+    }
+}
+
+/// State handle for the [`use_user_media`] hook.
+#[derive(Clone)]
+pub struct UseUserMediaHandle {
+    stream: UseStateHandle<Option<MediaStream>>,
+    pending: UseStateHandle<bool>,
+    error: UseStateHandle<Option<String>>,
+    capture: Rc<Capture>,
+    options: UserMediaOptions,
+}
+
+impl UseUserMediaHandle {
+    /// Whether `getUserMedia` is available; it requires a secure context.
+    pub fn is_supported(&self) -> bool {
+        media_devices().is_some()
+    }
+
+    /// The captured stream, to show in a `<video>` with `srcObject` or send
+    /// over WebRTC.
+    pub fn stream(&self) -> Option<&MediaStream> {
+        self.stream.as_ref()
+    }
+
+    /// Whether the stream is captured.
+    pub fn ready(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Whether capture is starting, e.g. while the permission prompt is
+    /// shown.
+    pub fn pending(&self) -> bool {
+        *self.pending
+    }
+
+    /// The error of the last [`start`](Self::start), e.g. `NotAllowedError`
+    /// when the user denies access or `NotFoundError` without a camera.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Capture with the current options, replacing a running stream. Asks
+    /// for the permission the first time.
+    pub fn start(&self) {
+        let promise = media_devices()
+            .map(|devices| devices.get_user_media_with_constraints(&self.options.constraints()));
+        let Some(Ok(promise)) = promise else {
+            self.error
+                .set(Some("getUserMedia is not supported".to_string()));
+            return;
+        };
+        self.capture.stop();
+        let generation = self.capture.generation.get();
+        let stream = self.stream.clone();
+        let pending = self.pending.clone();
+        let error = self.error.clone();
+        let capture = self.capture.clone();
+        stream.set(None);
+        pending.set(true);
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            // Stopped, restarted or unmounted while starting.
+            if !capture.mounted.get() || capture.generation.get() != generation {
+                if let Ok(captured) = result {
+                    stop_tracks(captured.unchecked_ref());
+                }
+                return;
+            }
+            match result {
+                Ok(captured) => {
+                    let captured: MediaStream = captured.unchecked_into();
+                    *capture.stream.borrow_mut() = Some(captured.clone());
+                    stream.set(Some(captured));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(
+                    js::get(&e, "name")
+                        .or_else(|| js::get(&e, "message"))
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                )),
+            }
+            pending.set(false);
+        });
+    }
+
+    /// Stop all tracks, turning the camera and microphone off.
+    pub fn stop(&self) {
+        self.capture.stop();
+        self.stream.set(None);
+        self.pending.set(false);
+    }
+}
+
+impl PartialEq for UseUserMediaHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.stream == *other.stream
+            && *self.pending == *other.pending
+            && *self.error == *other.error
+            && Rc::ptr_eq(&self.capture, &other.capture)
+            && self.options == other.options
+    }
+}
+
+/// A hook capturing the camera and/or microphone with
+/// `navigator.mediaDevices.getUserMedia()`, for video calls, recording or
+/// scanning.
+///
+/// All tracks are stopped on unmount, and when a capture is stopped or
+/// restarted before the permission prompt is answered, so the camera light
+/// doesn't stay on after navigating away. Changing the options while
+/// capturing, e.g. to switch cameras, restarts the capture.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use web_sys::HtmlMediaElement;
+///
+/// #[function_component(Preview)]
+/// fn preview() -> Html {
+///     let video = use_node_ref();
+///     let camera = use_user_media(UserMediaOptions {
+///         audio: false,
+///         ..Default::default()
+///     });
+///
+///     {
+///         let video = video.clone();
+///         use_effect_with(camera.stream().cloned(), move |stream| {
+///             if let Some(video) = video.cast::<HtmlMediaElement>() {
+///                 video.set_src_object(stream.as_ref());
+///             }
+///         });
+///     }
+///
+///     let onclick = {
+///         let camera = camera.clone();
+///         Callback::from(move |_| {
+///             if camera.ready() {
+///                 camera.stop();
+///             } else {
+///                 camera.start();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <video ref={video} autoplay=true muted=true playsinline=true />
+///             <button {onclick} disabled={camera.pending()}>
+///                 { if camera.ready() { "Stop camera" } else { "Start camera" } }
+///             </button>
+///             if let Some(error) = camera.error() {
+///                 <p class="error">{ error }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_user_media(options: UserMediaOptions) -> UseUserMediaHandle {
+    let stream = use_state_eq(|| None);
+    let pending = use_state_eq(|| false);
+    let error = use_state_eq(|| None);
+    let capture = use_memo((), |_| Capture::default());
+
+    let handle = UseUserMediaHandle {
+        stream,
+        pending,
+        error,
+        capture,
+        options: options.clone(),
+    };
+
+    {
+        let capture = handle.capture.clone();
+        use_effect_with((), move |_| {
+            capture.mounted.set(true);
+            move || {
+                capture.mounted.set(false);
+                capture.stop();
+            }
+        });
+    }
+    {
+        let handle = handle.clone();
+        use_effect_with(options, move |options| {
+            let capturing = handle.capture.stream.borrow().is_some() || handle.pending();
+            if options.immediate || capturing {
+                handle.start();
+            }
+        });
+    }
+
+    handle
+}
@@ -0,0 +1,68 @@
+use gloo::utils::window;
+use std::{cell::Cell, time::Duration};
+use yew::prelude::*;
+
+use crate::js;
+
+fn is_supported() -> bool {
+    js::has(&window().navigator(), "vibrate")
+}
+
+/// A hook returning a callback that vibrates the device with `navigator.vibrate`
+/// for haptic feedback on mobile.
+///
+/// The pattern alternates vibration and pause durations, starting with a
+/// vibration; an empty pattern stops the current vibration. The callback does
+/// nothing where vibration is unsupported (notably iOS), and a running pattern
+/// is cancelled on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(DeleteButton)]
+/// fn delete_button() -> Html {
+///     let vibrate = use_vibrate();
+///
+///     let onclick = Callback::from(move |_| {
+///         // Two short buzzes.
+///         let buzz = Duration::from_millis(40);
+///         vibrate.emit(vec![buzz, Duration::from_millis(60), buzz]);
+///     });
+///
+///     html! { <button {onclick}>{ "Delete" }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_vibrate() -> Callback<Vec<Duration>> {
+    // Only cancel on unmount if this component started a pattern.
+    let started = use_memo((), |_| Cell::new(false));
+
+    {
+        let started = started.clone();
+        use_effect_with((), move |_| {
+            move || {
+                if started.get() && is_supported() {
+                    window().navigator().vibrate_with_duration(0);
+                }
+            }
+        });
+    }
+
+    use_callback((), move |pattern: Vec<Duration>, _| {
+        if !is_supported() {
+            return;
+        }
+        let pattern: js_sys::Array = pattern
+            .iter()
+            .map(|d| u32::try_from(d.as_millis()).unwrap_or(u32::MAX))
+            .map(wasm_bindgen::JsValue::from)
+            .collect();
+        started.set(pattern.length() > 0);
+        window().navigator().vibrate_with_pattern(&pattern);
+    })
+}
@@ -0,0 +1,256 @@
+use std::{cell::RefCell, collections::HashMap, ops::Range, rc::Rc};
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::{use_element_scroll, use_element_size};
+
+/// Item height for the [`use_virtual_list`] hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemSize {
+    /// Every item is exactly this tall, in CSS pixels.
+    Fixed(f64),
+    /// Items are about this tall until measured with
+    /// [`UseVirtualListHandle::measure`].
+    Estimated(f64),
+}
+
+/// Options for the [`use_virtual_list`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualListOptions {
+    pub item_count: usize,
+    pub item_size: ItemSize,
+    /// Items rendered beyond each edge of the viewport, so fast scrolling
+    /// doesn't reveal blank space.
+    pub overscan: usize,
+}
+
+impl Default for VirtualListOptions {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            item_size: ItemSize::Fixed(32.0),
+            overscan: 3,
+        }
+    }
+}
+
+/// An item to render, as returned by [`UseVirtualListHandle::items`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualItem {
+    pub index: usize,
+    /// Offset from the top of the list, e.g. for `transform: translateY(..)`.
+    pub start: f64,
+    pub size: f64,
+}
+
+/// Item positions, computed once per change of count or sizes.
+#[derive(Clone)]
+enum Layout {
+    Fixed {
+        size: f64,
+        count: usize,
+    },
+    /// Start offsets of every item, plus the total size.
+    Measured(Rc<Vec<f64>>),
+}
+
+impl Layout {
+    fn count(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Measured(starts) => starts.len() - 1,
+        }
+    }
+
+    fn start(&self, index: usize) -> f64 {
+        match self {
+            Self::Fixed { size, .. } => *size * index as f64,
+            Self::Measured(starts) => starts[index.min(starts.len() - 1)],
+        }
+    }
+
+    fn total_size(&self) -> f64 {
+        self.start(self.count())
+    }
+
+    /// Index of the item at `offset`.
+    fn index_at(&self, offset: f64) -> usize {
+        let index = match self {
+            Self::Fixed { size, .. } if *size > 0.0 => (offset / *size).max(0.0) as usize,
+            Self::Fixed { .. } => 0,
+            Self::Measured(starts) => starts.partition_point(|start| *start <= offset).max(1) - 1,
+        };
+        index.min(self.count().saturating_sub(1))
+    }
+}
+
+impl PartialEq for Layout {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Fixed { size: a, count: m }, Self::Fixed { size: b, count: n }) => {
+                a == b && m == n
+            }
+            (Self::Measured(a), Self::Measured(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// State handle for the [`use_virtual_list`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseVirtualListHandle {
+    layout: Layout,
+    range: Range<usize>,
+    node: NodeRef,
+    measured: Rc<RefCell<HashMap<usize, f64>>>,
+    version: UseStateHandle<u64>,
+}
+
+impl UseVirtualListHandle {
+    /// Indices of the items to render, including overscan.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The items to render, with their positions.
+    pub fn items(&self) -> Vec<VirtualItem> {
+        self.range
+            .clone()
+            .map(|index| {
+                let start = self.layout.start(index);
+                VirtualItem {
+                    index,
+                    start,
+                    size: self.layout.start(index + 1) - start,
+                }
+            })
+            .collect()
+    }
+
+    /// Height of the whole list, for the inner element that makes the
+    /// container scrollable.
+    pub fn total_size(&self) -> f64 {
+        self.layout.total_size()
+    }
+
+    /// Scroll the container so the item at `index` is at the top.
+    pub fn scroll_to_index(&self, index: usize) {
+        if let Some(container) = self.node.cast::<Element>() {
+            container.set_scroll_top(self.layout.start(index) as i32);
+        }
+    }
+
+    /// Record the rendered height of the item at `index`, replacing the
+    /// estimate. Ignored with [`ItemSize::Fixed`].
+    pub fn measure(&self, index: usize, size: f64) {
+        if let Layout::Fixed { .. } = self.layout {
+            return;
+        }
+        let previous = self.measured.borrow_mut().insert(index, size);
+        if previous != Some(size) {
+            self.version.set(*self.version + 1);
+        }
+    }
+}
+
+/// A windowing hook for long lists: given the scroll container behind
+/// `node`, the item count and the item size, returns which items are in
+/// view and where to place them, so only those (plus a little
+/// [overscan](VirtualListOptions::overscan)) are rendered, even for lists of
+/// 10k+ rows.
+///
+/// Render an inner element [`total_size`](UseVirtualListHandle::total_size)
+/// tall inside the container, and each of the
+/// [`items`](UseVirtualListHandle::items) positioned at its `start`. The
+/// container's scroll offset and height are observed, so the range follows
+/// scrolling and resizing. For items of varying height, pass
+/// [`ItemSize::Estimated`] and report actual heights with
+/// [`measure`](UseVirtualListHandle::measure).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Rows)]
+/// fn rows() -> Html {
+///     let container = use_node_ref();
+///     let list = use_virtual_list(
+///         container.clone(),
+///         VirtualListOptions {
+///             item_count: 10_000,
+///             item_size: ItemSize::Fixed(32.0),
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <div ref={container} style="height: 400px; overflow-y: auto">
+///             <div style={format!("position: relative; height: {}px", list.total_size())}>
+///                 { for list.items().into_iter().map(|item| html! {
+///                     <div
+///                         key={item.index}
+///                         style={format!(
+///                             "position: absolute; width: 100%; height: {}px; transform: translateY({}px)",
+///                             item.size, item.start,
+///                         )}
+///                     >
+///                         { format!("Row {}", item.index) }
+///                     </div>
+///                 }) }
+///             </div>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_virtual_list(node: NodeRef, options: VirtualListOptions) -> UseVirtualListHandle {
+    let scroll = use_element_scroll(node.clone());
+    let viewport = use_element_size(node.clone()).content_box.height;
+    let measured = use_mut_ref(HashMap::new);
+    let version = use_state(|| 0_u64);
+
+    let layout = {
+        let measured = measured.clone();
+        use_memo(
+            (options.item_count, options.item_size, *version),
+            move |(count, item_size, _)| match *item_size {
+                ItemSize::Fixed(size) => Layout::Fixed {
+                    size,
+                    count: *count,
+                },
+                ItemSize::Estimated(estimate) => {
+                    let measured = measured.borrow();
+                    let mut starts = Vec::with_capacity(count + 1);
+                    let mut offset = 0.0;
+                    starts.push(offset);
+                    for index in 0..*count {
+                        offset += measured.get(&index).copied().unwrap_or(estimate);
+                        starts.push(offset);
+                    }
+                    Layout::Measured(Rc::new(starts))
+                }
+            },
+        )
+    };
+    let layout = (*layout).clone();
+
+    let range = if options.item_count == 0 {
+        0..0
+    } else {
+        let first = layout.index_at(scroll.y);
+        let last = layout.index_at(scroll.y + viewport);
+        first.saturating_sub(options.overscan)
+            ..(last + 1 + options.overscan).min(options.item_count)
+    };
+
+    UseVirtualListHandle {
+        layout,
+        range,
+        node,
+        measured,
+        version,
+    }
+}
@@ -0,0 +1,204 @@
+use gloo::{events::EventListener, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::EventTarget;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{js, use_page_visibility};
+
+fn wake_lock() -> Option<JsValue> {
+    js::get(&window().navigator(), "wakeLock")
+}
+
+/// The held `WakeLockSentinel`, if any.
+#[derive(Default)]
+struct Lock {
+    sentinel: RefCell<Option<(JsValue, EventListener)>>,
+    /// Whether the lock was requested and not released, so it is taken again
+    /// when the page comes back.
+    wanted: Cell<bool>,
+    /// Set while a request is pending, so requests don't pile up.
+    pending: Cell<bool>,
+}
+
+impl Lock {
+    /// Whether we hold a sentinel the browser hasn't released.
+    fn is_held(&self) -> bool {
+        self.sentinel.borrow().is_some()
+    }
+
+    fn release(&self) {
+        if let Some((sentinel, _)) = self.sentinel.take() {
+            let _ = js::call(&sentinel, "release", &[]);
+        }
+    }
+}
+
+/// State handle for the [`use_wake_lock`] hook.
+#[derive(Clone)]
+pub struct UseWakeLockHandle {
+    active: UseStateHandle<bool>,
+    error: UseStateHandle<Option<String>>,
+    lock: Rc<Lock>,
+}
+
+impl UseWakeLockHandle {
+    /// Whether the Screen Wake Lock API is available; it requires a secure
+    /// context.
+    pub fn is_supported(&self) -> bool {
+        wake_lock().is_some()
+    }
+
+    /// Whether the screen is being kept on.
+    pub fn is_active(&self) -> bool {
+        *self.active
+    }
+
+    /// The error of the last request, if it failed, e.g. in battery saver
+    /// mode.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Keep the screen on until [`release`](Self::release) or unmount,
+    /// re-acquiring the lock whenever the page becomes visible again.
+    pub fn request(&self) {
+        self.lock.wanted.set(true);
+        self.acquire();
+    }
+
+    /// Let the screen turn off again.
+    pub fn release(&self) {
+        self.lock.wanted.set(false);
+        self.lock.release();
+        self.active.set(false);
+    }
+
+    fn acquire(&self) {
+        let Some(wake_lock) = wake_lock() else {
+            self.error
+                .set(Some("Screen Wake Lock API is not supported".to_string()));
+            return;
+        };
+        if self.lock.is_held() || self.lock.pending.replace(true) {
+            return;
+        }
+        let active = self.active.clone();
+        let error = self.error.clone();
+        let lock = self.lock.clone();
+        spawn_local(async move {
+            let result = js::call_async(&wake_lock, "request", &["screen".into()]).await;
+            lock.pending.set(false);
+            match result {
+                // Released or unmounted while requesting.
+                Ok(sentinel) if !lock.wanted.get() => {
+                    let _ = js::call(&sentinel, "release", &[]);
+                }
+                Ok(sentinel) => {
+                    // The browser releases the lock when the page is hidden.
+                    let listener = {
+                        let active = active.clone();
+                        let lock = lock.clone();
+                        let target = sentinel.clone().unchecked_into::<EventTarget>();
+                        EventListener::once(&target, "release", move |_| {
+                            lock.sentinel.borrow_mut().take();
+                            active.set(false);
+                        })
+                    };
+                    *lock.sentinel.borrow_mut() = Some((sentinel, listener));
+                    active.set(true);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(
+                    js::get(&e, "message")
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                )),
+            }
+        });
+    }
+}
+
+impl PartialEq for UseWakeLockHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.active == *other.active
+            && *self.error == *other.error
+            && Rc::ptr_eq(&self.lock, &other.lock)
+    }
+}
+
+/// A hook for the Screen Wake Lock API, keeping the screen from dimming or
+/// locking while a recipe, presentation or boarding pass is on screen.
+///
+/// Browsers drop wake locks when the tab is hidden; the hook takes the lock
+/// again when the page becomes visible, until
+/// [`release`](UseWakeLockHandle::release). It is released on unmount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Recipe)]
+/// fn recipe() -> Html {
+///     let wake_lock = use_wake_lock();
+///
+///     let onchange = {
+///         let wake_lock = wake_lock.clone();
+///         Callback::from(move |_| {
+///             if wake_lock.is_active() {
+///                 wake_lock.release();
+///             } else {
+///                 wake_lock.request();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         if wake_lock.is_supported() {
+///             <label>
+///                 <input type="checkbox" checked={wake_lock.is_active()} {onchange} />
+///                 { "Keep screen on while cooking" }
+///             </label>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_wake_lock() -> UseWakeLockHandle {
+    let active = use_state_eq(|| false);
+    let error = use_state_eq(|| None);
+    let lock = use_memo((), |_| Lock::default());
+    let visibility = use_page_visibility();
+
+    let handle = UseWakeLockHandle {
+        active,
+        error,
+        lock,
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with(visibility, move |visibility| {
+            if visibility.is_visible() && handle.lock.wanted.get() {
+                handle.acquire();
+            }
+        });
+    }
+    {
+        let lock = handle.lock.clone();
+        use_effect_with((), move |_| {
+            move || {
+                lock.wanted.set(false);
+                lock.release();
+            }
+        });
+    }
+
+    handle
+}
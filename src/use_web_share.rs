@@ -0,0 +1,190 @@
+use gloo::utils::window;
+use std::fmt;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{File, ShareData};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// What to share with [`UseWebShareHandle::share`]. Set at least one field.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShareContent {
+    pub title: Option<String>,
+    pub text: Option<String>,
+    pub url: Option<String>,
+    /// Check [`can_share`](UseWebShareHandle::can_share) first; not every
+    /// browser or file type is supported.
+    pub files: Vec<File>,
+}
+
+impl ShareContent {
+    fn data(&self) -> ShareData {
+        let data = ShareData::new();
+        if let Some(title) = &self.title {
+            data.set_title(title);
+        }
+        if let Some(text) = &self.text {
+            data.set_text(text);
+        }
+        if let Some(url) = &self.url {
+            data.set_url(url);
+        }
+        if !self.files.is_empty() {
+            data.set_files(&files_array(&self.files));
+        }
+        data
+    }
+}
+
+fn files_array(files: &[File]) -> JsValue {
+    files.iter().collect::<js_sys::Array>().into()
+}
+
+/// How the last [`share`](UseWebShareHandle::share) ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// The user picked a target.
+    Shared,
+    /// The user dismissed the share sheet.
+    Cancelled,
+    /// Sharing failed, e.g. without a user gesture or with unsupported files.
+    Failed(String),
+}
+
+impl fmt::Display for ShareOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shared => write!(f, "shared"),
+            Self::Cancelled => write!(f, "share cancelled"),
+            Self::Failed(message) => write!(f, "share failed: {message}"),
+        }
+    }
+}
+
+fn is_supported() -> bool {
+    js::has(&window().navigator(), "share")
+}
+
+/// State handle for the [`use_web_share`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseWebShareHandle {
+    sharing: UseStateHandle<bool>,
+    outcome: UseStateHandle<Option<ShareOutcome>>,
+}
+
+impl UseWebShareHandle {
+    /// Whether the Web Share API is available (mostly mobile browsers and
+    /// Safari; it requires a secure context).
+    pub fn is_supported(&self) -> bool {
+        is_supported()
+    }
+
+    /// Whether `files` can be shared; with no files, whether sharing is
+    /// supported at all.
+    pub fn can_share(&self, files: &[File]) -> bool {
+        if !is_supported() {
+            return false;
+        }
+        if files.is_empty() {
+            return true;
+        }
+        let navigator = window().navigator();
+        let data = ShareData::new();
+        data.set_files(&files_array(files));
+        js::has(&navigator, "canShare") && navigator.can_share_with_data(&data)
+    }
+
+    /// Whether the share sheet is open.
+    pub fn sharing(&self) -> bool {
+        *self.sharing
+    }
+
+    /// How the last share ended.
+    pub fn outcome(&self) -> Option<&ShareOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Open the system share sheet. Call from a user gesture.
+    pub fn share(&self, content: ShareContent) {
+        if !is_supported() {
+            self.outcome.set(Some(ShareOutcome::Failed(
+                "Web Share API is not supported".to_string(),
+            )));
+            return;
+        }
+        let promise = window().navigator().share_with_data(&content.data());
+        let sharing = self.sharing.clone();
+        let outcome = self.outcome.clone();
+        sharing.set(true);
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            sharing.set(false);
+            outcome.set(Some(match result {
+                Ok(_) => ShareOutcome::Shared,
+                Err(e)
+                    if js::get(&e, "name").and_then(|n| n.as_string()).as_deref()
+                        == Some("AbortError") =>
+                {
+                    ShareOutcome::Cancelled
+                }
+                Err(e) => ShareOutcome::Failed(
+                    js::get(&e, "message")
+                        .and_then(|m| m.as_string())
+                        .unwrap_or_else(|| format!("{e:?}")),
+                ),
+            }));
+        });
+    }
+}
+
+/// A hook for the Web Share API: opens the system share sheet for a title,
+/// text, URL and files, and reports how it ended.
+///
+/// Check [`is_supported`](UseWebShareHandle::is_supported) to fall back to a
+/// copy-link flow (e.g. with [`use_clipboard`](crate::use_clipboard)) on
+/// desktop browsers without it.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     url: AttrValue,
+/// }
+///
+/// #[function_component(ShareButton)]
+/// fn share_button(props: &Props) -> Html {
+///     let share = use_web_share();
+///     let clipboard = use_clipboard(ClipboardOptions::default());
+///
+///     let onclick = {
+///         let share = share.clone();
+///         let url = props.url.clone();
+///         Callback::from(move |_| {
+///             if share.is_supported() {
+///                 share.share(ShareContent {
+///                     title: Some("Check this out".to_string()),
+///                     url: Some(url.to_string()),
+///                     ..Default::default()
+///                 });
+///             } else {
+///                 clipboard.write_text(&url);
+///             }
+///         })
+///     };
+///
+///     html! { <button {onclick}>{ "Share" }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_web_share() -> UseWebShareHandle {
+    let sharing = use_state_eq(|| false);
+    let outcome = use_state_eq(|| None);
+
+    UseWebShareHandle { sharing, outcome }
+}
@@ -0,0 +1,351 @@
+#![cfg(feature = "web-transport")]
+
+use gloo::utils::window;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    ReadableStream, ReadableStreamDefaultReader, WritableStream, WritableStreamDefaultWriter,
+};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::js;
+
+/// Connection state of [`use_web_transport`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum WebTransportState {
+    /// The browser has no `WebTransport`; fall back to e.g. a WebSocket.
+    Unsupported,
+    /// The session is being established.
+    #[default]
+    Connecting,
+    /// The session is ready for datagrams and streams.
+    Connected,
+    /// The session was closed by either side, with the server's reason if any.
+    Closed { code: u32, reason: String },
+    /// The session could not be established or was lost.
+    Failed(String),
+}
+
+/// Whether the browser supports `WebTransport`.
+fn is_supported() -> bool {
+    js::has(&window(), "WebTransport")
+}
+
+fn js_error(e: &JsValue) -> String {
+    e.as_string()
+        .or_else(|| js::get(e, "message").and_then(|m| m.as_string()))
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+fn reader(stream: &JsValue, key: &str) -> Result<ReadableStreamDefaultReader, String> {
+    let readable: ReadableStream = js::get(stream, key)
+        .ok_or_else(|| format!("{key} is missing"))?
+        .unchecked_into();
+    Ok(readable.get_reader().unchecked_into())
+}
+
+fn writer(stream: &JsValue, key: &str) -> Result<WritableStreamDefaultWriter, String> {
+    let writable: WritableStream = js::get(stream, key)
+        .ok_or_else(|| format!("{key} is missing"))?
+        .unchecked_into();
+    writable.get_writer().map_err(|e| js_error(&e))
+}
+
+/// Read the next chunk, or `None` once the stream is done.
+async fn read(reader: &ReadableStreamDefaultReader) -> Result<Option<Vec<u8>>, String> {
+    let chunk = JsFuture::from(reader.read())
+        .await
+        .map_err(|e| js_error(&e))?;
+    if js::get(&chunk, "done").and_then(|done| done.as_bool()) == Some(true) {
+        return Ok(None);
+    }
+    Ok(Some(
+        js::get(&chunk, "value")
+            .map(|value| js_sys::Uint8Array::new(&value).to_vec())
+            .unwrap_or_default(),
+    ))
+}
+
+async fn write(writer: &WritableStreamDefaultWriter, bytes: &[u8]) -> Result<(), String> {
+    let chunk = js_sys::Uint8Array::from(bytes);
+    JsFuture::from(writer.write_with_chunk(&chunk))
+        .await
+        .map(drop)
+        .map_err(|e| js_error(&e))
+}
+
+/// A bidirectional stream opened with [`UseWebTransportHandle::open_stream`].
+/// Streams are reliable and ordered, unlike datagrams.
+pub struct WebTransportStream {
+    reader: ReadableStreamDefaultReader,
+    writer: WritableStreamDefaultWriter,
+}
+
+impl WebTransportStream {
+    /// Send `bytes` on the stream.
+    pub async fn write(&self, bytes: &[u8]) -> Result<(), String> {
+        write(&self.writer, bytes).await
+    }
+
+    /// Receive the next chunk, or `None` once the peer finished sending.
+    pub async fn read(&self) -> Result<Option<Vec<u8>>, String> {
+        read(&self.reader).await
+    }
+
+    /// Finish sending; the stream can still be read.
+    pub async fn close(&self) -> Result<(), String> {
+        JsFuture::from(self.writer.close())
+            .await
+            .map(drop)
+            .map_err(|e| js_error(&e))
+    }
+}
+
+struct Session {
+    transport: RefCell<Option<JsValue>>,
+    datagrams: RefCell<Option<WritableStreamDefaultWriter>>,
+    /// Incremented per session, so a replaced one can't report late.
+    generation: Cell<u64>,
+    state: UseStateHandle<WebTransportState>,
+    datagram: UseStateHandle<Option<Rc<Vec<u8>>>>,
+}
+
+impl Session {
+    fn disconnect(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.datagrams.borrow_mut().take();
+        if let Some(transport) = self.transport.borrow_mut().take() {
+            let _ = js::call(&transport, "close", &[]);
+        }
+    }
+
+    fn connect(self: &Rc<Self>, url: &str) {
+        self.disconnect();
+        if !is_supported() {
+            self.state.set(WebTransportState::Unsupported);
+            return;
+        }
+        let transport = js::get(&window(), "WebTransport")
+            .and_then(|constructor| constructor.dyn_into::<js_sys::Function>().ok())
+            .ok_or_else(|| JsValue::from_str("WebTransport is not a constructor"))
+            .and_then(|constructor| {
+                js_sys::Reflect::construct(&constructor, &js_sys::Array::of1(&url.into()))
+            });
+        let transport = match transport {
+            Ok(transport) => transport,
+            Err(e) => {
+                self.state.set(WebTransportState::Failed(js_error(&e)));
+                return;
+            }
+        };
+        *self.transport.borrow_mut() = Some(transport.clone());
+        self.state.set(WebTransportState::Connecting);
+
+        let generation = self.generation.get();
+        let session = self.clone();
+        spawn_local(async move {
+            let ready = js::get(&transport, "ready")
+                .map(|ready| JsFuture::from(js_sys::Promise::from(ready)));
+            if let Some(ready) = ready
+                && let Err(e) = ready.await
+            {
+                if session.generation.get() == generation {
+                    session.transport.borrow_mut().take();
+                    session.state.set(WebTransportState::Failed(js_error(&e)));
+                }
+                return;
+            }
+            if session.generation.get() != generation {
+                return;
+            }
+            let datagrams = js::get(&transport, "datagrams").unwrap_or(JsValue::UNDEFINED);
+            *session.datagrams.borrow_mut() = writer(&datagrams, "writable").ok();
+            session.state.set(WebTransportState::Connected);
+
+            {
+                let session = session.clone();
+                spawn_local(async move { session.receive_datagrams(generation, &datagrams).await });
+            }
+
+            let closed = js::get(&transport, "closed")
+                .map(|closed| JsFuture::from(js_sys::Promise::from(closed)));
+            let Some(closed) = closed else {
+                return;
+            };
+            let state = match closed.await {
+                Ok(info) => WebTransportState::Closed {
+                    code: js::get(&info, "closeCode")
+                        .and_then(|code| code.as_f64())
+                        .unwrap_or_default() as u32,
+                    reason: js::get(&info, "reason")
+                        .and_then(|reason| reason.as_string())
+                        .unwrap_or_default(),
+                },
+                Err(e) => WebTransportState::Failed(js_error(&e)),
+            };
+            if session.generation.get() == generation {
+                session.transport.borrow_mut().take();
+                session.datagrams.borrow_mut().take();
+                session.state.set(state);
+            }
+        });
+    }
+
+    async fn receive_datagrams(&self, generation: u64, datagrams: &JsValue) {
+        let Ok(reader) = reader(datagrams, "readable") else {
+            return;
+        };
+        while let Ok(Some(bytes)) = read(&reader).await {
+            if self.generation.get() != generation {
+                break;
+            }
+            self.datagram.set(Some(Rc::new(bytes)));
+        }
+    }
+}
+
+/// State handle for the [`use_web_transport`] hook.
+#[derive(Clone)]
+pub struct UseWebTransportHandle {
+    session: Rc<Session>,
+    state: UseStateHandle<WebTransportState>,
+    datagram: UseStateHandle<Option<Rc<Vec<u8>>>>,
+}
+
+impl UseWebTransportHandle {
+    /// The current connection state.
+    pub fn state(&self) -> &WebTransportState {
+        &self.state
+    }
+
+    /// Whether the browser supports `WebTransport`.
+    pub fn is_supported(&self) -> bool {
+        *self.state != WebTransportState::Unsupported
+    }
+
+    /// The latest datagram received.
+    pub fn datagram(&self) -> Option<Rc<Vec<u8>>> {
+        (*self.datagram).clone()
+    }
+
+    /// Send an unreliable, unordered datagram. Datagrams may be dropped and
+    /// must fit a single packet (roughly 1 KiB is safe).
+    pub fn send_datagram(&self, bytes: &[u8]) -> Result<(), String> {
+        let datagrams = self.session.datagrams.borrow();
+        let writer = datagrams.as_ref().ok_or("not connected")?;
+        let chunk = js_sys::Uint8Array::from(bytes);
+        // Datagrams are fire-and-forget; the promise only reports queueing.
+        let _ = writer.write_with_chunk(&chunk);
+        Ok(())
+    }
+
+    /// Open a reliable bidirectional stream to the server.
+    pub async fn open_stream(&self) -> Result<WebTransportStream, String> {
+        let transport = self
+            .session
+            .transport
+            .borrow()
+            .clone()
+            .filter(|_| *self.state == WebTransportState::Connected)
+            .ok_or("not connected")?;
+        let stream = js::call_async(&transport, "createBidirectionalStream", &[])
+            .await
+            .map_err(|e| js_error(&e))?;
+        Ok(WebTransportStream {
+            reader: reader(&stream, "readable")?,
+            writer: writer(&stream, "writable")?,
+        })
+    }
+
+    /// Close the session.
+    pub fn close(&self) {
+        self.session.disconnect();
+        self.state.set(WebTransportState::Closed {
+            code: 0,
+            reason: String::new(),
+        });
+    }
+}
+
+impl PartialEq for UseWebTransportHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.session, &other.session)
+            && *self.state == *other.state
+            && *self.datagram == *other.datagram
+    }
+}
+
+/// A [WebTransport](https://developer.mozilla.org/en-US/docs/Web/API/WebTransport)
+/// hook: a low-latency alternative to
+/// [`use_websocket_reconnecting`](crate::use_websocket_reconnecting) over
+/// HTTP/3, with unreliable datagrams (e.g. game state, cursor positions) and
+/// reliable bidirectional streams.
+///
+/// Where `WebTransport` is unavailable the state is
+/// [`WebTransportState::Unsupported`], so the app can fall back to a
+/// WebSocket. The session is reopened when `url` changes and closed on
+/// unmount. It is not reconnected automatically.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Game)]
+/// fn game() -> Html {
+///     let transport = use_web_transport("https://game.example.com:4433/play".to_string());
+///
+///     let onclick = {
+///         let transport = transport.clone();
+///         Callback::from(move |_| {
+///             let _ = transport.send_datagram(b"jump");
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             if !transport.is_supported() {
+///                 <p>{ "WebTransport is not supported" }</p>
+///             }
+///             <button {onclick} disabled={*transport.state() != WebTransportState::Connected}>
+///                 { "Jump" }
+///             </button>
+///             if let Some(datagram) = transport.datagram() {
+///                 <p>{ format!("Last update: {} bytes", datagram.len()) }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_web_transport(url: String) -> UseWebTransportHandle {
+    let state = use_state(WebTransportState::default);
+    let datagram = use_state(|| None);
+    let session = use_memo((), |_| Session {
+        transport: RefCell::new(None),
+        datagrams: RefCell::new(None),
+        generation: Cell::new(0),
+        state: state.clone(),
+        datagram: datagram.clone(),
+    });
+
+    {
+        let session = session.clone();
+        use_effect_with(url, move |url| {
+            session.connect(url);
+            move || session.disconnect()
+        });
+    }
+
+    UseWebTransportHandle {
+        session,
+        state,
+        datagram,
+    }
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "serde")]
+
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fmt, marker::PhantomData, rc::Rc};
+use yew::prelude::*;
+
+use crate::{
+    ReconnectingWebSocketOptions, ReconnectingWebSocketState, UseWebSocketReconnectingHandle,
+    use_websocket_reconnecting::use_websocket_reconnecting_with_handler,
+};
+
+/// A text frame received by [`use_websocket_json`] that did not deserialize.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonFrameError {
+    /// The frame as received.
+    pub frame: String,
+    /// The deserialization error.
+    pub message: String,
+}
+
+impl fmt::Display for JsonFrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid JSON frame: {}", self.message)
+    }
+}
+
+impl std::error::Error for JsonFrameError {}
+
+/// State handle for the [`use_websocket_json`] hook.
+pub struct UseWebSocketJsonHandle<Tx, Rx> {
+    ws: UseWebSocketReconnectingHandle,
+    message: UseStateHandle<Option<Rc<Rx>>>,
+    error: UseStateHandle<Option<JsonFrameError>>,
+    _tx: PhantomData<Tx>,
+}
+
+impl<Tx, Rx> UseWebSocketJsonHandle<Tx, Rx>
+where
+    Tx: Serialize,
+{
+    /// The current connection state.
+    pub fn state(&self) -> ReconnectingWebSocketState {
+        self.ws.state()
+    }
+
+    /// The latest message that deserialized.
+    pub fn message(&self) -> Option<Rc<Rx>> {
+        (*self.message).clone()
+    }
+
+    /// The error of the latest frame, if it did not deserialize. Cleared by
+    /// the next valid frame.
+    pub fn error(&self) -> Option<&JsonFrameError> {
+        self.error.as_ref()
+    }
+
+    /// Serialize and send a message, buffering it while disconnected.
+    pub fn send(&self, message: &Tx) -> Result<(), serde_json::Error> {
+        self.ws.send(serde_json::to_string(message)?);
+        Ok(())
+    }
+
+    /// Close the connection and stop reconnecting.
+    pub fn close(&self) {
+        self.ws.close();
+    }
+
+    /// Connect again now, resetting the backoff.
+    pub fn reconnect(&self) {
+        self.ws.reconnect();
+    }
+}
+
+impl<Tx, Rx> Clone for UseWebSocketJsonHandle<Tx, Rx> {
+    fn clone(&self) -> Self {
+        Self {
+            ws: self.ws.clone(),
+            message: self.message.clone(),
+            error: self.error.clone(),
+            _tx: PhantomData,
+        }
+    }
+}
+
+impl<Tx, Rx> PartialEq for UseWebSocketJsonHandle<Tx, Rx> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_message = match (&*self.message, &*other.message) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.ws == other.ws && same_message && *self.error == *other.error
+    }
+}
+
+/// A WebSocket hook for JSON protocols: outgoing messages of type `Tx` are
+/// serialized, incoming text frames are deserialized into `Rx`. Frames that
+/// fail to deserialize are reported through
+/// [`error`](UseWebSocketJsonHandle::error) instead of being dropped silently.
+///
+/// The connection is a [`use_websocket_reconnecting`] with default options;
+/// use [`use_websocket_json_with_options`] to configure it. Binary frames are
+/// ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize)]
+/// #[serde(tag = "type", rename_all = "camelCase")]
+/// enum ClientMessage {
+///     Subscribe { symbol: String },
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Quote {
+///     symbol: String,
+///     price: f64,
+/// }
+///
+/// #[function_component(Ticker)]
+/// fn ticker() -> Html {
+///     let ws = use_websocket_json::<ClientMessage, Quote>("wss://example.com/quotes".to_string());
+///
+///     let subscribe = {
+///         let ws = ws.clone();
+///         Callback::from(move |_| {
+///             let _ = ws.send(&ClientMessage::Subscribe { symbol: "ACME".to_string() });
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={subscribe}>{ "Follow ACME" }</button>
+///             if let Some(quote) = ws.message() {
+///                 <p>{ format!("{}: {:.2}", quote.symbol, quote.price) }</p>
+///             }
+///             if let Some(error) = ws.error() {
+///                 <p class="error">{ error.to_string() }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_websocket_json<Tx, Rx>(url: String) -> UseWebSocketJsonHandle<Tx, Rx>
+where
+    Tx: Serialize,
+    Rx: DeserializeOwned + 'static,
+{
+    use_websocket_json_with_options(url, ReconnectingWebSocketOptions::default())
+}
+
+/// Like [`use_websocket_json`], with options for the underlying
+/// [`use_websocket_reconnecting`] connection.
+#[hook]
+pub fn use_websocket_json_with_options<Tx, Rx>(
+    url: String,
+    options: ReconnectingWebSocketOptions,
+) -> UseWebSocketJsonHandle<Tx, Rx>
+where
+    Tx: Serialize,
+    Rx: DeserializeOwned + 'static,
+{
+    let message = use_state(|| None);
+    let error = use_state(|| None);
+
+    // Every frame is parsed as it arrives, so repeated frames are not merged.
+    let on_text = {
+        let message = message.clone();
+        let error = error.clone();
+        Callback::from(
+            move |frame: String| match serde_json::from_str::<Rx>(&frame) {
+                Ok(value) => {
+                    message.set(Some(Rc::new(value)));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(JsonFrameError {
+                    frame,
+                    message: e.to_string(),
+                })),
+            },
+        )
+    };
+    let ws = use_websocket_reconnecting_with_handler(url, options, Some(on_text));
+
+    UseWebSocketJsonHandle {
+        ws,
+        message,
+        error,
+        _tx: PhantomData,
+    }
+}
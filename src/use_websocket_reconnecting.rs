@@ -0,0 +1,423 @@
+use gloo::{
+    events::EventListener,
+    timers::callback::{Interval, Timeout},
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, MessageEvent, WebSocket};
+use yew::prelude::*;
+
+/// Connection state of [`use_websocket_reconnecting`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReconnectingWebSocketState {
+    /// Opening a connection.
+    Connecting,
+    /// Connected; buffered messages have been flushed.
+    Open,
+    /// Disconnected; the next attempt starts after `delay`.
+    Reconnecting { attempt: u32, delay: Duration },
+    /// Closed by [`close`](UseWebSocketReconnectingHandle::close) or after
+    /// [`max_retries`](ReconnectingWebSocketOptions::max_retries).
+    Closed,
+}
+
+/// Keep-alive pings for [`ReconnectingWebSocketOptions::heartbeat`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heartbeat {
+    /// Send `ping` this often while connected.
+    pub interval: Duration,
+    /// Reconnect when nothing is received this long after a ping.
+    pub timeout: Duration,
+    /// Text message sent as ping.
+    pub ping: String,
+    /// Text message the server answers with; it is not surfaced as a message.
+    /// Any received message counts as a sign of life.
+    pub pong: Option<String>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(10),
+            ping: "ping".to_string(),
+            pong: Some("pong".to_string()),
+        }
+    }
+}
+
+/// Options for the [`use_websocket_reconnecting`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReconnectingWebSocketOptions {
+    /// Sub-protocols to request.
+    pub protocols: Vec<String>,
+    /// Delay before the first reconnect; doubled after every failed attempt.
+    pub min_delay: Duration,
+    /// Upper bound for the reconnect delay.
+    pub max_delay: Duration,
+    /// Fraction of the delay randomly shaved off, so clients don't reconnect
+    /// in lockstep after a server restart (0 disables jitter).
+    pub jitter: f64,
+    /// Give up after this many consecutive failed attempts; `None` retries forever.
+    pub max_retries: Option<u32>,
+    /// Messages sent while disconnected are buffered up to this many (oldest
+    /// dropped first) and flushed on reconnect.
+    pub buffer_limit: usize,
+    pub heartbeat: Option<Heartbeat>,
+}
+
+impl Default for ReconnectingWebSocketOptions {
+    fn default() -> Self {
+        Self {
+            protocols: Vec::new(),
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+            max_retries: None,
+            buffer_limit: 100,
+            heartbeat: None,
+        }
+    }
+}
+
+enum Outgoing {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+struct Socket {
+    ws: WebSocket,
+    _listeners: Vec<EventListener>,
+    _heartbeat: Option<Interval>,
+}
+
+struct Connection {
+    url: RefCell<String>,
+    options: RefCell<ReconnectingWebSocketOptions>,
+    socket: RefCell<Option<Socket>>,
+    buffer: RefCell<VecDeque<Outgoing>>,
+    attempt: Cell<u32>,
+    stopped: Cell<bool>,
+    reconnect: RefCell<Option<Timeout>>,
+    pong: RefCell<Option<Timeout>>,
+    state: UseStateHandle<ReconnectingWebSocketState>,
+    message: UseStateHandle<Option<String>>,
+    message_bytes: UseStateHandle<Option<Vec<u8>>>,
+    /// Receives text frames instead of [`message`](Self::message), if set.
+    on_text: RefCell<Option<Callback<String>>>,
+}
+
+impl Connection {
+    fn is_open(&self) -> bool {
+        self.socket
+            .borrow()
+            .as_ref()
+            .is_some_and(|s| s.ws.ready_state() == WebSocket::OPEN)
+    }
+
+    fn disconnect(&self) {
+        self.pong.borrow_mut().take();
+        if let Some(socket) = self.socket.borrow_mut().take() {
+            let _ = socket.ws.close();
+        }
+    }
+
+    fn stop(&self) {
+        self.stopped.set(true);
+        self.reconnect.borrow_mut().take();
+        self.disconnect();
+    }
+
+    fn connect(self: &Rc<Self>) {
+        self.disconnect();
+        self.reconnect.borrow_mut().take();
+        self.stopped.set(false);
+        let options = self.options.borrow().clone();
+        let protocols: js_sys::Array = options
+            .protocols
+            .iter()
+            .map(|p| wasm_bindgen::JsValue::from_str(p))
+            .collect();
+        let Ok(ws) = WebSocket::new_with_str_sequence(&self.url.borrow(), &protocols) else {
+            self.schedule_reconnect();
+            return;
+        };
+        ws.set_binary_type(BinaryType::Arraybuffer);
+        self.state.set(ReconnectingWebSocketState::Connecting);
+
+        let connection = self.clone();
+        let mut listeners = vec![EventListener::new(&ws, "open", move |_| {
+            connection.attempt.set(0);
+            connection.state.set(ReconnectingWebSocketState::Open);
+            connection.flush();
+        })];
+        {
+            let connection = self.clone();
+            let pong = options.heartbeat.as_ref().and_then(|h| h.pong.clone());
+            listeners.push(EventListener::new(&ws, "message", move |e| {
+                connection.pong.borrow_mut().take();
+                let Some(e) = e.dyn_ref::<MessageEvent>() else {
+                    return;
+                };
+                let data = e.data();
+                if let Some(text) = data.as_string() {
+                    if pong.as_ref() != Some(&text) {
+                        let on_text = connection.on_text.borrow().clone();
+                        match on_text {
+                            Some(on_text) => on_text.emit(text),
+                            None => connection.message.set(Some(text)),
+                        }
+                    }
+                } else if let Some(buffer) = data.dyn_ref::<js_sys::ArrayBuffer>() {
+                    connection
+                        .message_bytes
+                        .set(Some(js_sys::Uint8Array::new(buffer).to_vec()));
+                }
+            }));
+        }
+        {
+            // `error` is always followed by `close`, so only `close` is handled.
+            let connection = self.clone();
+            listeners.push(EventListener::new(&ws, "close", move |_| {
+                connection.disconnect();
+                if connection.stopped.get() {
+                    connection.state.set(ReconnectingWebSocketState::Closed);
+                } else {
+                    connection.schedule_reconnect();
+                }
+            }));
+        }
+
+        let heartbeat = options.heartbeat.map(|heartbeat| {
+            let connection = self.clone();
+            let millis = u32::try_from(heartbeat.interval.as_millis()).unwrap_or(u32::MAX);
+            Interval::new(millis, move || connection.ping(&heartbeat))
+        });
+
+        *self.socket.borrow_mut() = Some(Socket {
+            ws,
+            _listeners: listeners,
+            _heartbeat: heartbeat,
+        });
+    }
+
+    fn ping(self: &Rc<Self>, heartbeat: &Heartbeat) {
+        if !self.is_open() || self.pong.borrow().is_some() {
+            return;
+        }
+        self.send(Outgoing::Text(heartbeat.ping.clone()));
+        // The close handshake can't complete on a dead connection, so don't
+        // wait for `close` and reconnect right away.
+        let connection = self.clone();
+        let millis = u32::try_from(heartbeat.timeout.as_millis()).unwrap_or(u32::MAX);
+        *self.pong.borrow_mut() = Some(Timeout::new(millis, move || {
+            connection.disconnect();
+            connection.schedule_reconnect();
+        }));
+    }
+
+    fn schedule_reconnect(self: &Rc<Self>) {
+        let options = self.options.borrow().clone();
+        let attempt = self.attempt.get() + 1;
+        if options.max_retries.is_some_and(|max| attempt > max) {
+            self.state.set(ReconnectingWebSocketState::Closed);
+            return;
+        }
+        self.attempt.set(attempt);
+        let backoff = options
+            .min_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(options.max_delay);
+        let jitter = options.jitter.clamp(0.0, 1.0) * js_sys::Math::random();
+        let delay = backoff.mul_f64(1.0 - jitter);
+        self.state
+            .set(ReconnectingWebSocketState::Reconnecting { attempt, delay });
+        let connection = self.clone();
+        let millis = u32::try_from(delay.as_millis()).unwrap_or(u32::MAX);
+        *self.reconnect.borrow_mut() = Some(Timeout::new(millis, move || connection.connect()));
+    }
+
+    fn send(&self, message: Outgoing) {
+        let socket = self.socket.borrow();
+        match socket.as_ref().filter(|_| self.is_open()) {
+            Some(socket) => {
+                let _ = match &message {
+                    Outgoing::Text(text) => socket.ws.send_with_str(text),
+                    Outgoing::Bytes(bytes) => socket.ws.send_with_u8_array(bytes),
+                };
+            }
+            None => {
+                let limit = self.options.borrow().buffer_limit;
+                let mut buffer = self.buffer.borrow_mut();
+                buffer.push_back(message);
+                while buffer.len() > limit {
+                    buffer.pop_front();
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {
+        let buffered: Vec<_> = self.buffer.borrow_mut().drain(..).collect();
+        for message in buffered {
+            self.send(message);
+        }
+    }
+}
+
+/// State handle for the [`use_websocket_reconnecting`] hook.
+#[derive(Clone)]
+pub struct UseWebSocketReconnectingHandle {
+    connection: Rc<Connection>,
+    state: UseStateHandle<ReconnectingWebSocketState>,
+    message: UseStateHandle<Option<String>>,
+    message_bytes: UseStateHandle<Option<Vec<u8>>>,
+}
+
+impl UseWebSocketReconnectingHandle {
+    /// The current connection state.
+    pub fn state(&self) -> ReconnectingWebSocketState {
+        *self.state
+    }
+
+    /// The latest text message.
+    pub fn message(&self) -> Option<&String> {
+        self.message.as_ref()
+    }
+
+    /// The latest binary message.
+    pub fn message_bytes(&self) -> Option<&Vec<u8>> {
+        self.message_bytes.as_ref()
+    }
+
+    /// Send a text message, buffering it while disconnected.
+    pub fn send(&self, message: String) {
+        self.connection.send(Outgoing::Text(message));
+    }
+
+    /// Send a binary message, buffering it while disconnected.
+    pub fn send_bytes(&self, bytes: Vec<u8>) {
+        self.connection.send(Outgoing::Bytes(bytes));
+    }
+
+    /// Close the connection and stop reconnecting.
+    pub fn close(&self) {
+        self.connection.stop();
+        self.state.set(ReconnectingWebSocketState::Closed);
+    }
+
+    /// Connect again now, resetting the backoff.
+    pub fn reconnect(&self) {
+        self.connection.attempt.set(0);
+        self.connection.connect();
+    }
+}
+
+impl PartialEq for UseWebSocketReconnectingHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.connection, &other.connection)
+            && *self.state == *other.state
+            && *self.message == *other.message
+            && *self.message_bytes == *other.message_bytes
+    }
+}
+
+/// A WebSocket hook that reconnects with exponential backoff and jitter,
+/// buffers messages sent while disconnected, and optionally detects dead
+/// connections with heartbeats.
+///
+/// Unlike [`yew_hooks::use_websocket`], the socket survives re-renders and
+/// reconnects when `url` changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Chat)]
+/// fn chat() -> Html {
+///     let ws = use_websocket_reconnecting(
+///         "wss://example.com/chat".to_string(),
+///         ReconnectingWebSocketOptions {
+///             heartbeat: Some(Heartbeat::default()),
+///             ..Default::default()
+///         },
+///     );
+///
+///     let onclick = {
+///         let ws = ws.clone();
+///         Callback::from(move |_| ws.send("Hello".to_string()))
+///     };
+///
+///     html! {
+///         <div>
+///             if let ReconnectingWebSocketState::Reconnecting { delay, .. } = ws.state() {
+///                 <p>{ format!("Reconnecting in {}s", delay.as_secs()) }</p>
+///             }
+///             <button {onclick}>{ "Say hello" }</button>
+///             <p>{ ws.message().cloned().unwrap_or_default() }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_websocket_reconnecting(
+    url: String,
+    options: ReconnectingWebSocketOptions,
+) -> UseWebSocketReconnectingHandle {
+    use_websocket_reconnecting_with_handler(url, options, None)
+}
+
+/// Like [`use_websocket_reconnecting`], passing every text frame to `on_text`
+/// as it arrives instead of keeping the latest one in
+/// [`message`](UseWebSocketReconnectingHandle::message).
+#[hook]
+pub(crate) fn use_websocket_reconnecting_with_handler(
+    url: String,
+    options: ReconnectingWebSocketOptions,
+    on_text: Option<Callback<String>>,
+) -> UseWebSocketReconnectingHandle {
+    let state = use_state(|| ReconnectingWebSocketState::Connecting);
+    let message = use_state(|| None);
+    let message_bytes = use_state(|| None);
+    let connection = use_memo((), |_| Connection {
+        url: RefCell::new(url.clone()),
+        options: RefCell::new(options.clone()),
+        socket: RefCell::new(None),
+        buffer: RefCell::new(VecDeque::new()),
+        attempt: Cell::new(0),
+        stopped: Cell::new(false),
+        reconnect: RefCell::new(None),
+        pong: RefCell::new(None),
+        state: state.clone(),
+        message: message.clone(),
+        message_bytes: message_bytes.clone(),
+        on_text: RefCell::new(None),
+    });
+    *connection.options.borrow_mut() = options;
+    *connection.on_text.borrow_mut() = on_text;
+
+    {
+        let connection = connection.clone();
+        use_effect_with(url, move |url| {
+            *connection.url.borrow_mut() = url.clone();
+            connection.attempt.set(0);
+            connection.connect();
+            move || connection.stop()
+        });
+    }
+
+    UseWebSocketReconnectingHandle {
+        connection,
+        state,
+        message,
+        message_bytes,
+    }
+}
@@ -0,0 +1,48 @@
+use gloo::{
+    events::EventListener,
+    utils::{document, window},
+};
+use yew::prelude::*;
+
+/// A sensor hook returning whether the window has focus, updated on `focus`
+/// and `blur`.
+///
+/// Unlike [`use_page_visibility`](crate::use_page_visibility), this turns
+/// `false` while the page is still visible but the user is in another window
+/// or in the browser's address bar, so apps can dim live indicators or defer
+/// notifications to the system tray.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(LiveBadge)]
+/// fn live_badge() -> Html {
+///     let focused = use_window_focus();
+///
+///     html! {
+///         <span class={classes!("live", (!focused).then_some("dimmed"))}>{ "LIVE" }</span>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_window_focus() -> bool {
+    let focused = use_state_eq(|| document().has_focus().unwrap_or(false));
+
+    {
+        let focused = focused.clone();
+        use_effect_with((), move |_| {
+            focused.set(document().has_focus().unwrap_or(false));
+            let listeners = [("focus", true), ("blur", false)].map(|(event, value)| {
+                let focused = focused.clone();
+                EventListener::new(&window(), event, move |_| focused.set(value))
+            });
+            move || drop(listeners)
+        });
+    }
+
+    *focused
+}
@@ -0,0 +1,354 @@
+#![cfg(feature = "serde")]
+
+use futures::{
+    channel::oneshot,
+    future::{Either, select},
+};
+use gloo::{timers::future::TimeoutFuture, utils::window};
+use log::warn;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt,
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlIFrameElement, MessageEvent, Window};
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+use crate::js;
+
+/// The window that [`use_window_messaging`] posts messages to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum MessageTarget {
+    /// `window.parent`, for a widget embedded in an iframe.
+    #[default]
+    Parent,
+    /// `window.opener`, for a popup opened by another page.
+    Opener,
+    /// The content window of an `<iframe>` rendered by this component.
+    Frame(NodeRef),
+}
+
+impl MessageTarget {
+    fn resolve(&self) -> Option<Window> {
+        match self {
+            Self::Parent => window()
+                .parent()
+                .ok()
+                .flatten()
+                .filter(|parent| *parent != window()),
+            Self::Opener => window().opener().ok()?.dyn_into().ok(),
+            Self::Frame(node) => node.cast::<HtmlIFrameElement>()?.content_window(),
+        }
+    }
+}
+
+/// Options for the [`use_window_messaging`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowMessagingOptions {
+    /// Window that outgoing messages are posted to, and the only window
+    /// incoming messages are accepted from.
+    pub target: MessageTarget,
+    /// Origins incoming messages are accepted from. `"*"` accepts any origin.
+    pub allowed_origins: Vec<String>,
+    /// Name shared by both sides, so unrelated `postMessage` traffic is ignored.
+    pub channel: String,
+    /// How long [`UseWindowMessagingHandle::request`] waits for a reply.
+    pub request_timeout: Duration,
+}
+
+impl Default for WindowMessagingOptions {
+    fn default() -> Self {
+        Self {
+            target: MessageTarget::default(),
+            allowed_origins: Vec::new(),
+            channel: "more-yew-hooks".to_string(),
+            request_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Errors returned by [`UseWindowMessagingHandle`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum WindowMessagingError {
+    /// The target window does not exist (not embedded, popup closed, iframe not mounted).
+    NoTarget,
+    /// The outgoing message could not be serialized or posted.
+    Send(String),
+    /// A reply arrived but could not be deserialized.
+    Deserialize(String),
+    /// No reply arrived within [`WindowMessagingOptions::request_timeout`].
+    Timeout,
+}
+
+impl fmt::Display for WindowMessagingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTarget => write!(f, "no target window to post to"),
+            Self::Send(e) => write!(f, "failed to post message: {e}"),
+            Self::Deserialize(e) => write!(f, "failed to deserialize reply: {e}"),
+            Self::Timeout => write!(f, "timed out waiting for reply"),
+        }
+    }
+}
+
+impl std::error::Error for WindowMessagingError {}
+
+/// A message received by [`use_window_messaging`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedMessage<T> {
+    /// The deserialized payload.
+    pub data: T,
+    /// Origin of the sending window.
+    pub origin: String,
+    /// Set when the sender used [`UseWindowMessagingHandle::request`] and awaits a reply.
+    pub request_id: Option<u64>,
+}
+
+type PendingRequests = Rc<RefCell<HashMap<u64, oneshot::Sender<JsValue>>>>;
+
+/// State handle for the [`use_window_messaging`] hook.
+pub struct UseWindowMessagingHandle<In, Out> {
+    target_origin: Rc<str>,
+    options: Rc<WindowMessagingOptions>,
+    last_message: UseStateHandle<Option<ReceivedMessage<In>>>,
+    pending: PendingRequests,
+    next_id: Rc<Cell<u64>>,
+    _out: std::marker::PhantomData<Out>,
+}
+
+impl<In, Out> UseWindowMessagingHandle<In, Out>
+where
+    In: DeserializeOwned,
+    Out: Serialize,
+{
+    /// The most recent message received (replies to requests are not included).
+    pub fn last_message(&self) -> Option<&ReceivedMessage<In>> {
+        self.last_message.as_ref()
+    }
+
+    /// Post `message` to the target window.
+    pub fn send(&self, message: &Out) -> Result<(), WindowMessagingError> {
+        self.post(message, None, None)
+    }
+
+    /// Reply to a message received with a `request_id`.
+    pub fn reply(
+        &self,
+        request: &ReceivedMessage<In>,
+        message: &Out,
+    ) -> Result<(), WindowMessagingError> {
+        self.post(message, None, request.request_id)
+    }
+
+    /// Post `message` and wait for the other side to [`reply`](Self::reply).
+    pub async fn request(&self, message: &Out) -> Result<In, WindowMessagingError> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let (tx, rx) = oneshot::channel();
+        self.pending.borrow_mut().insert(id, tx);
+
+        if let Err(e) = self.post(message, Some(id), None) {
+            self.pending.borrow_mut().remove(&id);
+            return Err(e);
+        }
+
+        let millis = u32::try_from(self.options.request_timeout.as_millis()).unwrap_or(u32::MAX);
+        match select(rx, TimeoutFuture::new(millis)).await {
+            Either::Left((Ok(payload), _)) => {
+                js::from_js(&payload).map_err(WindowMessagingError::Deserialize)
+            }
+            _ => {
+                self.pending.borrow_mut().remove(&id);
+                Err(WindowMessagingError::Timeout)
+            }
+        }
+    }
+
+    fn post(
+        &self,
+        message: &Out,
+        request_id: Option<u64>,
+        reply_to: Option<u64>,
+    ) -> Result<(), WindowMessagingError> {
+        let target = self
+            .options
+            .target
+            .resolve()
+            .ok_or(WindowMessagingError::NoTarget)?;
+        let payload = js::to_js(message).map_err(WindowMessagingError::Send)?;
+        let envelope = js::object(&[
+            ("channel", self.options.channel.as_str().into()),
+            ("payload", payload),
+            (
+                "id",
+                request_id.map_or(JsValue::NULL, |id| (id as f64).into()),
+            ),
+            (
+                "replyTo",
+                reply_to.map_or(JsValue::NULL, |id| (id as f64).into()),
+            ),
+        ]);
+        target
+            .post_message(&envelope, &self.target_origin)
+            .map_err(|e| WindowMessagingError::Send(format!("{e:?}")))
+    }
+}
+
+impl<In, Out> Clone for UseWindowMessagingHandle<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            target_origin: self.target_origin.clone(),
+            options: self.options.clone(),
+            last_message: self.last_message.clone(),
+            pending: self.pending.clone(),
+            next_id: self.next_id.clone(),
+            _out: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<In, Out> PartialEq for UseWindowMessagingHandle<In, Out>
+where
+    In: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.target_origin == other.target_origin
+            && self.options == other.options
+            && *self.last_message == *other.last_message
+    }
+}
+
+/// A typed `postMessage` bridge for iframe/parent/opener communication.
+///
+/// Outgoing messages are only delivered while the target window shows a page
+/// from `target_origin` (`postMessage`'s `targetOrigin`; pass `"*"` only for
+/// non-sensitive data). Messages are serialized with `serde` and wrapped in an
+/// envelope tagged with [`WindowMessagingOptions::channel`]; incoming messages
+/// are only accepted from the [`target`](WindowMessagingOptions::target)
+/// window and from [`WindowMessagingOptions::allowed_origins`]. Use
+/// [`request`](UseWindowMessagingHandle::request) /
+/// [`reply`](UseWindowMessagingHandle::reply) for request/response exchanges.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// enum HostMessage {
+///     Theme(String),
+/// }
+///
+/// #[derive(Serialize)]
+/// enum WidgetMessage {
+///     Ready,
+/// }
+///
+/// #[function_component(Widget)]
+/// fn widget() -> Html {
+///     let host = use_window_messaging::<HostMessage, WidgetMessage>(
+///         "https://host.example".to_string(),
+///         WindowMessagingOptions {
+///             allowed_origins: vec!["https://host.example".to_string()],
+///             ..Default::default()
+///         },
+///     );
+///
+///     let onready = {
+///         let host = host.clone();
+///         Callback::from(move |_| {
+///             let _ = host.send(&WidgetMessage::Ready);
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onready}>{ "Notify host" }</button>
+///             {
+///                 match host.last_message().map(|m| &m.data) {
+///                     Some(HostMessage::Theme(theme)) => html! { <p>{ theme }</p> },
+///                     None => html! {},
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_window_messaging<In, Out>(
+    target_origin: String,
+    options: WindowMessagingOptions,
+) -> UseWindowMessagingHandle<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    let target_origin = use_memo(target_origin, |origin| Rc::<str>::from(origin.as_str()));
+    let options = use_memo(options, |options| options.clone());
+    let last_message = use_state(|| None);
+    let pending: PendingRequests = use_mut_ref(HashMap::new);
+    let next_id = use_memo((), |_| Cell::new(0));
+
+    {
+        let options = options.clone();
+        let last_message = last_message.clone();
+        let pending = pending.clone();
+        use_event_with_window("message", move |e: MessageEvent| {
+            let origin = e.origin();
+            if !options
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed == "*" || *allowed == origin)
+            {
+                return;
+            }
+            // Only the target window, not e.g. another iframe on an allowed origin.
+            let source = e.source().map(JsValue::from);
+            if source.is_none() || source != options.target.resolve().map(JsValue::from) {
+                return;
+            }
+            let data = e.data();
+            if js::get(&data, "channel")
+                .and_then(|c| c.as_string())
+                .as_deref()
+                != Some(options.channel.as_str())
+            {
+                return;
+            }
+            let payload = js::get(&data, "payload").unwrap_or(JsValue::NULL);
+            if let Some(reply_to) = js::get(&data, "replyTo").and_then(|id| id.as_f64()) {
+                if let Some(tx) = pending.borrow_mut().remove(&(reply_to as u64)) {
+                    let _ = tx.send(payload);
+                }
+                return;
+            }
+            match js::from_js::<In>(&payload) {
+                Ok(data) => last_message.set(Some(ReceivedMessage {
+                    data,
+                    origin,
+                    request_id: js::get(&e.data(), "id")
+                        .and_then(|id| id.as_f64())
+                        .map(|id| id as u64),
+                })),
+                Err(err) => warn!("Ignoring message from {origin}: {err}"),
+            }
+        });
+    }
+
+    UseWindowMessagingHandle {
+        target_origin: (*target_origin).clone(),
+        options,
+        last_message,
+        pending,
+        next_id,
+        _out: std::marker::PhantomData,
+    }
+}
@@ -0,0 +1,159 @@
+use gloo::{events::EventListener, timers::callback::Timeout, utils::window};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+use yew::prelude::*;
+
+/// How often a size hook re-renders while the size keeps changing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResizeRate {
+    /// On every `resize` event.
+    #[default]
+    Immediate,
+    /// Once the size has been stable for this long.
+    Debounce(Duration),
+    /// At most once per interval, including the final size.
+    Throttle(Duration),
+}
+
+/// Rate-limits updates according to a [`ResizeRate`].
+pub(crate) struct ResizeLimiter {
+    rate: ResizeRate,
+    pending: RefCell<Option<Timeout>>,
+    last: Cell<f64>,
+}
+
+impl ResizeLimiter {
+    pub(crate) fn new(rate: ResizeRate) -> Rc<Self> {
+        Rc::new(Self {
+            rate,
+            pending: RefCell::new(None),
+            last: Cell::new(f64::NEG_INFINITY),
+        })
+    }
+
+    /// Drop the pending update, if any.
+    pub(crate) fn cancel(&self) {
+        self.pending.borrow_mut().take();
+    }
+
+    /// Run `update` now or later, as the rate allows.
+    pub(crate) fn run(self: &Rc<Self>, update: impl Fn() + 'static) {
+        let millis = |d: Duration| u32::try_from(d.as_millis()).unwrap_or(u32::MAX);
+        match self.rate {
+            ResizeRate::Immediate => update(),
+            ResizeRate::Debounce(delay) => {
+                *self.pending.borrow_mut() = Some(Timeout::new(millis(delay), update));
+            }
+            ResizeRate::Throttle(interval) => {
+                if self.pending.borrow().is_some() {
+                    return;
+                }
+                let now = js_sys::Date::now();
+                let wait = self.last.get() + interval.as_secs_f64() * 1000.0 - now;
+                if wait <= 0.0 {
+                    self.last.set(now);
+                    update();
+                    return;
+                }
+                let limiter = self.clone();
+                *self.pending.borrow_mut() = Some(Timeout::new(wait.ceil() as u32, move || {
+                    limiter.pending.borrow_mut().take();
+                    limiter.last.set(js_sys::Date::now());
+                    update();
+                }));
+            }
+        }
+    }
+}
+
+/// Viewport size in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl WindowSize {
+    fn current() -> Self {
+        let window = window();
+        let px = |value: Result<wasm_bindgen::JsValue, _>| {
+            value.ok().and_then(|v| v.as_f64()).unwrap_or_default()
+        };
+        Self {
+            width: px(window.inner_width()),
+            height: px(window.inner_height()),
+        }
+    }
+}
+
+/// A sensor hook returning the window's inner width and height, updated on
+/// `resize`.
+///
+/// Every `resize` event re-renders; use [`use_window_size_with_rate`] to
+/// debounce or throttle updates during drag-resizes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Viewport)]
+/// fn viewport() -> Html {
+///     let size = use_window_size();
+///
+///     html! { <p>{ format!("{} × {}", size.width, size.height) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_window_size() -> WindowSize {
+    use_window_size_with_rate(ResizeRate::Immediate)
+}
+
+/// [`use_window_size`] re-rendering at most at `rate`, so a drag-resize
+/// doesn't re-render the whole app on every frame.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Chart)]
+/// fn chart() -> Html {
+///     let size = use_window_size_with_rate(ResizeRate::Debounce(Duration::from_millis(150)));
+///
+///     html! { <canvas width={size.width.to_string()} height="300" /> }
+/// }
+/// ```
+#[hook]
+pub fn use_window_size_with_rate(rate: ResizeRate) -> WindowSize {
+    let size = use_state_eq(WindowSize::current);
+
+    {
+        let size = size.clone();
+        use_effect_with(rate, move |rate| {
+            size.set(WindowSize::current());
+            let limiter = ResizeLimiter::new(*rate);
+            let listener = {
+                let limiter = limiter.clone();
+                EventListener::new(&window(), "resize", move |_| {
+                    let size = size.clone();
+                    limiter.run(move || size.set(WindowSize::current()));
+                })
+            };
+            move || {
+                drop(listener);
+                limiter.cancel();
+            }
+        });
+    }
+
+    *size
+}
@@ -0,0 +1,413 @@
+#![cfg(feature = "serde")]
+
+use gloo::{events::EventListener, utils::window};
+use log::warn;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    cell::{Cell, Ref, RefCell},
+    collections::VecDeque,
+    rc::{Rc, Weak},
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{ErrorEvent, MessageEvent, Worker, WorkerOptions, WorkerType};
+use yew::prelude::*;
+use yew_hooks::use_update;
+
+use crate::js;
+
+/// Options for the [`use_worker_pool_map`] hook.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkerPoolOptions {
+    /// URL of the worker script.
+    pub script_url: String,
+    /// Number of workers; `0` uses `navigator.hardwareConcurrency`.
+    pub pool_size: usize,
+    /// Load the script as an ES module worker.
+    pub module: bool,
+}
+
+impl WorkerPoolOptions {
+    fn size(&self, items: usize) -> usize {
+        let size = match self.pool_size {
+            0 => window().navigator().hardware_concurrency() as usize,
+            size => size,
+        };
+        size.clamp(1, items.max(1))
+    }
+}
+
+/// Processing status of one input of [`use_worker_pool_map`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum WorkerItemStatus<O> {
+    /// Waiting for a free worker.
+    #[default]
+    Pending,
+    /// Being processed; `progress` (0 to 1) is set when the worker reports it.
+    Running { progress: Option<f64> },
+    /// The worker returned a result.
+    Done(O),
+    /// The input did not serialize, or the worker reported an error, threw,
+    /// failed to load, or returned an invalid result.
+    Failed(String),
+    /// The run was cancelled before the item finished.
+    Cancelled,
+}
+
+impl<O> WorkerItemStatus<O> {
+    /// Whether the item has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Done(_) | Self::Failed(_) | Self::Cancelled)
+    }
+}
+
+struct PoolWorker {
+    worker: Worker,
+    /// Index of the item being processed.
+    busy: Option<usize>,
+    /// Terminated after an error; no longer given items.
+    retired: bool,
+    _listeners: [EventListener; 2],
+}
+
+struct Pool<O> {
+    options: RefCell<WorkerPoolOptions>,
+    workers: RefCell<Vec<PoolWorker>>,
+    queue: RefCell<VecDeque<(usize, JsValue)>>,
+    items: RefCell<Vec<WorkerItemStatus<O>>>,
+    /// Bumped on every change, so handles from different renders compare unequal.
+    version: Cell<u64>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<O> Pool<O>
+where
+    O: DeserializeOwned + 'static,
+{
+    fn changed(&self) {
+        self.version.set(self.version.get() + 1);
+        (self.update)();
+    }
+
+    /// Start a run; inputs that failed to serialize are marked failed right away.
+    fn start(self: &Rc<Self>, inputs: Vec<Result<JsValue, String>>) {
+        self.terminate();
+        let mut items = Vec::with_capacity(inputs.len());
+        let mut queue = VecDeque::with_capacity(inputs.len());
+        for (id, input) in inputs.into_iter().enumerate() {
+            match input {
+                Ok(input) => {
+                    items.push(WorkerItemStatus::Pending);
+                    queue.push_back((id, input));
+                }
+                Err(e) => items.push(WorkerItemStatus::Failed(e)),
+            }
+        }
+        *self.items.borrow_mut() = items;
+        *self.queue.borrow_mut() = queue;
+
+        let options = self.options.borrow().clone();
+        let worker_options = WorkerOptions::new();
+        if options.module {
+            worker_options.set_type(WorkerType::Module);
+        }
+        let size = match self.queue.borrow().len() {
+            0 => 0,
+            queued => options.size(queued),
+        };
+        let mut workers = Vec::with_capacity(size);
+        for _ in 0..size {
+            match Worker::new_with_options(&options.script_url, &worker_options) {
+                Ok(worker) => {
+                    let index = workers.len();
+                    workers.push(self.listen(worker, index));
+                }
+                Err(e) => warn!("Failed to start worker {}: {e:?}", options.script_url),
+            }
+        }
+        let started = !workers.is_empty();
+        *self.workers.borrow_mut() = workers;
+        if !started && size > 0 {
+            self.fail_queue("failed to start workers");
+        }
+        self.dispatch();
+        self.changed();
+    }
+
+    fn listen(self: &Rc<Self>, worker: Worker, index: usize) -> PoolWorker {
+        let pool = Rc::downgrade(self);
+        let message = EventListener::new(&worker, "message", move |e| {
+            let e: &MessageEvent = e.unchecked_ref();
+            if let Some(pool) = Weak::upgrade(&pool) {
+                pool.message(index, e.data());
+            }
+        });
+        let pool = Rc::downgrade(self);
+        let error = EventListener::new(&worker, "error", move |e| {
+            let e: &ErrorEvent = e.unchecked_ref();
+            if let Some(pool) = Weak::upgrade(&pool) {
+                pool.retire(index, e.message());
+            }
+        });
+        PoolWorker {
+            worker,
+            busy: None,
+            retired: false,
+            _listeners: [message, error],
+        }
+    }
+
+    /// Handle a `{ id, output }`, `{ id, error }` or `{ id, progress }` reply.
+    fn message(&self, index: usize, data: JsValue) {
+        if let Some(progress) = js::get(&data, "progress").and_then(|p| p.as_f64()) {
+            let busy = self.workers.borrow().get(index).and_then(|w| w.busy);
+            let updated = busy.is_some_and(|id| match self.items.borrow_mut().get_mut(id) {
+                Some(WorkerItemStatus::Running { progress: p }) => {
+                    *p = Some(progress.clamp(0.0, 1.0));
+                    true
+                }
+                _ => false,
+            });
+            if updated {
+                self.changed();
+            }
+            return;
+        }
+        let status = match js::get(&data, "error") {
+            Some(error) => {
+                WorkerItemStatus::Failed(error.as_string().unwrap_or_else(|| format!("{error:?}")))
+            }
+            None => {
+                let output = js::get(&data, "output").unwrap_or(JsValue::NULL);
+                match js::from_js(&output) {
+                    Ok(output) => WorkerItemStatus::Done(output),
+                    Err(e) => WorkerItemStatus::Failed(e),
+                }
+            }
+        };
+        self.finish(index, status);
+    }
+
+    /// Record the result of worker `index`'s item and hand it the next one.
+    fn finish(&self, index: usize, status: WorkerItemStatus<O>) {
+        let busy = self
+            .workers
+            .borrow_mut()
+            .get_mut(index)
+            .and_then(|w| w.busy.take());
+        if let Some(id) = busy
+            && let Some(item) = self.items.borrow_mut().get_mut(id)
+        {
+            *item = status;
+        }
+        self.dispatch();
+        self.changed();
+    }
+
+    /// Terminate worker `index` after an error that is not a reply, e.g. its
+    /// script failed to load or threw, failing the item it was processing.
+    /// Once no worker is left, the queued items fail too.
+    fn retire(&self, index: usize, message: String) {
+        let busy = {
+            let mut workers = self.workers.borrow_mut();
+            let Some(worker) = workers.get_mut(index) else {
+                return;
+            };
+            worker.worker.terminate();
+            worker.retired = true;
+            worker.busy.take()
+        };
+        if let Some(id) = busy
+            && let Some(item) = self.items.borrow_mut().get_mut(id)
+        {
+            *item = WorkerItemStatus::Failed(message.clone());
+        }
+        if self.workers.borrow().iter().all(|w| w.retired) {
+            self.fail_queue(&message);
+        }
+        self.changed();
+    }
+
+    /// Fail every queued item with `message`.
+    fn fail_queue(&self, message: &str) {
+        let mut items = self.items.borrow_mut();
+        for (id, _) in self.queue.borrow_mut().drain(..) {
+            items[id] = WorkerItemStatus::Failed(message.to_string());
+        }
+    }
+
+    /// Post queued inputs to idle workers.
+    fn dispatch(&self) {
+        let mut workers = self.workers.borrow_mut();
+        for worker in workers
+            .iter_mut()
+            .filter(|w| w.busy.is_none() && !w.retired)
+        {
+            let Some((id, input)) = self.queue.borrow_mut().pop_front() else {
+                return;
+            };
+            let message = js::object(&[("id", (id as f64).into()), ("input", input)]);
+            let status = match worker.worker.post_message(&message) {
+                Ok(()) => {
+                    worker.busy = Some(id);
+                    WorkerItemStatus::Running { progress: None }
+                }
+                Err(e) => WorkerItemStatus::Failed(format!("{e:?}")),
+            };
+            self.items.borrow_mut()[id] = status;
+        }
+    }
+
+    /// Stop every worker; unfinished items are marked cancelled.
+    fn terminate(&self) {
+        for worker in self.workers.borrow_mut().drain(..) {
+            worker.worker.terminate();
+        }
+        self.queue.borrow_mut().clear();
+        for item in self.items.borrow_mut().iter_mut() {
+            if !item.is_finished() {
+                *item = WorkerItemStatus::Cancelled;
+            }
+        }
+    }
+}
+
+/// State handle for the [`use_worker_pool_map`] hook.
+pub struct UseWorkerPoolMapHandle<I, O> {
+    pool: Rc<Pool<O>>,
+    version: u64,
+    _input: std::marker::PhantomData<I>,
+}
+
+impl<I, O> UseWorkerPoolMapHandle<I, O>
+where
+    I: Serialize,
+    O: DeserializeOwned + 'static,
+{
+    /// Process `inputs`, cancelling the current run. Inputs that fail to
+    /// serialize are marked [`Failed`](WorkerItemStatus::Failed) without being sent.
+    pub fn run(&self, inputs: Vec<I>) {
+        let inputs = inputs.iter().map(js::to_js).collect();
+        self.pool.start(inputs);
+    }
+
+    /// Stop every worker; unfinished items are marked
+    /// [`Cancelled`](WorkerItemStatus::Cancelled).
+    pub fn cancel(&self) {
+        self.pool.terminate();
+        self.pool.changed();
+    }
+
+    /// The status of every input of the current run, in input order.
+    pub fn items(&'_ self) -> Ref<'_, Vec<WorkerItemStatus<O>>> {
+        self.pool.items.borrow()
+    }
+
+    /// Fraction of finished items (0 to 1), counting reported per-item progress.
+    pub fn progress(&self) -> f64 {
+        let items = self.items();
+        if items.is_empty() {
+            return 0.0;
+        }
+        let done: f64 = items
+            .iter()
+            .map(|item| match item {
+                WorkerItemStatus::Running { progress } => progress.unwrap_or(0.0),
+                item if item.is_finished() => 1.0,
+                _ => 0.0,
+            })
+            .sum();
+        done / items.len() as f64
+    }
+
+    /// Whether any item is still pending or running.
+    pub fn is_running(&self) -> bool {
+        self.items().iter().any(|item| !item.is_finished())
+    }
+}
+
+impl<I, O> Clone for UseWorkerPoolMapHandle<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            version: self.version,
+            _input: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<I, O> PartialEq for UseWorkerPoolMapHandle<I, O> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.pool, &other.pool) && self.version == other.version
+    }
+}
+
+/// A hook that maps inputs through a worker script on a pool of web workers,
+/// e.g. for hashing or thumbnailing many files on the client.
+///
+/// Each input is serialized and posted to a free worker as `{ id, input }`.
+/// The worker replies with `{ id, output }` or `{ id, error }`, and may post
+/// `{ id, progress }` (0 to 1) while working. Results appear per item as they
+/// arrive. Workers are terminated when the run is cancelled, a new run starts,
+/// or the component unmounts.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use more_yew_hooks::*;
+///
+/// #[function_component(Hasher)]
+/// fn hasher() -> Html {
+///     let pool = use_worker_pool_map::<String, String>(WorkerPoolOptions {
+///         script_url: "/hash-worker.js".to_string(),
+///         ..Default::default()
+///     });
+///
+///     let onstart = {
+///         let pool = pool.clone();
+///         Callback::from(move |_| pool.run(vec!["a".to_string(), "b".to_string()]))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onstart}>{ "Hash" }</button>
+///             <progress value={pool.progress().to_string()} />
+///             { for pool.items().iter().map(|item| match item {
+///                 WorkerItemStatus::Done(hash) => html! { <p>{ hash }</p> },
+///                 WorkerItemStatus::Failed(e) => html! { <p>{ e }</p> },
+///                 _ => html! { <p>{ "…" }</p> },
+///             }) }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_worker_pool_map<I, O>(options: WorkerPoolOptions) -> UseWorkerPoolMapHandle<I, O>
+where
+    I: Serialize + 'static,
+    O: DeserializeOwned + 'static,
+{
+    let update = use_update();
+    let pool = use_memo((), |_| Pool {
+        options: RefCell::new(options.clone()),
+        workers: RefCell::new(Vec::new()),
+        queue: RefCell::new(VecDeque::new()),
+        items: RefCell::new(Vec::new()),
+        version: Cell::new(0),
+        update,
+    });
+    *pool.options.borrow_mut() = options;
+
+    {
+        let pool = pool.clone();
+        use_effect_with((), move |_| move || pool.terminate());
+    }
+
+    let version = pool.version.get();
+    UseWorkerPoolMapHandle {
+        pool,
+        version,
+        _input: std::marker::PhantomData,
+    }
+}